@@ -431,6 +431,8 @@ def set_minimal_package_versions(session: nox.Session):
         "chrono": "0.4.24",
         "indexmap": "1.6.2",
         "inventory": "0.3.4",
+        # newer versions of log require Rust 1.60
+        "log": "0.4.17",
         "hashbrown": "0.9.1",
         "plotters": "0.3.1",
         "plotters-svg": "0.3.1",
@@ -553,14 +555,18 @@ def _get_feature_sets() -> Tuple[Tuple[str, ...], ...]:
     cargo_target = os.getenv("CARGO_BUILD_TARGET", "")
     if rust_version[:2] >= (1, 62) and "wasm32-wasi" not in cargo_target:
         # multiple-pymethods feature not supported before 1.62 or on WASI
+        extra = "multiple-pymethods"
+        if rust_version[:2] >= (1, 65):
+            # reference-pool-debug and fixed-width big integer features not supported before 1.65
+            extra += " reference-pool-debug bnum primitive-types ruint"
         return (
             ("--no-default-features",),
             (
                 "--no-default-features",
                 "--features=abi3",
             ),
-            ("--features=full multiple-pymethods",),
-            ("--features=abi3 full multiple-pymethods",),
+            (f"--features=full {extra}",),
+            (f"--features=abi3 full {extra}",),
         )
     else:
         return (
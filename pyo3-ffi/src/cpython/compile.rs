@@ -12,12 +12,16 @@ use std::os::raw::c_int;
 
 // skipped non-limited PyCF_MASK
 // skipped non-limited PyCF_MASK_OBSOLETE
-// skipped non-limited PyCF_SOURCE_IS_UTF8
-// skipped non-limited PyCF_DONT_IMPLY_DEDENT
-// skipped non-limited PyCF_ONLY_AST
-// skipped non-limited PyCF_IGNORE_COOKIE
-// skipped non-limited PyCF_TYPE_COMMENTS
-// skipped non-limited PyCF_ALLOW_TOP_LEVEL_AWAIT
+pub const PyCF_SOURCE_IS_UTF8: c_int = 0x0100;
+pub const PyCF_DONT_IMPLY_DEDENT: c_int = 0x0200;
+pub const PyCF_ONLY_AST: c_int = 0x0400;
+pub const PyCF_IGNORE_COOKIE: c_int = 0x0800;
+#[cfg(Py_3_8)]
+pub const PyCF_TYPE_COMMENTS: c_int = 0x1000;
+#[cfg(Py_3_8)]
+pub const PyCF_ALLOW_TOP_LEVEL_AWAIT: c_int = 0x2000;
+#[cfg(Py_3_11)]
+pub const PyCF_ALLOW_INCOMPLETE_INPUT: c_int = 0x4000;
 // skipped non-limited PyCF_COMPILE_MASK
 
 #[repr(C)]
@@ -24,7 +24,12 @@ extern "C" {
     // skipped _PyDict_GetItem_KnownHash
     // skipped _PyDict_GetItemIdWithError
     // skipped _PyDict_GetItemStringWithError
-    // skipped PyDict_SetDefault
+    #[cfg(not(PyPy))]
+    pub fn PyDict_SetDefault(
+        mp: *mut PyObject,
+        key: *mut PyObject,
+        defaultobj: *mut PyObject,
+    ) -> *mut PyObject;
     pub fn _PyDict_SetItem_KnownHash(
         mp: *mut PyObject,
         key: *mut PyObject,
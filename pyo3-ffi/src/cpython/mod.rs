@@ -28,7 +28,8 @@ pub(crate) mod pylifecycle;
 pub(crate) mod pymem;
 pub(crate) mod pystate;
 pub(crate) mod pythonrun;
-// skipped sysmodule.h
+#[cfg(not(PyPy))]
+pub(crate) mod sysmodule;
 pub(crate) mod tupleobject;
 pub(crate) mod unicodeobject;
 pub(crate) mod weakrefobject;
@@ -59,6 +60,8 @@ pub use self::pylifecycle::*;
 pub use self::pymem::*;
 pub use self::pystate::*;
 pub use self::pythonrun::*;
+#[cfg(not(PyPy))]
+pub use self::sysmodule::*;
 pub use self::tupleobject::*;
 pub use self::unicodeobject::*;
 pub use self::weakrefobject::*;
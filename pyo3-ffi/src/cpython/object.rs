@@ -338,7 +338,11 @@ pub unsafe fn PyHeapType_GET_MEMBERS(
 }
 
 // skipped _PyType_Name
-// skipped _PyType_Lookup
+extern "C" {
+    #[cfg(not(PyPy))]
+    pub fn _PyType_Lookup(arg1: *mut PyTypeObject, arg2: *mut PyObject) -> *mut PyObject;
+}
+
 // skipped _PyType_LookupId
 // skipped _PyObject_LookupSpecial
 // skipped _PyType_CalculateMetaclass
@@ -46,3 +46,31 @@ extern "C" {
 
     // skipped _Py_NewInterpreter
 }
+
+#[cfg(Py_3_12)]
+pub const PyInterpreterConfig_DEFAULT_GIL: c_int = 0;
+#[cfg(Py_3_12)]
+pub const PyInterpreterConfig_SHARED_GIL: c_int = 1;
+#[cfg(Py_3_12)]
+pub const PyInterpreterConfig_OWN_GIL: c_int = 2;
+
+#[cfg(Py_3_12)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PyInterpreterConfig {
+    pub use_main_obmalloc: c_int,
+    pub allow_fork: c_int,
+    pub allow_exec: c_int,
+    pub allow_threads: c_int,
+    pub allow_daemon_threads: c_int,
+    pub check_multi_interp_extensions: c_int,
+    pub gil: c_int,
+}
+
+#[cfg(Py_3_12)]
+extern "C" {
+    pub fn Py_NewInterpreterFromConfig(
+        tstate_p: *mut *mut crate::PyThreadState,
+        config: *const PyInterpreterConfig,
+    ) -> PyStatus;
+}
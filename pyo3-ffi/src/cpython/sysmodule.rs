@@ -0,0 +1,20 @@
+use crate::object::PyObject;
+use std::os::raw::{c_char, c_int, c_void};
+
+// skipped _PySys_GetAttr
+// skipped _PySys_GetObjectId
+// skipped _PySys_SetObjectId
+// skipped _PySys_GetSizeOf
+
+#[cfg(Py_3_8)]
+pub type Py_AuditHookFunction =
+    unsafe extern "C" fn(event: *const c_char, args: *mut PyObject, userData: *mut c_void) -> c_int;
+
+extern "C" {
+    #[cfg(Py_3_8)]
+    pub fn PySys_Audit(event: *const c_char, argFormat: *const c_char, ...) -> c_int;
+    #[cfg(Py_3_8)]
+    pub fn PySys_AddAuditHook(hook: Py_AuditHookFunction, userData: *mut c_void) -> c_int;
+}
+
+// skipped _PySys_AddAuditHook
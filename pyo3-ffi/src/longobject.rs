@@ -94,7 +94,8 @@ extern "C" {
 
 #[cfg(not(Py_LIMITED_API))]
 extern "C" {
-    // skipped non-limited _PyLong_Sign
+    #[cfg(not(PyPy))]
+    pub fn _PyLong_Sign(v: *mut PyObject) -> c_int;
 
     #[cfg_attr(PyPy, link_name = "_PyPyLong_NumBits")]
     pub fn _PyLong_NumBits(obj: *mut PyObject) -> size_t;
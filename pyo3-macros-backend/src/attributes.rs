@@ -14,6 +14,7 @@ pub mod kw {
     syn::custom_keyword!(attribute);
     syn::custom_keyword!(dict);
     syn::custom_keyword!(extends);
+    syn::custom_keyword!(extends_py);
     syn::custom_keyword!(freelist);
     syn::custom_keyword!(from_py_with);
     syn::custom_keyword!(frozen);
@@ -26,7 +27,9 @@ pub mod kw {
     syn::custom_keyword!(module);
     syn::custom_keyword!(name);
     syn::custom_keyword!(pass_module);
+    syn::custom_keyword!(pickle);
     syn::custom_keyword!(sequence);
+    syn::custom_keyword!(serde);
     syn::custom_keyword!(set);
     syn::custom_keyword!(set_all);
     syn::custom_keyword!(signature);
@@ -117,7 +120,38 @@ impl ToTokens for TextSignatureAttributeValue {
     }
 }
 
+/// `pickle`, optionally as `pickle = serde` to pickle the Rust value using serde.
+#[derive(Clone, Debug)]
+pub struct PickleAttribute {
+    pub kw: kw::pickle,
+    pub serde: Option<kw::serde>,
+}
+
+impl Parse for PickleAttribute {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let kw = input.parse()?;
+        let serde = if input.peek(Token![=]) {
+            let _: Token![=] = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(PickleAttribute { kw, serde })
+    }
+}
+
+impl ToTokens for PickleAttribute {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.kw.to_tokens(tokens);
+        if let Some(serde) = &self.serde {
+            Token![=](serde.span).to_tokens(tokens);
+            serde.to_tokens(tokens);
+        }
+    }
+}
+
 pub type ExtendsAttribute = KeywordAttribute<kw::extends, Path>;
+pub type ExtendsPyAttribute = KeywordAttribute<kw::extends_py, LitStr>;
 pub type FreelistAttribute = KeywordAttribute<kw::freelist, Box<Expr>>;
 pub type ModuleAttribute = KeywordAttribute<kw::module, LitStr>;
 pub type NameAttribute = KeywordAttribute<kw::name, NameLitStr>;
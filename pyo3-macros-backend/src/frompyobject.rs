@@ -23,11 +23,11 @@ impl<'a> Enum<'a> {
     /// Construct a new enum representation.
     ///
     /// `data_enum` is the `syn` representation of the input enum, `ident` is the
-    /// `Identifier` of the enum.
-    fn new(data_enum: &'a DataEnum, ident: &'a Ident) -> Result<Self> {
+    /// `Identifier` of the enum and `derive` is the name of the derived trait.
+    fn new(data_enum: &'a DataEnum, ident: &'a Ident, derive: &str) -> Result<Self> {
         ensure_spanned!(
             !data_enum.variants.is_empty(),
-            ident.span() => "cannot derive FromPyObject for empty enum"
+            ident.span() => format!("cannot derive {} for empty enum", derive)
         );
         let variants = data_enum
             .variants
@@ -35,7 +35,12 @@ impl<'a> Enum<'a> {
             .map(|variant| {
                 let attrs = ContainerOptions::from_attrs(&variant.attrs)?;
                 let var_ident = &variant.ident;
-                Container::new(&variant.fields, parse_quote!(#ident::#var_ident), attrs)
+                Container::new(
+                    &variant.fields,
+                    parse_quote!(#ident::#var_ident),
+                    attrs,
+                    derive,
+                )
             })
             .collect::<Result<Vec<_>>>()?;
 
@@ -83,6 +88,20 @@ impl<'a> Enum<'a> {
             )
         )
     }
+
+    /// Build `IntoPy` body for enums, converting each variant like a struct.
+    fn build_into_py(&self) -> Result<TokenStream> {
+        let arms = self
+            .variants
+            .iter()
+            .map(Container::build_into_py)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(quote!(
+            match self {
+                #(#arms)*
+            }
+        ))
+    }
 }
 
 struct NamedStructField<'a> {
@@ -131,7 +150,12 @@ impl<'a> Container<'a> {
     /// Construct a container based on fields, identifier and attributes.
     ///
     /// Fails if the variant has no fields or incompatible attributes.
-    fn new(fields: &'a Fields, path: syn::Path, options: ContainerOptions) -> Result<Self> {
+    fn new(
+        fields: &'a Fields,
+        path: syn::Path,
+        options: ContainerOptions,
+        derive: &str,
+    ) -> Result<Self> {
         let style = match fields {
             Fields::Unnamed(unnamed) if !unnamed.unnamed.is_empty() => {
                 let mut tuple_fields = unnamed
@@ -211,7 +235,7 @@ impl<'a> Container<'a> {
                 }
             }
             _ => bail_spanned!(
-                fields.span() => "cannot derive FromPyObject for empty structs and variants"
+                fields.span() => format!("cannot derive {} for empty structs and variants", derive)
             ),
         };
         let err_name = options.annotation.map_or_else(
@@ -352,6 +376,104 @@ impl<'a> Container<'a> {
         }
         quote!(::std::result::Result::Ok(#self_ty{#fields}))
     }
+
+    /// Build a `match` arm converting this struct or variant into a Python object.
+    ///
+    /// This is the inverse of `build`: fields read with `item` are written into a `dict`, fields
+    /// read with `attribute` are written onto a `types.SimpleNamespace` and tuple structs become
+    /// tuples.
+    fn build_into_py(&self) -> Result<TokenStream> {
+        let self_ty = &self.path;
+        let arm = match &self.ty {
+            ContainerType::StructNewtype(ident, _) => quote! {
+                #self_ty { #ident: field0 } => _pyo3::IntoPy::into_py(field0, py),
+            },
+            ContainerType::TupleNewtype(_) => quote! {
+                #self_ty(field0) => _pyo3::IntoPy::into_py(field0, py),
+            },
+            ContainerType::Tuple(tups) => {
+                let field_idents: Vec<_> = (0..tups.len())
+                    .map(|i| format_ident!("field{}", i))
+                    .collect();
+                quote! {
+                    #self_ty(#(#field_idents),*) => _pyo3::IntoPy::into_py(
+                        _pyo3::types::PyTuple::new(
+                            py,
+                            &[#(_pyo3::IntoPy::<_pyo3::PyObject>::into_py(#field_idents, py)),*],
+                        ),
+                        py,
+                    ),
+                }
+            }
+            ContainerType::Struct(struct_fields) => {
+                let field_idents: Vec<_> = (0..struct_fields.len())
+                    .map(|i| format_ident!("field{}", i))
+                    .collect();
+                let idents = struct_fields.iter().map(|field| field.ident);
+                let pattern = quote!(#self_ty { #(#idents: #field_idents),* });
+                // Decide from all fields, so that the error points at the odd one out.
+                let (item_fields, attribute_fields): (Vec<_>, Vec<_>) = struct_fields
+                    .iter()
+                    .partition(|field| matches!(field.getter, Some(FieldGetter::GetItem(_))));
+                let as_items = attribute_fields.is_empty();
+                if !as_items && !item_fields.is_empty() {
+                    let odd_one_out = if item_fields.len() < attribute_fields.len() {
+                        item_fields[0]
+                    } else {
+                        attribute_fields[0]
+                    };
+                    bail_spanned!(
+                        odd_one_out.ident.span() => "cannot derive IntoPy for structs which mix `item` and `attribute` fields"
+                    );
+                }
+                let mut entries = Vec::new();
+                for (field, value) in struct_fields.iter().zip(&field_idents) {
+                    let field_name = field.ident.to_string();
+                    let key = match &field.getter {
+                        Some(FieldGetter::GetItem(Some(syn::Lit::Str(key)))) => {
+                            quote!(_pyo3::ToPyObject::to_object(_pyo3::intern!(py, #key), py))
+                        }
+                        Some(FieldGetter::GetItem(Some(key))) => {
+                            // Byte strings convert to lists, which can't be `dict` keys.
+                            ensure_spanned!(
+                                matches!(
+                                    key,
+                                    syn::Lit::Int(_)
+                                        | syn::Lit::Float(_)
+                                        | syn::Lit::Bool(_)
+                                        | syn::Lit::Char(_)
+                                        | syn::Lit::Byte(_)
+                                ),
+                                key.span() => "cannot derive IntoPy for `item` keys which do not convert to a hashable Python object"
+                            );
+                            quote!(_pyo3::ToPyObject::to_object(&#key, py))
+                        }
+                        Some(FieldGetter::GetItem(None)) => {
+                            quote!(_pyo3::ToPyObject::to_object(
+                                _pyo3::intern!(py, #field_name),
+                                py
+                            ))
+                        }
+                        Some(FieldGetter::GetAttr(Some(name))) => quote!(_pyo3::intern!(py, #name)),
+                        None | Some(FieldGetter::GetAttr(None)) => {
+                            quote!(_pyo3::intern!(py, #field_name))
+                        }
+                    };
+                    entries.push(quote!((#key, _pyo3::IntoPy::into_py(#value, py))));
+                }
+                if as_items {
+                    quote! {
+                        #pattern => _pyo3::impl_::intopy::struct_into_dict(py, ::std::vec![#(#entries),*]),
+                    }
+                } else {
+                    quote! {
+                        #pattern => _pyo3::impl_::intopy::struct_into_namespace(py, ::std::vec![#(#entries),*]),
+                    }
+                }
+            }
+        };
+        Ok(arm)
+    }
 }
 
 #[derive(Default)]
@@ -586,7 +708,7 @@ pub fn build_derive_from_pyobject(tokens: &DeriveInput) -> Result<TokenStream> {
                 bail_spanned!(tokens.span() => "`transparent` or `annotation` is not supported \
                                                 at top level for enums");
             }
-            let en = Enum::new(en, &tokens.ident)?;
+            let en = Enum::new(en, &tokens.ident, "FromPyObject")?;
             en.build()
         }
         syn::Data::Struct(st) => {
@@ -594,7 +716,7 @@ pub fn build_derive_from_pyobject(tokens: &DeriveInput) -> Result<TokenStream> {
                 bail_spanned!(lit_str.span() => "`annotation` is unsupported for structs");
             }
             let ident = &tokens.ident;
-            let st = Container::new(&st.fields, parse_quote!(#ident), options)?;
+            let st = Container::new(&st.fields, parse_quote!(#ident), options, "FromPyObject")?;
             st.build()
         }
         syn::Data::Union(_) => bail_spanned!(
@@ -616,3 +738,61 @@ pub fn build_derive_from_pyobject(tokens: &DeriveInput) -> Result<TokenStream> {
         };
     ))
 }
+
+/// Derive `IntoPy<PyObject>` for enums and structs.
+///
+///   * Accepts the same attributes as `#[derive(FromPyObject)]`, so that values round-trip
+///   * Structs whose fields are read with `item` are converted into a `dict`, structs whose
+///     fields are read with `attribute` into a `types.SimpleNamespace`
+///   * Tuple structs are converted into tuples, newtypes into their inner value
+///   * Enums are converted like the struct of the active variant
+///   * Derivation for structs with generic fields like `struct<T> Foo(T)`
+///     adds `T: IntoPy<PyObject>` on the derived implementation.
+pub fn build_derive_into_py(tokens: &DeriveInput) -> Result<TokenStream> {
+    let generics = &tokens.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut where_clause = where_clause.cloned().unwrap_or_else(|| parse_quote!(where));
+    for param in generics.type_params() {
+        let gen_ident = &param.ident;
+        where_clause
+            .predicates
+            .push(parse_quote!(#gen_ident: _pyo3::IntoPy<_pyo3::PyObject>))
+    }
+    let options = ContainerOptions::from_attrs(&tokens.attrs)?;
+    let krate = get_pyo3_crate(&options.krate);
+    let body = match &tokens.data {
+        syn::Data::Enum(en) => {
+            if options.transparent || options.annotation.is_some() {
+                bail_spanned!(tokens.span() => "`transparent` or `annotation` is not supported \
+                                                at top level for enums");
+            }
+            Enum::new(en, &tokens.ident, "IntoPy")?.build_into_py()?
+        }
+        syn::Data::Struct(st) => {
+            if let Some(lit_str) = &options.annotation {
+                bail_spanned!(lit_str.span() => "`annotation` is unsupported for structs");
+            }
+            let ident = &tokens.ident;
+            let arm = Container::new(&st.fields, parse_quote!(#ident), options, "IntoPy")?
+                .build_into_py()?;
+            quote!(match self { #arm })
+        }
+        syn::Data::Union(_) => bail_spanned!(
+            tokens.span() => "#[derive(IntoPy)] is not supported for unions"
+        ),
+    };
+
+    let ident = &tokens.ident;
+    Ok(quote!(
+        const _: () = {
+            use #krate as _pyo3;
+
+            #[automatically_derived]
+            impl #impl_generics _pyo3::IntoPy<_pyo3::PyObject> for #ident #ty_generics #where_clause {
+                fn into_py(self, py: _pyo3::Python<'_>) -> _pyo3::PyObject {
+                    #body
+                }
+            }
+        };
+    ))
+}
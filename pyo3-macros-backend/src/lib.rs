@@ -21,7 +21,7 @@ mod pyfunction;
 mod pyimpl;
 mod pymethod;
 
-pub use frompyobject::build_derive_from_pyobject;
+pub use frompyobject::{build_derive_from_pyobject, build_derive_into_py};
 pub use module::{process_functions_in_module, pymodule_impl, PyModuleOptions};
 pub use pyclass::{build_py_class, build_py_enum, PyClassArgs};
 pub use pyfunction::{build_py_function, PyFunctionOptions};
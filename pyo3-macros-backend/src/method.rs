@@ -369,7 +369,27 @@ impl<'a> FnSpec<'a> {
                 .map(|stripped| syn::Ident::new(stripped, name.span()))
         };
 
+        // Like in Python, these methods are class methods even without `#[classmethod]`.
+        let method_name = python_name.as_ref().unwrap_or(name).unraw().to_string();
+        let is_implicit_classmethod = IMPLICIT_CLASSMETHODS.contains(&method_name.as_str());
+        if is_implicit_classmethod {
+            ensure_spanned!(
+                matches!(fn_type_attr, None | Some(MethodTypeAttribute::ClassMethod)),
+                name.span() => format!("`{}` is always a class method", method_name)
+            );
+        }
+
         let (fn_type, skip_first_arg, fixed_convention) = match fn_type_attr {
+            None if is_implicit_classmethod => {
+                ensure_spanned!(
+                    matches!(sig.inputs.first(), Some(syn::FnArg::Typed(_))),
+                    sig.inputs.first().map_or_else(|| sig.span(), Spanned::span) => format!(
+                        "`{}` is always a class method, so its first argument must be the class (e.g. `cls: &PyType`)",
+                        method_name
+                    )
+                );
+                (FnType::FnClass, true, None)
+            }
             Some(MethodTypeAttribute::StaticMethod) => (FnType::FnStatic, false, None),
             Some(MethodTypeAttribute::ClassAttribute) => (FnType::ClassAttribute, false, None),
             Some(MethodTypeAttribute::New) | Some(MethodTypeAttribute::NewClassMethod) => {
@@ -780,6 +800,9 @@ fn parse_method_attributes(
     })
 }
 
+/// Methods which Python always treats as class methods.
+const IMPLICIT_CLASSMETHODS: &[&str] = &["__init_subclass__", "__class_getitem__"];
+
 const IMPL_TRAIT_ERR: &str = "Python functions cannot have `impl Trait` arguments";
 const RECEIVER_BY_VALUE_ERR: &str =
     "Python objects are shared, so 'self' cannot be moved out of the Python interpreter.
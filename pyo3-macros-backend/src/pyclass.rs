@@ -3,17 +3,17 @@
 use std::borrow::Cow;
 
 use crate::attributes::{
-    self, kw, take_pyo3_options, CrateAttribute, ExtendsAttribute, FreelistAttribute,
-    ModuleAttribute, NameAttribute, NameLitStr, TextSignatureAttribute,
-    TextSignatureAttributeValue,
+    self, kw, take_pyo3_options, CrateAttribute, ExtendsAttribute, ExtendsPyAttribute,
+    FreelistAttribute, ModuleAttribute, NameAttribute, NameLitStr, PickleAttribute,
+    TextSignatureAttribute, TextSignatureAttributeValue,
 };
 use crate::deprecations::{Deprecation, Deprecations};
 use crate::konst::{ConstAttributes, ConstSpec};
-use crate::method::FnSpec;
+use crate::method::{FnSpec, FnType};
 use crate::pyimpl::{gen_py_const, PyClassMethodsType};
 use crate::pymethod::{
-    impl_py_getter_def, impl_py_setter_def, MethodAndMethodDef, MethodAndSlotDef, PropertyType,
-    SlotDef, __INT__, __REPR__, __RICHCMP__,
+    impl_py_getter_def, impl_py_method_def, impl_py_setter_def, MethodAndMethodDef,
+    MethodAndSlotDef, PropertyType, SlotDef, __INT__, __REPR__, __RICHCMP__,
 };
 use crate::utils::{self, get_pyo3_crate, PythonDoc};
 use crate::PyFunctionOptions;
@@ -61,12 +61,14 @@ pub struct PyClassPyO3Options {
     pub krate: Option<CrateAttribute>,
     pub dict: Option<kw::dict>,
     pub extends: Option<ExtendsAttribute>,
+    pub extends_py: Option<ExtendsPyAttribute>,
     pub get_all: Option<kw::get_all>,
     pub freelist: Option<FreelistAttribute>,
     pub frozen: Option<kw::frozen>,
     pub mapping: Option<kw::mapping>,
     pub module: Option<ModuleAttribute>,
     pub name: Option<NameAttribute>,
+    pub pickle: Option<PickleAttribute>,
     pub sequence: Option<kw::sequence>,
     pub set_all: Option<kw::set_all>,
     pub subclass: Option<kw::subclass>,
@@ -81,12 +83,14 @@ enum PyClassPyO3Option {
     Crate(CrateAttribute),
     Dict(kw::dict),
     Extends(ExtendsAttribute),
+    ExtendsPy(ExtendsPyAttribute),
     Freelist(FreelistAttribute),
     Frozen(kw::frozen),
     GetAll(kw::get_all),
     Mapping(kw::mapping),
     Module(ModuleAttribute),
     Name(NameAttribute),
+    Pickle(PickleAttribute),
     Sequence(kw::sequence),
     SetAll(kw::set_all),
     Subclass(kw::subclass),
@@ -104,6 +108,8 @@ impl Parse for PyClassPyO3Option {
             input.parse().map(PyClassPyO3Option::Dict)
         } else if lookahead.peek(kw::extends) {
             input.parse().map(PyClassPyO3Option::Extends)
+        } else if lookahead.peek(attributes::kw::extends_py) {
+            input.parse().map(PyClassPyO3Option::ExtendsPy)
         } else if lookahead.peek(attributes::kw::freelist) {
             input.parse().map(PyClassPyO3Option::Freelist)
         } else if lookahead.peek(attributes::kw::frozen) {
@@ -116,6 +122,8 @@ impl Parse for PyClassPyO3Option {
             input.parse().map(PyClassPyO3Option::Module)
         } else if lookahead.peek(kw::name) {
             input.parse().map(PyClassPyO3Option::Name)
+        } else if lookahead.peek(attributes::kw::pickle) {
+            input.parse().map(PyClassPyO3Option::Pickle)
         } else if lookahead.peek(attributes::kw::sequence) {
             input.parse().map(PyClassPyO3Option::Sequence)
         } else if lookahead.peek(attributes::kw::set_all) {
@@ -168,12 +176,14 @@ impl PyClassPyO3Options {
             PyClassPyO3Option::Crate(krate) => set_option!(krate),
             PyClassPyO3Option::Dict(dict) => set_option!(dict),
             PyClassPyO3Option::Extends(extends) => set_option!(extends),
+            PyClassPyO3Option::ExtendsPy(extends_py) => set_option!(extends_py),
             PyClassPyO3Option::Freelist(freelist) => set_option!(freelist),
             PyClassPyO3Option::Frozen(frozen) => set_option!(frozen),
             PyClassPyO3Option::GetAll(get_all) => set_option!(get_all),
             PyClassPyO3Option::Mapping(mapping) => set_option!(mapping),
             PyClassPyO3Option::Module(module) => set_option!(module),
             PyClassPyO3Option::Name(name) => set_option!(name),
+            PyClassPyO3Option::Pickle(pickle) => set_option!(pickle),
             PyClassPyO3Option::Sequence(sequence) => set_option!(sequence),
             PyClassPyO3Option::SetAll(set_all) => set_option!(set_all),
             PyClassPyO3Option::Subclass(subclass) => set_option!(subclass),
@@ -353,15 +363,20 @@ fn impl_class(
 ) -> syn::Result<TokenStream> {
     let pytypeinfo_impl = impl_pytypeinfo(cls, args, Some(&args.options.deprecations));
 
-    let py_class_impl = PyClassImplsBuilder::new(
-        cls,
-        args,
-        methods_type,
-        descriptors_to_items(cls, field_options)?,
-        vec![],
-    )
-    .doc(doc)
-    .impl_all()?;
+    let mut pickle_impls = match &args.options.pickle {
+        Some(pickle) => pickle_struct_methods(args, pickle, &field_options)?,
+        None => Vec::new(),
+    };
+
+    let mut default_methods = descriptors_to_items(cls, field_options)?;
+    for (python_name, method) in &mut pickle_impls {
+        default_methods.push(generate_default_method(cls, method, python_name)?);
+    }
+    let pickle_impls = pickle_impls.iter().map(|(_, method)| method);
+
+    let py_class_impl = PyClassImplsBuilder::new(cls, args, methods_type, default_methods, vec![])
+        .doc(doc)
+        .impl_all()?;
 
     Ok(quote! {
         const _: () = {
@@ -370,10 +385,118 @@ fn impl_class(
             #pytypeinfo_impl
 
             #py_class_impl
+
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            impl #cls {
+                #(#pickle_impls)*
+            }
         };
     })
 }
 
+/// Generates the methods implementing the pickle and copy protocols for `#[pyclass(pickle)]`
+/// structs, along with their Python names.
+fn pickle_struct_methods(
+    args: &PyClassArgs,
+    pickle: &PickleAttribute,
+    field_options: &[(&syn::Field, FieldPyO3Options)],
+) -> Result<Vec<(&'static str, syn::ImplItemMethod)>> {
+    let mut methods = Vec::new();
+
+    // Neither the fields of a Rust base class nor their initialization can be recovered from
+    // the Rust value of this class alone.
+    ensure_spanned!(
+        args.options.extends.is_none(),
+        pickle.kw.span() => "`pickle` cannot be used together with `extends`"
+    );
+
+    if pickle.serde.is_some() {
+        methods.push((
+            "__reduce__",
+            parse_quote! {
+                fn __pyo3__reduce__(
+                    slf: &_pyo3::PyCell<Self>,
+                ) -> _pyo3::PyResult<_pyo3::PyObject> {
+                    _pyo3::impl_::pickle::serde::reduce(slf)
+                }
+            },
+        ));
+        // `__reduce__` looks this classmethod up by name to reconstruct the object.
+        methods.push((
+            "__pyo3_unpickle__",
+            parse_quote! {
+                #[classmethod]
+                fn __pyo3__unpickle__(
+                    cls: &_pyo3::types::PyType,
+                    state: &_pyo3::PyAny,
+                ) -> _pyo3::PyResult<_pyo3::PyObject> {
+                    _pyo3::impl_::pickle::serde::unpickle::<Self>(cls, state)
+                }
+            },
+        ));
+    } else {
+        // Fields without a Python name are rejected when generating their getter.
+        let kwargs = field_options
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, options))| options.get.is_some())
+            .filter_map(|(index, (field, options))| {
+                let name = match (&options.name, &field.ident) {
+                    (Some(name), _) => name.value.0.to_string(),
+                    (None, Some(ident)) => ident.unraw().to_string(),
+                    (None, None) => return None,
+                };
+                let member = match &field.ident {
+                    Some(ident) => quote!(#ident),
+                    None => {
+                        let index = syn::Index::from(index);
+                        quote!(#index)
+                    }
+                };
+                Some(quote! {
+                    (#name, _pyo3::IntoPy::<_pyo3::PyObject>::into_py(
+                        ::std::clone::Clone::clone(&self.#member),
+                        py,
+                    ))
+                })
+            });
+        methods.push((
+            "__getnewargs_ex__",
+            parse_quote! {
+                fn __pyo3__getnewargs_ex__(
+                    &self,
+                    py: _pyo3::Python<'_>,
+                ) -> _pyo3::PyResult<_pyo3::PyObject> {
+                    _pyo3::impl_::pickle::getnewargs_ex(py, &[#(#kwargs),*])
+                }
+            },
+        ));
+    }
+
+    methods.push((
+        "__copy__",
+        parse_quote! {
+            fn __pyo3__copy__(slf: &_pyo3::PyCell<Self>) -> _pyo3::PyResult<_pyo3::PyObject> {
+                _pyo3::impl_::pickle::copy(slf)
+            }
+        },
+    ));
+    methods.push((
+        "__deepcopy__",
+        parse_quote! {
+            fn __pyo3__deepcopy__(
+                slf: &_pyo3::PyCell<Self>,
+                memo: &_pyo3::PyAny,
+            ) -> _pyo3::PyResult<_pyo3::PyObject> {
+                _pyo3::impl_::pickle::deepcopy(slf, memo)
+            }
+        },
+    ));
+
+    Ok(methods)
+}
+
 struct PyClassEnumVariant<'a> {
     ident: &'a syn::Ident,
     options: EnumVariantPyO3Options,
@@ -446,6 +569,10 @@ pub fn build_py_enum(
 
     if let Some(extends) = &args.options.extends {
         bail_spanned!(extends.span() => "enums can't extend from other classes");
+    } else if let Some(serde) = args.options.pickle.as_ref().and_then(|p| p.serde.as_ref()) {
+        bail_spanned!(serde.span() => "enums are always pickled by variant name; use `pickle` instead");
+    } else if let Some(extends_py) = &args.options.extends_py {
+        bail_spanned!(extends_py.span() => "enums can't extend from other classes");
     } else if let Some(subclass) = &args.options.subclass {
         bail_spanned!(subclass.span() => "enums can't be inherited by other classes");
     } else if enum_.variants.is_empty() {
@@ -594,15 +721,40 @@ fn impl_enum(
 
     let default_slots = vec![default_repr_slot, default_int_slot, default_richcmp_slot];
 
-    let pyclass_impls = PyClassImplsBuilder::new(
-        cls,
-        args,
-        methods_type,
-        enum_default_methods(cls, variants.iter().map(|v| (v.ident, v.python_name()))),
-        default_slots,
-    )
-    .doc(doc)
-    .impl_all()?;
+    let mut default_methods =
+        enum_default_methods(cls, variants.iter().map(|v| (v.ident, v.python_name())));
+
+    let default_reduce = if args.options.pickle.is_some() {
+        let variants_name = variants.iter().map(|variant| {
+            let variant_name = variant.ident;
+            let python_name = variant.python_name().to_string();
+            quote! { #cls::#variant_name => #python_name, }
+        });
+        let mut reduce_impl: syn::ImplItemMethod = syn::parse_quote! {
+            fn __pyo3__reduce__(
+                &self,
+                py: _pyo3::Python<'_>,
+            ) -> _pyo3::PyResult<_pyo3::PyObject> {
+                let variant = match self {
+                    #(#variants_name)*
+                };
+                _pyo3::impl_::pickle::reduce_enum_variant::<Self>(py, variant)
+            }
+        };
+        default_methods.push(generate_default_method(
+            cls,
+            &mut reduce_impl,
+            "__reduce__",
+        )?);
+        Some(reduce_impl)
+    } else {
+        None
+    };
+
+    let pyclass_impls =
+        PyClassImplsBuilder::new(cls, args, methods_type, default_methods, default_slots)
+            .doc(doc)
+            .impl_all()?;
 
     Ok(quote! {
         const _: () = {
@@ -618,6 +770,7 @@ fn impl_enum(
                 #default_repr
                 #default_int
                 #default_richcmp
+                #default_reduce
             }
         };
     })
@@ -642,6 +795,31 @@ fn generate_default_protocol_slot(
     )
 }
 
+fn generate_default_method(
+    cls: &syn::Ident,
+    method: &mut syn::ImplItemMethod,
+    python_name: &str,
+) -> syn::Result<MethodAndMethodDef> {
+    let options = PyFunctionOptions {
+        name: Some(NameAttribute {
+            kw: syn::parse_quote! { name },
+            value: NameLitStr(syn::Ident::new(python_name, Span::call_site())),
+        }),
+        ..Default::default()
+    };
+    let spec = FnSpec::parse(&mut method.sig, &mut method.attrs, options)?;
+    let flags = match spec.tp {
+        FnType::FnClass => Some(quote!(_pyo3::ffi::METH_CLASS)),
+        _ => None,
+    };
+    impl_py_method_def(
+        &syn::parse_quote!(#cls),
+        &spec,
+        &spec.get_doc(&method.attrs),
+        flags,
+    )
+}
+
 fn enum_default_methods<'a>(
     cls: &'a syn::Ident,
     unit_variant_names: impl IntoIterator<Item = (&'a syn::Ident, Cow<'a, syn::Ident>)>,
@@ -895,6 +1073,17 @@ impl<'a> PyClassImplsBuilder<'a> {
             .map(|extends_attr| extends_attr.value.clone())
             .unwrap_or_else(|| parse_quote! { _pyo3::PyAny });
         let is_subclass = self.attr.options.extends.is_some();
+        let python_base_type = match &self.attr.options.extends_py {
+            Some(extends_py) => {
+                let path = extends_py.value.value();
+                ensure_spanned!(
+                    path.split('.').all(|part| !part.is_empty()) && path.contains('.'),
+                    extends_py.value.span() => "expected a Python class path of the form \"module.Class\""
+                );
+                quote! { ::std::option::Option::Some(#path) }
+            }
+            None => quote! { ::std::option::Option::None },
+        };
         let is_mapping: bool = self.attr.options.mapping.is_some();
         let is_sequence: bool = self.attr.options.sequence.is_some();
 
@@ -1010,6 +1199,7 @@ impl<'a> PyClassImplsBuilder<'a> {
                 const IS_SUBCLASS: bool = #is_subclass;
                 const IS_MAPPING: bool = #is_mapping;
                 const IS_SEQUENCE: bool = #is_sequence;
+                const PYTHON_BASE_TYPE: ::std::option::Option<&'static str> = #python_base_type;
 
                 type Layout = _pyo3::PyCell<Self>;
                 type BaseType = #base;
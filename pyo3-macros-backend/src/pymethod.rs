@@ -188,6 +188,7 @@ pub fn gen_py_method(
     ensure_function_options_valid(&options)?;
     let method = PyMethod::parse(sig, meth_attrs, options)?;
     let spec = &method.spec;
+    ensure_hook_arguments_valid(spec, &method.method_name)?;
 
     Ok(match (method.kind, &spec.tp) {
         // Class attributes go before protos so that class attributes can be used to set proto
@@ -296,6 +297,34 @@ fn ensure_no_forbidden_protocol_attributes(
     Ok(())
 }
 
+/// Checks the arguments of the magic methods which Python calls with a fixed argument list, but
+/// which are not implemented through type slots.
+fn ensure_hook_arguments_valid(spec: &FnSpec<'_>, method_name: &str) -> syn::Result<()> {
+    let (expected, arguments_description) = match (method_name, &spec.tp) {
+        (_, FnType::ClassAttribute) => return Ok(()),
+        ("__class_getitem__", _) => (1, "one argument (`item`) besides the class"),
+        ("__set_name__", FnType::Fn(_)) => (2, "two arguments (`owner` and `name`) besides `self`"),
+        ("__set_name__", _) => {
+            bail_spanned!(spec.name.span() => "`__set_name__` must be an instance method")
+        }
+        _ => return Ok(()),
+    };
+    let arguments = spec.signature.arguments.iter().filter(|arg| !arg.py);
+    let mut count = 0;
+    for arg in arguments {
+        ensure_spanned!(
+            !arg.is_varargs && !arg.is_kwargs,
+            arg.name.span() => format!("`{}` cannot take `*args` or `**kwargs`", method_name)
+        );
+        count += 1;
+    }
+    ensure_spanned!(
+        count == expected,
+        spec.name.span() => format!("`{}` must take exactly {}", method_name, arguments_description)
+    );
+    Ok(())
+}
+
 /// Also used by pyfunction.
 pub fn impl_py_method_def(
     cls: &syn::Type,
@@ -19,7 +19,8 @@
 
 //! `PyBuffer` implementation
 use crate::{
-    err, exceptions::PyBufferError, ffi, AsPyPointer, FromPyObject, PyAny, PyResult, Python,
+    err, exceptions::PyBufferError, ffi, marker::Detached, AsPyPointer, FromPyObject, PyAny,
+    PyResult, Python,
 };
 use std::marker::PhantomData;
 use std::os::raw;
@@ -459,6 +460,31 @@ impl<T: Element> PyBuffer<T> {
         }
     }
 
+    /// Gets the buffer memory as a slice which can be used while the GIL is released.
+    ///
+    /// This function succeeds if:
+    /// * the buffer is read-only
+    /// * the buffer format is compatible with `T`
+    /// * alignment and size of buffer elements is matching the expectations for type `T`
+    /// * the buffer is C-style contiguous
+    ///
+    /// The exporter can't reallocate the memory while the buffer is held, and the buffer protocol
+    /// doesn't allow writing to read-only buffers, such as those exported by `bytes`. See
+    /// [`Detached`] for how to use the slice with [`Python::allow_threads`].
+    pub fn detach_slice<'a>(&'a self, _py: Python<'a>) -> Option<Detached<'a, [T]>> {
+        if self.readonly() && self.is_c_contiguous() {
+            // SAFETY: the buffer is read-only and stays alive for `'a`.
+            Some(unsafe {
+                Detached::new(slice::from_raw_parts(
+                    self.0.buf as *const T,
+                    self.item_count(),
+                ))
+            })
+        } else {
+            None
+        }
+    }
+
     /// Copies the buffer elements to the specified slice.
     /// If the buffer is multi-dimensional, the elements are written in C-style order.
     ///
@@ -890,6 +916,10 @@ mod tests {
 
             assert!(buffer.copy_from_slice(py, &[0u8; 5]).is_err());
             assert_eq!(buffer.to_vec(py).unwrap(), b"abcde");
+
+            let detached = buffer.detach_slice(py).unwrap();
+            let count = py.allow_threads(move || detached.iter().filter(|&&b| b > b'b').count());
+            assert_eq!(count, 3);
         });
     }
 
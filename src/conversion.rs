@@ -8,7 +8,7 @@ use crate::pyclass::boolean_struct::False;
 use crate::type_object::PyTypeInfo;
 use crate::types::PyTuple;
 use crate::{
-    ffi, gil, Py, PyAny, PyCell, PyClass, PyNativeType, PyObject, PyRef, PyRefMut, Python,
+    ffi, gil, Bound, Py, PyAny, PyCell, PyClass, PyNativeType, PyObject, PyRef, PyRefMut, Python,
 };
 use std::cell::Cell;
 use std::ptr::NonNull;
@@ -276,6 +276,16 @@ pub trait FromPyObject<'source>: Sized {
     /// Extracts `Self` from the source `PyObject`.
     fn extract(ob: &'source PyAny) -> PyResult<Self>;
 
+    /// Extracts `Self` from a [`Bound`] reference to the source `PyObject`.
+    ///
+    /// This is used by [`Bound::extract`]. The default implementation extracts from the GIL-bound
+    /// reference the `Bound` dereferences to, which is correct for any type; implementations
+    /// may override it to avoid creating GIL-bound references.
+    #[inline]
+    fn extract_bound(ob: &'source Bound<'_, PyAny>) -> PyResult<Self> {
+        Self::extract(ob.as_gil_ref())
+    }
+
     /// Extracts the type hint information for this type when it appears as an argument.
     ///
     /// For example, `Vec<u32>` would return `Sequence[int]`.
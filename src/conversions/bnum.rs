@@ -0,0 +1,196 @@
+#![cfg(feature = "bnum")]
+//! Conversions to and from [bnum](https://docs.rs/bnum)'s fixed-width [`BUint`] and [`BInt`]
+//! types, along with their variants using narrower digits such as [`BUintD8`].
+//!
+//! This is useful for converting Python integers which are too large for Rust's built-in integer
+//! types. Unlike many other big integer crates, bnum also provides signed types such as
+//! [`I256`](bnum::types::I256).
+//!
+//! The conversions are also available when using the limited API (`abi3`).
+//!
+//! # Setup
+//!
+//! To use this feature, add this to your **`Cargo.toml`**:
+//!
+//! ```toml
+//! [dependencies]
+//! bnum = "0.10"
+// workaround for `extended_key_value_attributes`: https://github.com/rust-lang/rust/issues/82768#issuecomment-803935643
+#![cfg_attr(docsrs, cfg_attr(docsrs, doc = concat!("pyo3 = { version = \"", env!("CARGO_PKG_VERSION"),  "\", features = [\"bnum\"] }")))]
+#![cfg_attr(not(docsrs), doc = "pyo3 = { version = \"*\", features = [\"bnum\"] }")]
+//! ```
+//!
+//! Note that you must use compatible versions of bnum and PyO3.
+//! The required bnum version may vary based on the version of PyO3.
+//! This feature requires Rust 1.65 or greater.
+//!
+//! # Example
+//!
+//! Extracting a Python integer which does not fit into the target type fails with `OverflowError`.
+//!
+//! ```rust
+//! use bnum::types::I256;
+//! use pyo3::prelude::*;
+//!
+//! #[pyfunction]
+//! fn negate(n: I256) -> Option<I256> {
+//!     n.checked_neg()
+//! }
+//!
+//! #[pymodule]
+//! fn my_module(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+//!     m.add_function(wrap_pyfunction!(negate, m)?)?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Python code:
+//! ```python
+//! from my_module import negate
+//!
+//! assert negate(2 ** 255 - 1) == -(2 ** 255 - 1)
+//! assert negate(-(2 ** 255)) is None
+//! ```
+
+use crate::exceptions::PyOverflowError;
+use crate::types::{Endianness, PyLong};
+use crate::{FromPyObject, IntoPy, PyAny, PyObject, PyResult, Python, ToPyObject};
+
+use bnum::{BInt, BIntD16, BIntD32, BIntD8, BUint, BUintD16, BUintD32, BUintD8};
+
+macro_rules! bnum_conversion {
+    ($uint: ident, $int: ident, $digit: ty) => {
+        bnum_conversion!(@impl $uint, false, |value: &$uint<N>| *value);
+        bnum_conversion!(@impl $int, true, |value: &$int<N>| value.to_bits());
+    };
+    (@impl $rust_ty: ident, $is_signed: expr, $to_bits: expr) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "bnum")))]
+        impl<const N: usize> ToPyObject for $rust_ty<N> {
+            fn to_object(&self, py: Python<'_>) -> PyObject {
+                // Signed values are converted from their two's complement representation
+                let bits = $to_bits(self);
+                let digits = bits.digits();
+                // On little endian targets, the memory of the digits is their little endian
+                // byte representation
+                #[cfg(target_endian = "little")]
+                let bytes = unsafe {
+                    std::slice::from_raw_parts(
+                        digits.as_ptr().cast::<u8>(),
+                        std::mem::size_of_val(digits),
+                    )
+                };
+                #[cfg(target_endian = "big")]
+                let bytes = &digits
+                    .iter()
+                    .flat_map(|digit| digit.to_le_bytes())
+                    .collect::<Vec<u8>>();
+                unsafe {
+                    PyObject::from_owned_ptr(
+                        py,
+                        PyLong::from_bytes_ptr(py, bytes, Endianness::Little, $is_signed),
+                    )
+                }
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "bnum")))]
+        impl<const N: usize> IntoPy<PyObject> for $rust_ty<N> {
+            fn into_py(self, py: Python<'_>) -> PyObject {
+                self.to_object(py)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "bnum")))]
+        impl<'source, const N: usize> FromPyObject<'source> for $rust_ty<N> {
+            fn extract(ob: &'source PyAny) -> PyResult<Self> {
+                let mut buffer = vec![0; Self::BYTES as usize];
+                PyLong::index(ob)?.to_bytes_into(&mut buffer, Endianness::Little, $is_signed)?;
+                Self::from_le_slice(&buffer)
+                    .ok_or_else(|| PyOverflowError::new_err("int too big to convert"))
+            }
+        }
+    };
+}
+
+bnum_conversion!(BUint, BInt, u64);
+bnum_conversion!(BUintD32, BIntD32, u32);
+bnum_conversion!(BUintD16, BIntD16, u16);
+bnum_conversion!(BUintD8, BIntD8, u8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exceptions::PyOverflowError;
+    use bnum::types::{I256, U256};
+
+    #[test]
+    fn test_u256_roundtrip() {
+        Python::with_gil(|py| {
+            let max: PyObject = py.eval("2 ** 256 - 1", None, None).unwrap().into();
+            let value: U256 = max.extract(py).unwrap();
+            assert_eq!(value, U256::MAX);
+            assert!(value.into_py(py).as_ref(py).eq(max.as_ref(py)).unwrap());
+        });
+    }
+
+    #[test]
+    fn test_i256_roundtrip() {
+        Python::with_gil(|py| {
+            for (code, expected) in [
+                ("-(2 ** 255)", I256::MIN),
+                ("2 ** 255 - 1", I256::MAX),
+                ("-1", I256::NEG_ONE),
+                ("0", I256::ZERO),
+            ] {
+                let obj = py.eval(code, None, None).unwrap();
+                let value: I256 = obj.extract().unwrap();
+                assert_eq!(value, expected, "{}", code);
+                assert!(value.into_py(py).as_ref(py).eq(obj).unwrap(), "{}", code);
+            }
+        });
+    }
+
+    #[test]
+    fn test_narrow_digits() {
+        Python::with_gil(|py| {
+            let value = -0x1234_5678_9abc_def0_0fed_cba9_8765i128;
+            let obj = value.to_object(py);
+            let d8: BIntD8<16> = obj.extract(py).unwrap();
+            let d16: BIntD16<8> = obj.extract(py).unwrap();
+            let d32: BIntD32<4> = obj.extract(py).unwrap();
+            assert_eq!(d8.to_object(py).extract::<i128>(py).unwrap(), value);
+            assert_eq!(d16.to_object(py).extract::<i128>(py).unwrap(), value);
+            assert_eq!(d32.to_object(py).extract::<i128>(py).unwrap(), value);
+
+            let small: BUintD8<3> = 0xabcdefu32.to_object(py).extract(py).unwrap();
+            assert_eq!(small.into_py(py).extract::<u32>(py).unwrap(), 0xabcdef);
+        });
+    }
+
+    #[test]
+    fn test_overflow() {
+        Python::with_gil(|py| {
+            for code in ["2 ** 255", "-(2 ** 255) - 1"] {
+                let obj = py.eval(code, None, None).unwrap();
+                let err = obj.extract::<I256>().unwrap_err();
+                assert!(err.is_instance_of::<PyOverflowError>(py), "{}", code);
+            }
+
+            let negative = py.eval("-1", None, None).unwrap();
+            let err = negative.extract::<U256>().unwrap_err();
+            assert!(err.is_instance_of::<PyOverflowError>(py));
+        });
+    }
+
+    #[test]
+    fn test_to_object_does_not_grow_pool() {
+        Python::with_gil(|py| {
+            let count = crate::gil::owned_object_count();
+            for _ in 0..10 {
+                let obj = I256::MIN.to_object(py);
+                assert_eq!(obj.extract::<I256>(py).unwrap(), I256::MIN);
+            }
+            assert_eq!(crate::gil::owned_object_count(), count);
+        });
+    }
+}
@@ -0,0 +1,301 @@
+#![cfg(feature = "log")]
+
+//! Bridges between Python's [`logging`] module and the [log] crate.
+//!
+//! [`forward_python_logging`] installs a `logging.Handler` which forwards the records of Python
+//! loggers to the Rust logger, so that they end up in the same pipeline as the application's own
+//! log messages. [`PythonLogger`] does the opposite, and emits the records of the `log` macros
+//! into Python's `logging` module.
+//!
+//! Records are not forwarded back to where they came from, so both directions can be used
+//! together.
+//!
+//! # Setup
+//!
+//! To use this feature, add this to your **`Cargo.toml`**:
+//!
+//! ```toml
+//! [dependencies]
+//! log = "0.4"
+// workaround for `extended_key_value_attributes`: https://github.com/rust-lang/rust/issues/82768#issuecomment-803935643
+#![cfg_attr(docsrs, cfg_attr(docsrs, doc = concat!("pyo3 = { version = \"", env!("CARGO_PKG_VERSION"),  "\", features = [\"log\"] }")))]
+#![cfg_attr(not(docsrs), doc = "pyo3 = { version = \"*\", features = [\"log\"] }")]
+//! ```
+//!
+//! # Example: Forwarding Python `logging` to the Rust logger
+//!
+//! ```rust
+//! use pyo3::prelude::*;
+//!
+//! # fn main() -> PyResult<()> {
+//! // Set up any `log` implementation, for example `env_logger`.
+//! log::set_max_level(log::LevelFilter::Info);
+//!
+//! Python::with_gil(|py| {
+//!     pyo3::log::forward_python_logging(py)?;
+//!     py.run("import logging; logging.getLogger('app').info('started')", None, None)
+//! })
+//! # }
+//! ```
+//!
+//! [`logging`]: https://docs.python.org/3/library/logging.html
+//! [log]: https://docs.rs/log
+
+use crate::exceptions::PyKeyError;
+use crate::gil::thread_local_const_init;
+use crate::sync::GILOnceCell;
+use crate::types::{PyCFunction, PyDict, PyTuple, PyType};
+use crate::{ffi, Py, PyAny, PyErr, PyResult, Python};
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+use std::cell::Cell;
+
+thread_local_const_init! {
+    /// Set while a record of a Python logger is passed to the Rust logger.
+    static FORWARDING_FROM_PYTHON: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Installs a handler on Python's root logger which forwards all records to the Rust logger.
+///
+/// The records keep the logger name as their target, and the file, line and module of the
+/// Python code which emitted them. Exception information and stack traces are appended to the
+/// message. Python's log levels are mapped to the nearest lower [`Level`].
+///
+/// The handler is returned so that it can be removed again.
+///
+/// # Root logger level
+///
+/// This function overwrites the level of Python's root logger with the level matching
+/// [`log::max_level`], which should be configured before calling it. Otherwise the root logger's
+/// default level of `WARNING` would drop most records before they reach the handler. To use a
+/// different level, call the root logger's `setLevel` afterwards.
+pub fn forward_python_logging(py: Python<'_>) -> PyResult<&PyAny> {
+    let emit = PyCFunction::new_closure(py, None, None, |args: &PyTuple, _| {
+        let (levelno, target, message, file, line, module): (i32, &str, &str, &str, u32, &str) =
+            args.extract()?;
+        let _guard = ForwardingGuard::new();
+        log::logger().log(
+            &Record::builder()
+                .level(level_from_python(levelno))
+                .target(target)
+                .args(format_args!("{}", message))
+                .file(Some(file))
+                .line(Some(line))
+                .module_path(Some(module))
+                .build(),
+        );
+        Ok::<_, PyErr>(())
+    })?;
+
+    let handler = handler_class(py)?.call1((emit,))?;
+    let root = py.import("logging")?.call_method0("getLogger")?;
+    root.call_method1("setLevel", (level_to_python(log::max_level()),))?;
+    root.call_method1("addHandler", (handler,))?;
+    Ok(handler)
+}
+
+/// Sets `FORWARDING_FROM_PYTHON` until dropped, even if the Rust logger panics.
+struct ForwardingGuard;
+
+impl ForwardingGuard {
+    fn new() -> Self {
+        FORWARDING_FROM_PYTHON.with(|forwarding| forwarding.set(true));
+        ForwardingGuard
+    }
+}
+
+impl Drop for ForwardingGuard {
+    fn drop(&mut self) {
+        FORWARDING_FROM_PYTHON.with(|forwarding| forwarding.set(false));
+    }
+}
+
+/// Returns the `logging.Handler` subclass defined by [`HANDLER_SOURCE`].
+fn handler_class(py: Python<'_>) -> PyResult<&PyType> {
+    static HANDLER_CLASS: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+    HANDLER_CLASS
+        .per_interpreter(py)?
+        .get_or_try_init(py, || {
+            let globals = PyDict::new(py);
+            globals.set_item("__name__", "pyo3.log")?;
+            py.run(HANDLER_SOURCE, Some(globals), None)?;
+            globals
+                .get_item("RustHandler")
+                .ok_or_else(|| PyKeyError::new_err("RustHandler"))?
+                .extract()
+        })
+        .map(|class| class.as_ref(py))
+}
+
+/// The Python side of [`forward_python_logging`].
+const HANDLER_SOURCE: &str = r#"
+import logging
+
+_formatter = logging.Formatter()
+
+
+class RustHandler(logging.Handler):
+    def __init__(self, emit):
+        super().__init__()
+        self._emit = emit
+
+    def emit(self, record):
+        if getattr(record, "_pyo3_from_rust", False):
+            return
+        try:
+            message = record.getMessage()
+            if record.exc_info:
+                message += "\n" + _formatter.formatException(record.exc_info)
+            if record.stack_info:
+                message += "\n" + _formatter.formatStack(record.stack_info)
+            self._emit(
+                record.levelno,
+                record.name,
+                message,
+                record.pathname,
+                record.lineno,
+                record.module,
+            )
+        except Exception:
+            self.handleError(record)
+"#;
+
+/// A Rust logger which emits records into Python's `logging` module.
+///
+/// The target of each record is used as the logger name, with `::` replaced by `.`, so that
+/// `log::info!` in `my_crate::db` is handled by the Python logger `my_crate.db`. Records are only
+/// emitted when the Python interpreter is initialized, and Python's logger configuration decides
+/// whether they are handled.
+///
+/// # Examples
+///
+/// ```rust
+/// use log::LevelFilter;
+/// use pyo3::log::PythonLogger;
+///
+/// # fn main() -> Result<(), log::SetLoggerError> {
+/// pyo3::prepare_freethreaded_python();
+/// PythonLogger::install(LevelFilter::Info)?;
+/// log::warn!("this is handled by Python's root logger");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PythonLogger;
+
+static LOGGER: PythonLogger = PythonLogger;
+
+impl PythonLogger {
+    /// Sets `PythonLogger` as the Rust logger, with `max_level` as the maximum level.
+    pub fn install(max_level: LevelFilter) -> Result<(), SetLoggerError> {
+        log::set_logger(&LOGGER)?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+}
+
+impl Log for PythonLogger {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        !FORWARDING_FROM_PYTHON.with(Cell::get) && unsafe { ffi::Py_IsInitialized() } != 0
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        Python::with_gil(|py| {
+            if let Err(err) = log_to_python(py, record) {
+                err.write_unraisable(py, None);
+            }
+        })
+    }
+
+    fn flush(&self) {}
+}
+
+fn log_to_python(py: Python<'_>, record: &Record<'_>) -> PyResult<()> {
+    let name = record.target().replace("::", ".");
+    let level = level_to_python(record.level().to_level_filter());
+    let logger = py
+        .import("logging")?
+        .call_method1("getLogger", (name.as_str(),))?;
+    if !logger.call_method1("isEnabledFor", (level,))?.is_true()? {
+        return Ok(());
+    }
+    let extra = PyDict::new(py);
+    extra.set_item("_pyo3_from_rust", true)?;
+    let record = logger.call_method1(
+        "makeRecord",
+        (
+            name,
+            level,
+            record.file().unwrap_or("(unknown file)"),
+            record.line().unwrap_or(0),
+            record.args().to_string(),
+            (),
+            py.None(),
+            py.None(),
+            extra,
+        ),
+    )?;
+    logger.call_method1("handle", (record,))?;
+    Ok(())
+}
+
+fn level_from_python(levelno: i32) -> Level {
+    match levelno {
+        40..=i32::MAX => Level::Error,
+        30..=39 => Level::Warn,
+        20..=29 => Level::Info,
+        10..=19 => Level::Debug,
+        _ => Level::Trace,
+    }
+}
+
+fn level_to_python(level: LevelFilter) -> i32 {
+    match level {
+        LevelFilter::Off => 51,
+        LevelFilter::Error => 40,
+        LevelFilter::Warn => 30,
+        LevelFilter::Info => 20,
+        LevelFilter::Debug => 10,
+        LevelFilter::Trace => 5,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{level_from_python, level_to_python, ForwardingGuard, FORWARDING_FROM_PYTHON};
+    use log::{Level, LevelFilter};
+    use std::cell::Cell;
+
+    #[test]
+    fn test_levels() {
+        for &level in &[
+            Level::Error,
+            Level::Warn,
+            Level::Info,
+            Level::Debug,
+            Level::Trace,
+        ] {
+            assert_eq!(
+                level_from_python(level_to_python(level.to_level_filter())),
+                level
+            );
+        }
+        assert_eq!(level_from_python(50), Level::Error);
+        assert_eq!(level_from_python(25), Level::Info);
+        assert_eq!(level_from_python(0), Level::Trace);
+        assert_eq!(level_to_python(LevelFilter::Off), 51);
+    }
+
+    #[test]
+    fn test_forwarding_guard_resets_on_panic() {
+        let result = std::panic::catch_unwind(|| {
+            let _guard = ForwardingGuard::new();
+            assert!(FORWARDING_FROM_PYTHON.with(Cell::get));
+            panic!("logger panicked");
+        });
+        assert!(result.is_err());
+        assert!(!FORWARDING_FROM_PYTHON.with(Cell::get));
+    }
+}
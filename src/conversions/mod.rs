@@ -1,12 +1,16 @@
 //! This module contains conversions between various Rust object and their representation in Python.
 
 pub mod anyhow;
+pub mod bnum;
 pub mod chrono;
 pub mod eyre;
 pub mod hashbrown;
 pub mod indexmap;
+pub mod log;
 pub mod num_bigint;
 pub mod num_complex;
+pub mod primitive_types;
+pub mod ruint;
 pub mod rust_decimal;
 pub mod serde;
 mod std;
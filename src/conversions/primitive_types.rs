@@ -0,0 +1,166 @@
+#![cfg(feature = "primitive-types")]
+//! Conversions to and from [primitive-types](https://docs.rs/primitive-types)'
+//! [`U128`], [`U256`] and [`U512`] types.
+//!
+//! This is useful for converting Python integers which are too large for Rust's built-in integer
+//! types, such as the 256-bit unsigned integers used by many blockchain libraries.
+//!
+//! The conversions are also available when using the limited API (`abi3`).
+//!
+//! # Setup
+//!
+//! To use this feature, add this to your **`Cargo.toml`**:
+//!
+//! ```toml
+//! [dependencies]
+//! primitive-types = "0.12"
+// workaround for `extended_key_value_attributes`: https://github.com/rust-lang/rust/issues/82768#issuecomment-803935643
+#![cfg_attr(docsrs, cfg_attr(docsrs, doc = concat!("pyo3 = { version = \"", env!("CARGO_PKG_VERSION"),  "\", features = [\"primitive-types\"] }")))]
+#![cfg_attr(
+    not(docsrs),
+    doc = "pyo3 = { version = \"*\", features = [\"primitive-types\"] }"
+)]
+//! ```
+//!
+//! Note that you must use compatible versions of primitive-types and PyO3.
+//! The required primitive-types version may vary based on the version of PyO3.
+//! This feature requires Rust 1.60 or greater.
+//!
+//! # Example
+//!
+//! Extracting a Python integer which does not fit into the target type fails with `OverflowError`.
+//!
+//! ```rust
+//! use primitive_types::U256;
+//! use pyo3::prelude::*;
+//!
+//! #[pyfunction]
+//! fn add_one(n: U256) -> Option<U256> {
+//!     n.checked_add(U256::one())
+//! }
+//!
+//! #[pymodule]
+//! fn my_module(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+//!     m.add_function(wrap_pyfunction!(add_one, m)?)?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Python code:
+//! ```python
+//! from my_module import add_one
+//!
+//! assert add_one(2 ** 255) == 2 ** 255 + 1
+//! assert add_one(2 ** 256 - 1) is None
+//! ```
+
+use crate::types::{Endianness, PyLong};
+use crate::{FromPyObject, IntoPy, PyAny, PyObject, PyResult, Python, ToPyObject};
+
+use primitive_types::{U128, U256, U512};
+
+macro_rules! uint_conversion {
+    ($rust_ty: ty) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "primitive-types")))]
+        impl ToPyObject for $rust_ty {
+            fn to_object(&self, py: Python<'_>) -> PyObject {
+                let mut bytes = [0; std::mem::size_of::<$rust_ty>()];
+                self.to_little_endian(&mut bytes);
+                unsafe {
+                    PyObject::from_owned_ptr(
+                        py,
+                        PyLong::from_bytes_ptr(py, &bytes, Endianness::Little, false),
+                    )
+                }
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "primitive-types")))]
+        impl IntoPy<PyObject> for $rust_ty {
+            fn into_py(self, py: Python<'_>) -> PyObject {
+                self.to_object(py)
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "primitive-types")))]
+        impl<'source> FromPyObject<'source> for $rust_ty {
+            fn extract(ob: &'source PyAny) -> PyResult<$rust_ty> {
+                let mut bytes = [0; std::mem::size_of::<$rust_ty>()];
+                PyLong::index(ob)?.to_bytes_into(&mut bytes, Endianness::Little, false)?;
+                Ok(<$rust_ty>::from_little_endian(&bytes))
+            }
+        }
+    };
+}
+
+uint_conversion!(U128);
+uint_conversion!(U256);
+uint_conversion!(U512);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exceptions::PyOverflowError;
+
+    #[test]
+    fn test_u256_roundtrip() {
+        Python::with_gil(|py| {
+            let max: PyObject = py.eval("2 ** 256 - 1", None, None).unwrap().into();
+            let value: U256 = max.extract(py).unwrap();
+            assert_eq!(value, U256::MAX);
+            assert!(value.into_py(py).as_ref(py).eq(max.as_ref(py)).unwrap());
+
+            let small = U256::from(42).into_py(py);
+            assert_eq!(small.extract::<u64>(py).unwrap(), 42);
+            assert_eq!(small.extract::<U256>(py).unwrap(), U256::from(42));
+        });
+    }
+
+    #[test]
+    fn test_matches_u128() {
+        Python::with_gil(|py| {
+            let value = 0x1234_5678_9abc_def0_0fed_cba9_8765_4321u128;
+            let obj = U128::from(value).to_object(py);
+            assert_eq!(obj.extract::<u128>(py).unwrap(), value);
+            assert_eq!(
+                value.to_object(py).extract::<U128>(py).unwrap(),
+                U128::from(value)
+            );
+        });
+    }
+
+    #[test]
+    fn test_u512() {
+        Python::with_gil(|py| {
+            let obj = py.eval("3 ** 300", None, None).unwrap();
+            let value: U512 = obj.extract().unwrap();
+            assert_eq!(value, U512::from(3).pow(U512::from(300)));
+            assert!(value.into_py(py).as_ref(py).eq(obj).unwrap());
+        });
+    }
+
+    #[test]
+    fn test_overflow() {
+        Python::with_gil(|py| {
+            let too_big = py.eval("2 ** 256", None, None).unwrap();
+            let err = too_big.extract::<U256>().unwrap_err();
+            assert!(err.is_instance_of::<PyOverflowError>(py));
+
+            let negative = py.eval("-1", None, None).unwrap();
+            let err = negative.extract::<U128>().unwrap_err();
+            assert!(err.is_instance_of::<PyOverflowError>(py));
+        });
+    }
+
+    #[test]
+    fn test_to_object_does_not_grow_pool() {
+        Python::with_gil(|py| {
+            let count = crate::gil::owned_object_count();
+            for _ in 0..10 {
+                let obj = U256::MAX.to_object(py);
+                assert_eq!(obj.extract::<U256>(py).unwrap(), U256::MAX);
+            }
+            assert_eq!(crate::gil::owned_object_count(), count);
+        });
+    }
+}
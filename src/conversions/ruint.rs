@@ -0,0 +1,191 @@
+#![cfg(feature = "ruint")]
+//! Conversions to and from [ruint](https://docs.rs/ruint)'s [`Uint`] type.
+//!
+//! This is useful for converting Python integers which are too large for Rust's built-in integer
+//! types, such as the 256-bit unsigned integers used by many blockchain libraries.
+//!
+//! The conversions are also available when using the limited API (`abi3`).
+//!
+//! # Setup
+//!
+//! To use this feature, add this to your **`Cargo.toml`**:
+//!
+//! ```toml
+//! [dependencies]
+//! ruint = "1.12"
+// workaround for `extended_key_value_attributes`: https://github.com/rust-lang/rust/issues/82768#issuecomment-803935643
+#![cfg_attr(docsrs, cfg_attr(docsrs, doc = concat!("pyo3 = { version = \"", env!("CARGO_PKG_VERSION"),  "\", features = [\"ruint\"] }")))]
+#![cfg_attr(
+    not(docsrs),
+    doc = "pyo3 = { version = \"*\", features = [\"ruint\"] }"
+)]
+//! ```
+//!
+//! Note that you must use compatible versions of ruint and PyO3.
+//! The required ruint version may vary based on the version of PyO3.
+//! This feature requires Rust 1.65 or greater.
+//!
+//! # Example
+//!
+//! Extracting a Python integer which does not fit into the target type fails with `OverflowError`.
+//!
+//! ```rust
+//! use pyo3::prelude::*;
+//! use ruint::aliases::U256;
+//!
+//! #[pyfunction]
+//! fn add_one(n: U256) -> Option<U256> {
+//!     n.checked_add(U256::from(1))
+//! }
+//!
+//! #[pymodule]
+//! fn my_module(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+//!     m.add_function(wrap_pyfunction!(add_one, m)?)?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Python code:
+//! ```python
+//! from my_module import add_one
+//!
+//! assert add_one(2 ** 255) == 2 ** 255 + 1
+//! assert add_one(2 ** 256 - 1) is None
+//! ```
+
+use crate::exceptions::PyOverflowError;
+use crate::types::{Endianness, PyLong};
+use crate::{FromPyObject, IntoPy, PyAny, PyObject, PyResult, Python, ToPyObject};
+
+use ruint::Uint;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ruint")))]
+impl<const BITS: usize, const LIMBS: usize> ToPyObject for Uint<BITS, LIMBS> {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        // The limbs are already in little endian order on little endian targets
+        #[cfg(target_endian = "little")]
+        let bytes = self.as_le_slice();
+        #[cfg(target_endian = "big")]
+        let bytes = &self
+            .as_limbs()
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect::<Vec<u8>>();
+        unsafe {
+            PyObject::from_owned_ptr(
+                py,
+                PyLong::from_bytes_ptr(py, bytes, Endianness::Little, false),
+            )
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ruint")))]
+impl<const BITS: usize, const LIMBS: usize> IntoPy<PyObject> for Uint<BITS, LIMBS> {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.to_object(py)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "ruint")))]
+impl<'source, const BITS: usize, const LIMBS: usize> FromPyObject<'source> for Uint<BITS, LIMBS> {
+    fn extract(ob: &'source PyAny) -> PyResult<Self> {
+        let mut buffer = vec![0; Self::BYTES];
+        PyLong::index(ob)?.to_bytes_into(&mut buffer, Endianness::Little, false)?;
+        // `BITS` need not be a multiple of 8, so the top byte may still hold too many bits
+        Self::try_from_le_slice(&buffer)
+            .ok_or_else(|| PyOverflowError::new_err("int too big to convert"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exceptions::{PyOverflowError, PyTypeError};
+    use ruint::aliases::{U0, U128, U256};
+
+    type U7 = Uint<7, 1>;
+
+    #[test]
+    fn test_u256_roundtrip() {
+        Python::with_gil(|py| {
+            let max: PyObject = py.eval("2 ** 256 - 1", None, None).unwrap().into();
+            let value: U256 = max.extract(py).unwrap();
+            assert_eq!(value, U256::MAX);
+            assert!(value.into_py(py).as_ref(py).eq(max.as_ref(py)).unwrap());
+
+            let small = U256::from(42u64).into_py(py);
+            assert_eq!(small.extract::<u64>(py).unwrap(), 42);
+            assert_eq!(small.extract::<U256>(py).unwrap(), U256::from(42u64));
+        });
+    }
+
+    #[test]
+    fn test_matches_u128() {
+        Python::with_gil(|py| {
+            let value = 0x1234_5678_9abc_def0_0fed_cba9_8765_4321u128;
+            let obj = U128::from(value).to_object(py);
+            assert_eq!(obj.extract::<u128>(py).unwrap(), value);
+            assert_eq!(
+                value.to_object(py).extract::<U128>(py).unwrap(),
+                U128::from(value)
+            );
+        });
+    }
+
+    #[test]
+    fn test_overflow() {
+        Python::with_gil(|py| {
+            let too_big = py.eval("2 ** 256", None, None).unwrap();
+            let err = too_big.extract::<U256>().unwrap_err();
+            assert!(err.is_instance_of::<PyOverflowError>(py));
+
+            let negative = py.eval("-1", None, None).unwrap();
+            let err = negative.extract::<U256>().unwrap_err();
+            assert!(err.is_instance_of::<PyOverflowError>(py));
+
+            // 128 fits into a byte, but not into 7 bits
+            let err = py
+                .eval("128", None, None)
+                .unwrap()
+                .extract::<U7>()
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyOverflowError>(py));
+            assert_eq!(
+                py.eval("127", None, None).unwrap().extract::<U7>().unwrap(),
+                U7::from(127u64)
+            );
+            assert_eq!(U0::ZERO.into_py(py).extract::<u8>(py).unwrap(), 0);
+        });
+    }
+
+    #[test]
+    fn test_index() {
+        Python::with_gil(|py| {
+            let float = py.eval("1.0", None, None).unwrap();
+            let err = float.extract::<U256>().unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+
+            let index = py
+                .eval(
+                    "type('Index', (), {'__index__': lambda self: 2 ** 200})()",
+                    None,
+                    None,
+                )
+                .unwrap();
+            assert_eq!(index.extract::<U256>().unwrap(), U256::from(1u64) << 200);
+        });
+    }
+
+    #[test]
+    fn test_to_object_does_not_grow_pool() {
+        Python::with_gil(|py| {
+            let count = crate::gil::owned_object_count();
+            for _ in 0..10 {
+                let obj = U256::MAX.to_object(py);
+                assert_eq!(obj.extract::<U256>(py).unwrap(), U256::MAX);
+            }
+            assert_eq!(crate::gil::owned_object_count(), count);
+        });
+    }
+}
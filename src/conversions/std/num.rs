@@ -1,5 +1,6 @@
 #[cfg(feature = "experimental-inspect")]
 use crate::inspect::types::TypeInfo;
+use crate::types::{Endianness, PyLong};
 use crate::{
     exceptions, ffi, AsPyPointer, FromPyObject, IntoPy, PyAny, PyErr, PyObject, PyResult, Python,
     ToPyObject,
@@ -163,144 +164,57 @@ int_convert_u64_or_i64!(
     ffi::PyLong_AsUnsignedLongLong
 );
 
-#[cfg(not(Py_LIMITED_API))]
-mod fast_128bit_int_conversion {
-    use super::*;
-
-    // for 128bit Integers
-    macro_rules! int_convert_128 {
-        ($rust_type: ty, $is_signed: expr) => {
-            impl ToPyObject for $rust_type {
-                #[inline]
-                fn to_object(&self, py: Python<'_>) -> PyObject {
-                    (*self).into_py(py)
-                }
-            }
-            impl IntoPy<PyObject> for $rust_type {
-                fn into_py(self, py: Python<'_>) -> PyObject {
-                    unsafe {
-                        // Always use little endian
-                        let bytes = self.to_le_bytes();
-                        let obj = ffi::_PyLong_FromByteArray(
-                            bytes.as_ptr() as *const std::os::raw::c_uchar,
-                            bytes.len(),
-                            1,
-                            $is_signed,
-                        );
-                        PyObject::from_owned_ptr(py, obj)
-                    }
-                }
-
-                #[cfg(feature = "experimental-inspect")]
-                fn type_output() -> TypeInfo {
-                    TypeInfo::builtin("int")
-                }
+// for 128bit Integers
+macro_rules! int_convert_128 {
+    ($rust_type: ty, $is_signed: expr) => {
+        impl ToPyObject for $rust_type {
+            #[inline]
+            fn to_object(&self, py: Python<'_>) -> PyObject {
+                (*self).into_py(py)
             }
+        }
 
-            impl<'source> FromPyObject<'source> for $rust_type {
-                fn extract(ob: &'source PyAny) -> PyResult<$rust_type> {
-                    unsafe {
-                        let num = ffi::PyNumber_Index(ob.as_ptr());
-                        if num.is_null() {
-                            return Err(PyErr::fetch(ob.py()));
-                        }
-                        let mut buffer = [0; std::mem::size_of::<$rust_type>()];
-                        let ok = ffi::_PyLong_AsByteArray(
-                            num as *mut ffi::PyLongObject,
-                            buffer.as_mut_ptr(),
-                            buffer.len(),
-                            1,
-                            $is_signed,
-                        );
-                        ffi::Py_DECREF(num);
-                        crate::err::error_on_minusone(ob.py(), ok)?;
-                        Ok(<$rust_type>::from_le_bytes(buffer))
-                    }
+        impl IntoPy<PyObject> for $rust_type {
+            fn into_py(self, py: Python<'_>) -> PyObject {
+                // Most values fit into 64 bits, which avoids going through a byte array
+                if let Ok(small) = i64::try_from(self) {
+                    return small.into_py(py);
                 }
-
-                #[cfg(feature = "experimental-inspect")]
-                fn type_input() -> TypeInfo {
-                    Self::type_output()
+                // Always use little endian
+                let bytes = self.to_le_bytes();
+                unsafe {
+                    PyObject::from_owned_ptr(
+                        py,
+                        PyLong::from_bytes_ptr(py, &bytes, Endianness::Little, $is_signed),
+                    )
                 }
             }
-        };
-    }
 
-    int_convert_128!(i128, 1);
-    int_convert_128!(u128, 0);
-}
-
-// For ABI3 we implement the conversion manually.
-#[cfg(Py_LIMITED_API)]
-mod slow_128bit_int_conversion {
-    use super::*;
-    const SHIFT: usize = 64;
-
-    // for 128bit Integers
-    macro_rules! int_convert_128 {
-        ($rust_type: ty, $half_type: ty) => {
-            impl ToPyObject for $rust_type {
-                #[inline]
-                fn to_object(&self, py: Python<'_>) -> PyObject {
-                    (*self).into_py(py)
-                }
+            #[cfg(feature = "experimental-inspect")]
+            fn type_output() -> TypeInfo {
+                TypeInfo::builtin("int")
             }
+        }
 
-            impl IntoPy<PyObject> for $rust_type {
-                fn into_py(self, py: Python<'_>) -> PyObject {
-                    let lower = self as u64;
-                    let upper = (self >> SHIFT) as $half_type;
-                    unsafe {
-                        let shifted = PyObject::from_owned_ptr(
-                            py,
-                            ffi::PyNumber_Lshift(
-                                upper.into_py(py).as_ptr(),
-                                SHIFT.into_py(py).as_ptr(),
-                            ),
-                        );
-                        PyObject::from_owned_ptr(
-                            py,
-                            ffi::PyNumber_Or(shifted.as_ptr(), lower.into_py(py).as_ptr()),
-                        )
-                    }
-                }
-
-                #[cfg(feature = "experimental-inspect")]
-                fn type_output() -> TypeInfo {
-                    TypeInfo::builtin("int")
-                }
+        impl<'source> FromPyObject<'source> for $rust_type {
+            fn extract(ob: &'source PyAny) -> PyResult<$rust_type> {
+                let num = PyLong::index(ob)?;
+                let mut buffer = [0; std::mem::size_of::<$rust_type>()];
+                num.to_bytes_into(&mut buffer, Endianness::Little, $is_signed)?;
+                Ok(<$rust_type>::from_le_bytes(buffer))
             }
 
-            impl<'source> FromPyObject<'source> for $rust_type {
-                fn extract(ob: &'source PyAny) -> PyResult<$rust_type> {
-                    let py = ob.py();
-                    unsafe {
-                        let lower = err_if_invalid_value(
-                            py,
-                            -1 as _,
-                            ffi::PyLong_AsUnsignedLongLongMask(ob.as_ptr()),
-                        )? as $rust_type;
-                        let shifted = PyObject::from_owned_ptr_or_err(
-                            py,
-                            ffi::PyNumber_Rshift(ob.as_ptr(), SHIFT.into_py(py).as_ptr()),
-                        )?;
-                        let upper: $half_type = shifted.extract(py)?;
-                        Ok((<$rust_type>::from(upper) << SHIFT) | lower)
-                    }
-                }
-
-                #[cfg(feature = "experimental-inspect")]
-                fn type_input() -> TypeInfo {
-                    Self::type_output()
-                }
+            #[cfg(feature = "experimental-inspect")]
+            fn type_input() -> TypeInfo {
+                Self::type_output()
             }
-        };
-    }
-
-    int_convert_128!(i128, i64);
-    int_convert_128!(u128, u64);
+        }
+    };
 }
 
+int_convert_128!(i128, true);
+int_convert_128!(u128, false);
+
 fn err_if_invalid_value<T: PartialEq>(
     py: Python<'_>,
     invalid_value: T,
@@ -428,6 +342,36 @@ mod test_128bit_integers {
         }
     }
 
+    #[test]
+    fn test_u128_extract_does_not_grow_pool() {
+        Python::with_gil(|py| {
+            let index = py
+                .eval(
+                    "type('Index', (), {'__index__': lambda self: 2 ** 100})()",
+                    None,
+                    None,
+                )
+                .unwrap();
+            let count = crate::gil::owned_object_count();
+            for _ in 0..10 {
+                assert_eq!(index.extract::<u128>().unwrap(), 1 << 100);
+            }
+            assert_eq!(crate::gil::owned_object_count(), count);
+        })
+    }
+
+    #[test]
+    fn test_u128_into_py_does_not_grow_pool() {
+        Python::with_gil(|py| {
+            let count = crate::gil::owned_object_count();
+            for _ in 0..10 {
+                let obj = (u128::MAX - 1).into_py(py);
+                assert_eq!(obj.extract::<u128>(py).unwrap(), u128::MAX - 1);
+            }
+            assert_eq!(crate::gil::owned_object_count(), count);
+        })
+    }
+
     #[test]
     fn test_i128_max() {
         Python::with_gil(|py| {
@@ -628,6 +628,74 @@ impl PyErr {
         }
     }
 
+    /// Adds a frame pointing at `filename` and `line_number` to the traceback of the exception.
+    ///
+    /// This makes errors found by Rust code in non-Python files, for example a parser reporting
+    /// an error in a configuration file, show their location in Python tracebacks. The frame
+    /// appears as the caller of the frames already in the traceback, so when adding several
+    /// frames, add the innermost one first. Frames of Python code which the exception passes
+    /// through later are added as callers of this one.
+    ///
+    /// The frame runs an empty function named `function`, and has no local variables.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::exceptions::PyValueError;
+    /// use pyo3::Python;
+    ///
+    /// Python::with_gil(|py| {
+    ///     let mut err = PyValueError::new_err("expected a string");
+    ///     err.add_traceback_frame(py, "config.toml", "<config>", 42).unwrap();
+    ///
+    ///     assert_eq!(
+    ///         err.traceback(py).unwrap().format().unwrap(),
+    ///         "Traceback (most recent call last):\n  File \"config.toml\", line 42, in <config>\n"
+    ///     );
+    /// });
+    /// ```
+    #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+    pub fn add_traceback_frame(
+        &mut self,
+        py: Python<'_>,
+        filename: &str,
+        function: &str,
+        line_number: u32,
+    ) -> PyResult<()> {
+        let filename = CString::new(filename)?;
+        let function = CString::new(function)?;
+        let line_number = <c_int as std::convert::TryFrom<u32>>::try_from(line_number)
+            .map_err(|_| exceptions::PyOverflowError::new_err("line number out of range"))?;
+        unsafe {
+            // The line of a frame which hasn't started to execute is the first line of its code.
+            let code: Py<PyAny> = Py::from_owned_ptr_or_err(
+                py,
+                ffi::PyCode_NewEmpty(filename.as_ptr(), function.as_ptr(), line_number).cast(),
+            )?;
+            let globals = crate::types::PyDict::new(py);
+            let frame: Py<PyAny> = Py::from_owned_ptr_or_err(
+                py,
+                ffi::PyFrame_New(
+                    ffi::PyThreadState_Get(),
+                    code.as_ptr().cast(),
+                    globals.as_ptr(),
+                    std::ptr::null_mut(),
+                )
+                .cast(),
+            )?;
+            #[cfg(not(Py_3_11))]
+            {
+                (*frame.as_ptr().cast::<ffi::PyFrameObject>()).f_lineno = line_number;
+            }
+
+            self.clone_ref(py).restore(py);
+            // On failure, this chains the original exception to the new one.
+            ffi::PyTraceBack_Here(frame.as_ptr().cast());
+            *self = PyErr::fetch(py);
+        }
+        Ok(())
+    }
+
     #[inline]
     fn from_state(state: PyErrState) -> PyErr {
         PyErr {
@@ -1007,4 +1075,52 @@ mod tests {
             warnings.call_method0("resetwarnings").unwrap();
         });
     }
+
+    #[test]
+    #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+    fn test_add_traceback_frame() {
+        use crate::types::{PyCFunction, PyDict, PyTuple};
+
+        Python::with_gil(|py| {
+            let parse = PyCFunction::new_closure(py, None, None, |args: &PyTuple, _| {
+                let py = args.py();
+                let mut err = exceptions::PyValueError::new_err("bad value");
+                err.add_traceback_frame(py, "config.toml", "<table>", 42)
+                    .unwrap();
+                err.add_traceback_frame(py, "config.toml", "<document>", 1)
+                    .unwrap();
+                Err::<(), _>(err)
+            })
+            .unwrap();
+            let globals = PyDict::new(py);
+            globals.set_item("parse", parse).unwrap();
+            let err = py
+                .run("def load():\n    parse()\nload()", Some(globals), None)
+                .unwrap_err();
+            assert!(err.is_instance_of::<exceptions::PyValueError>(py));
+
+            let entries: Vec<_> = err
+                .traceback(py)
+                .unwrap()
+                .iter()
+                .map(|entry| {
+                    let code = entry.frame().unwrap().code();
+                    (
+                        code.filename().unwrap().to_owned(),
+                        code.name().unwrap().to_owned(),
+                        entry.line_number().unwrap(),
+                    )
+                })
+                .collect();
+            assert_eq!(
+                entries,
+                [
+                    ("<string>".to_owned(), "<module>".to_owned(), 3),
+                    ("<string>".to_owned(), "load".to_owned(), 2),
+                    ("config.toml".to_owned(), "<document>".to_owned(), 1),
+                    ("config.toml".to_owned(), "<table>".to_owned(), 42),
+                ]
+            );
+        });
+    }
 }
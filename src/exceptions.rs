@@ -108,7 +108,8 @@ macro_rules! import_exception {
                     GILOnceCell::new();
 
                 TYPE_OBJECT
-                    .get_or_init(py, || {
+                    .per_interpreter(py)
+                    .map(|cell| cell.get_or_init(py, || {
                         let imp = py
                             .import(stringify!($module))
                             .unwrap_or_else(|err| {
@@ -127,6 +128,14 @@ macro_rules! import_exception {
 
                         cls.extract()
                             .expect("Imported exception should be a type object")
+                    }))
+                    .unwrap_or_else(|err| {
+                        ::std::panic!(
+                            "Can not load exception class {}.{}: {}",
+                            stringify!($module),
+                            stringify!($name),
+                            err
+                        )
                     })
                     .as_ptr() as *mut _
             }
@@ -247,15 +256,18 @@ macro_rules! create_exception_type_object {
                     GILOnceCell::new();
 
                 TYPE_OBJECT
-                    .get_or_init(py, ||
+                    .per_interpreter(py)
+                    .and_then(|cell| cell.get_or_try_init(py, ||
                         $crate::PyErr::new_type(
                             py,
                             concat!(stringify!($module), ".", stringify!($name)),
                             $doc,
                             ::std::option::Option::Some(py.get_type::<$base>()),
                             ::std::option::Option::None,
-                        ).expect("Failed to initialize new exception type.")
-                ).as_ptr() as *mut $crate::ffi::PyTypeObject
+                        )
+                    ))
+                    .expect("Failed to initialize new exception type.")
+                    .as_ptr() as *mut $crate::ffi::PyTypeObject
             }
         }
     };
@@ -669,6 +681,47 @@ impl PyUnicodeDecodeError {
     }
 }
 
+impl PySyntaxError {
+    /// Returns the name of the file in which the error occurred.
+    pub fn filename(&self) -> Option<&str> {
+        self.attribute("filename")
+    }
+
+    /// Returns the line number of the error, starting at 1.
+    pub fn lineno(&self) -> Option<usize> {
+        self.attribute("lineno")
+    }
+
+    /// Returns the column of the error in [`text`](Self::text), starting at 1.
+    pub fn offset(&self) -> Option<usize> {
+        self.attribute("offset")
+    }
+
+    /// Returns the line number at which the erroneous code ends.
+    ///
+    /// This is only available from Python 3.10 on.
+    pub fn end_lineno(&self) -> Option<usize> {
+        self.attribute("end_lineno")
+    }
+
+    /// Returns the column at which the erroneous code ends.
+    ///
+    /// This is only available from Python 3.10 on.
+    pub fn end_offset(&self) -> Option<usize> {
+        self.attribute("end_offset")
+    }
+
+    /// Returns the source code of the line containing the error.
+    pub fn text(&self) -> Option<&str> {
+        self.attribute("text")
+    }
+
+    /// Returns the attribute `name`, or `None` if it is missing or `None`.
+    fn attribute<'a, T: crate::FromPyObject<'a>>(&'a self, name: &str) -> Option<T> {
+        self.getattr(name).ok()?.extract().ok()
+    }
+}
+
 impl_native_exception!(PyWarning, PyExc_Warning, native_doc!("Warning"));
 impl_native_exception!(PyUserWarning, PyExc_UserWarning, native_doc!("UserWarning"));
 impl_native_exception!(
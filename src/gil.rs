@@ -10,15 +10,20 @@ use std::{mem, ptr::NonNull, sync::atomic};
 
 static START: Once = Once::new();
 
+/// Set when an interpreter started with `InterpreterBuilder` has been finalized, after which the
+/// GIL can no longer be acquired.
+pub(crate) static FINALIZED: atomic::AtomicBool = atomic::AtomicBool::new(false);
+
 cfg_if::cfg_if! {
     if #[cfg(thread_local_const_init)] {
-        use std::thread_local as thread_local_const_init;
+        pub(crate) use std::thread_local as thread_local_const_init;
     } else {
         macro_rules! thread_local_const_init {
             ($($(#[$attr:meta])* static $name:ident: $ty:ty = const { $init:expr };)*) => (
                 thread_local! { $($(#[$attr])* static $name: $ty = $init;)* }
             )
         }
+        pub(crate) use thread_local_const_init;
     }
 }
 
@@ -47,7 +52,7 @@ const GIL_LOCKED_DURING_TRAVERSE: isize = -1;
 ///  2) PyGILState_Check always returns 1 if the sub-interpreter APIs have ever been called,
 ///     which could lead to incorrect conclusions that the GIL is held.
 #[inline(always)]
-fn gil_is_acquired() -> bool {
+pub(crate) fn gil_is_acquired() -> bool {
     GIL_COUNT.try_with(|c| c.get() > 0).unwrap_or(false)
 }
 
@@ -168,6 +173,11 @@ impl GILGuard {
             return None;
         }
 
+        assert!(
+            !FINALIZED.load(atomic::Ordering::Acquire),
+            "The Python interpreter has been finalized and can no longer be used."
+        );
+
         // Maybe auto-initialize the GIL:
         //  - If auto-initialize feature set and supported, try to initialize the interpreter.
         //  - If the auto-initialize feature is set but unsupported, emit hard errors only when the
@@ -238,11 +248,28 @@ impl Drop for GILGuard {
 // Vector of PyObject
 type PyObjVec = Vec<NonNull<ffi::PyObject>>;
 
+/// A snapshot of the reference count updates which PyO3 has deferred because the GIL was not
+/// held, as returned by [`reference_pool_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ReferencePoolStats {
+    /// The number of queued reference count increments.
+    pub pending_increfs: usize,
+    /// The number of queued reference count decrements.
+    pub pending_decrefs: usize,
+    /// The largest number of queued increments and decrements at any one time since the process
+    /// started.
+    pub high_water_mark: usize,
+}
+
 /// Thread-safe storage for objects which were inc_ref / dec_ref while the GIL was not held.
 struct ReferencePool {
     dirty: atomic::AtomicBool,
     // .0 is INCREFs, .1 is DECREFs
     pointer_ops: Mutex<(PyObjVec, PyObjVec)>,
+    high_water_mark: atomic::AtomicUsize,
+    #[cfg(feature = "reference-pool-debug")]
+    decref_origins: Mutex<Vec<std::backtrace::Backtrace>>,
 }
 
 impl ReferencePool {
@@ -250,19 +277,46 @@ impl ReferencePool {
         Self {
             dirty: atomic::AtomicBool::new(false),
             pointer_ops: const_mutex((Vec::new(), Vec::new())),
+            high_water_mark: atomic::AtomicUsize::new(0),
+            #[cfg(feature = "reference-pool-debug")]
+            decref_origins: const_mutex(Vec::new()),
         }
     }
 
     fn register_incref(&self, obj: NonNull<ffi::PyObject>) {
-        self.pointer_ops.lock().0.push(obj);
+        let mut ops = self.pointer_ops.lock();
+        ops.0.push(obj);
+        self.update_high_water_mark(ops.0.len() + ops.1.len());
+        drop(ops);
         self.dirty.store(true, atomic::Ordering::Release);
     }
 
     fn register_decref(&self, obj: NonNull<ffi::PyObject>) {
-        self.pointer_ops.lock().1.push(obj);
+        #[cfg(feature = "reference-pool-debug")]
+        let origin = std::backtrace::Backtrace::force_capture();
+        let mut ops = self.pointer_ops.lock();
+        ops.1.push(obj);
+        self.update_high_water_mark(ops.0.len() + ops.1.len());
+        #[cfg(feature = "reference-pool-debug")]
+        self.decref_origins.lock().push(origin);
+        drop(ops);
         self.dirty.store(true, atomic::Ordering::Release);
     }
 
+    fn update_high_water_mark(&self, pending: usize) {
+        self.high_water_mark
+            .fetch_max(pending, atomic::Ordering::Relaxed);
+    }
+
+    fn stats(&self) -> ReferencePoolStats {
+        let ops = self.pointer_ops.lock();
+        ReferencePoolStats {
+            pending_increfs: ops.0.len(),
+            pending_decrefs: ops.1.len(),
+            high_water_mark: self.high_water_mark.load(atomic::Ordering::Relaxed),
+        }
+    }
+
     fn update_counts(&self, _py: Python<'_>) {
         let prev = self.dirty.swap(false, atomic::Ordering::Acquire);
         if !prev {
@@ -271,6 +325,8 @@ impl ReferencePool {
 
         let mut ops = self.pointer_ops.lock();
         let (increfs, decrefs) = mem::take(&mut *ops);
+        #[cfg(feature = "reference-pool-debug")]
+        self.decref_origins.lock().clear();
         drop(ops);
         // Always increase reference counts first - as otherwise objects which have a
         // nonzero total reference count might be incorrectly dropped by Python during
@@ -289,6 +345,50 @@ unsafe impl Sync for ReferencePool {}
 
 static POOL: ReferencePool = ReferencePool::new();
 
+/// Returns statistics about the reference count updates which are waiting for the GIL.
+///
+/// When a [`Py<T>`](crate::Py) is cloned or dropped on a thread which does not hold the GIL, the
+/// reference count update is queued and applied the next time any thread acquires the GIL through
+/// PyO3. Threads which hold many `Py<T>` without the GIL can use this to observe how much work is
+/// pending, and [`Python::flush_pending_refs`] to apply it.
+///
+/// This function does not require the GIL.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::prelude::*;
+///
+/// let list: PyObject = Python::with_gil(|py| pyo3::types::PyList::empty(py).into());
+/// std::thread::spawn(move || drop(list)).join().unwrap();
+/// assert!(pyo3::reference_pool_stats().high_water_mark >= 1);
+///
+/// Python::with_gil(|py| py.flush_pending_refs());
+/// ```
+pub fn reference_pool_stats() -> ReferencePoolStats {
+    POOL.stats()
+}
+
+/// Applies the reference count updates which were queued while the GIL was not held.
+pub(crate) fn flush_pending_refs(py: Python<'_>) {
+    POOL.update_counts(py);
+}
+
+/// Returns the backtraces of the places where the currently pending reference count decrements
+/// were queued.
+///
+/// This requires the `reference-pool-debug` feature, which captures a backtrace each time a
+/// [`Py<T>`](crate::Py) is dropped without the GIL. It is intended to help track down where
+/// objects which accumulate in the queue come from.
+#[cfg(feature = "reference-pool-debug")]
+pub fn pending_decref_origins() -> Vec<String> {
+    POOL.decref_origins
+        .lock()
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
 /// A guard which can be used to temporarily release the GIL and restore on `Drop`.
 pub(crate) struct SuspendGIL {
     count: isize,
@@ -483,9 +583,16 @@ fn decrement_gil_count() {
     });
 }
 
+/// Returns the number of objects currently held by GIL pools on this thread.
+#[cfg(test)]
+pub(crate) fn owned_object_count() -> usize {
+    OWNED_OBJECTS.with(|holder| holder.borrow().len())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{gil_is_acquired, GILPool, GIL_COUNT, OWNED_OBJECTS, POOL};
+    use super::{gil_is_acquired, owned_object_count, GILPool, GIL_COUNT, POOL};
+    use crate::types::PyList;
     use crate::{ffi, gil, AsPyPointer, IntoPyPointer, PyObject, Python, ToPyObject};
     #[cfg(not(target_arch = "wasm32"))]
     use parking_lot::{const_mutex, Condvar, Mutex};
@@ -501,10 +608,6 @@ mod tests {
         obj.to_object(py)
     }
 
-    fn owned_object_count() -> usize {
-        OWNED_OBJECTS.with(|holder| holder.borrow().len())
-    }
-
     fn pool_not_dirty() -> bool {
         !POOL.dirty.load(Ordering::SeqCst)
     }
@@ -542,6 +645,29 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_bound_is_not_owned() {
+        Python::with_gil(|py| {
+            let pool = unsafe { py.new_pool() };
+            let py = pool.python();
+
+            let list = PyList::new_bound(py, [1, 2, 3]);
+            for _ in 0..100 {
+                let total: i32 = list
+                    .iter()
+                    .map(|item| {
+                        item.call_method0("__abs__")
+                            .unwrap()
+                            .extract::<i32>()
+                            .unwrap()
+                    })
+                    .sum();
+                assert_eq!(total, 6);
+            }
+            assert_eq!(owned_object_count(), 0);
+        })
+    }
+
     #[test]
     fn test_owned_nested() {
         Python::with_gil(|py| {
@@ -627,6 +753,32 @@ mod tests {
         });
     }
 
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))] // We are building wasm Python with pthreads disabled
+    fn test_flush_pending_refs() {
+        Python::with_gil(|py| {
+            let obj = get_object(py);
+            let references: Vec<_> = (0..3).map(|_| obj.clone_ref(py)).collect();
+            assert_eq!(obj.get_refcnt(py), 4);
+
+            std::thread::spawn(move || drop(references)).join().unwrap();
+
+            let stats = super::reference_pool_stats();
+            assert!(stats.pending_decrefs >= 3);
+            assert!(stats.high_water_mark >= 3);
+            #[cfg(feature = "reference-pool-debug")]
+            assert!(super::pending_decref_origins()
+                .iter()
+                .any(|origin| origin.contains("test_flush_pending_refs")));
+            assert_eq!(obj.get_refcnt(py), 4);
+
+            py.flush_pending_refs();
+            assert_eq!(obj.get_refcnt(py), 1);
+            let non_null = unsafe { NonNull::new_unchecked(obj.as_ptr()) };
+            assert!(!POOL.pointer_ops.lock().1.contains(&non_null));
+        });
+    }
+
     #[test]
     fn test_gil_counts() {
         // Check with_gil and GILPool both increase counts correctly
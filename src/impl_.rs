@@ -10,8 +10,10 @@ pub mod deprecations;
 pub mod extract_argument;
 pub mod freelist;
 pub mod frompyobject;
+pub mod intopy;
 pub(crate) mod not_send;
 pub mod panic;
+pub mod pickle;
 pub mod pycell;
 pub mod pyclass;
 pub mod pyfunction;
@@ -0,0 +1,67 @@
+//! Helpers for `#[derive(IntoPy)]`.
+//!
+//! The derive macro only accepts hashable literals as `item` keys and string literals as
+//! `attribute` names, so like other object constructors these can only fail on memory exhaustion.
+
+use crate::{
+    err::panic_after_error,
+    ffi,
+    sync::GILOnceCell,
+    types::{PyDict, PyString, PyTuple, PyType},
+    AsPyPointer, IntoPy, Py, PyObject, Python,
+};
+
+static SIMPLE_NAMESPACE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+/// Converts a struct whose fields are read with `item` into a `dict`.
+pub fn struct_into_dict(
+    py: Python<'_>,
+    items: impl IntoIterator<Item = (PyObject, PyObject)>,
+) -> PyObject {
+    let dict = PyDict::new(py);
+    for (key, value) in items {
+        set_item(py, dict, key.as_ptr(), value);
+    }
+    dict.into_py(py)
+}
+
+/// Converts a struct whose fields are read with `attribute` into a `types.SimpleNamespace`.
+pub fn struct_into_namespace<'py>(
+    py: Python<'py>,
+    attributes: impl IntoIterator<Item = (&'py PyString, PyObject)>,
+) -> PyObject {
+    let kwargs = PyDict::new(py);
+    for (name, value) in attributes {
+        set_item(py, kwargs, name.as_ptr(), value);
+    }
+    let namespace = simple_namespace(py);
+    unsafe {
+        PyObject::from_owned_ptr(
+            py,
+            ffi::PyObject_Call(
+                namespace.as_ptr(),
+                PyTuple::empty(py).as_ptr(),
+                kwargs.as_ptr(),
+            ),
+        )
+    }
+}
+
+fn set_item(py: Python<'_>, dict: &PyDict, key: *mut ffi::PyObject, value: PyObject) {
+    if unsafe { ffi::PyDict_SetItem(dict.as_ptr(), key, value.as_ptr()) } == -1 {
+        panic_after_error(py);
+    }
+}
+
+fn simple_namespace(py: Python<'_>) -> &PyType {
+    SIMPLE_NAMESPACE
+        .get_or_init(py, || unsafe {
+            // This is how the `types` module defines `SimpleNamespace`, without importing it.
+            let implementation = ffi::PySys_GetObject("implementation\0".as_ptr().cast());
+            if implementation.is_null() {
+                panic_after_error(py);
+            }
+            Py::from_borrowed_ptr(py, ffi::Py_TYPE(implementation).cast())
+        })
+        .as_ref(py)
+}
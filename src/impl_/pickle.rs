@@ -0,0 +1,222 @@
+//! Support for `#[pyclass(pickle)]`, which implements the pickle and copy protocols.
+
+use crate::{
+    exceptions::PyTypeError,
+    ffi, intern,
+    once_cell::GILOnceCell,
+    pyclass_init::PyClassInitializer,
+    type_object::PyTypeInfo,
+    types::{PyDict, PyString, PyTuple, PyType},
+    IntoPy, PyAny, PyCell, PyClass, PyErr, PyObject, PyResult, Python,
+};
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+/// Implements `__getnewargs_ex__` for `#[pyclass(pickle)]` structs.
+///
+/// The fields exposed to Python are passed back to `#[new]` as keyword arguments. Whether `#[new]`
+/// accepts them can't be checked when expanding `#[pyclass]`, so a mismatch only surfaces as a
+/// `TypeError` on unpickling.
+pub fn getnewargs_ex(py: Python<'_>, kwargs: &[(&str, PyObject)]) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    for (name, value) in kwargs {
+        dict.set_item(*name, value)?;
+    }
+    Ok((PyTuple::empty(py), dict).into_py(py))
+}
+
+/// Implements `__reduce__` for `#[pyclass(pickle)]` enums.
+///
+/// Variants are pickled by name, as `getattr(cls, "Variant")`.
+pub fn reduce_enum_variant<T: PyClass>(py: Python<'_>, variant: &str) -> PyResult<PyObject> {
+    let getattr = py.import("builtins")?.getattr(intern!(py, "getattr"))?;
+    Ok((getattr, (T::type_object(py), variant)).into_py(py))
+}
+
+/// Implements `__copy__` for `#[pyclass(pickle)]` by cloning the Rust value.
+///
+/// The rest of the instance state (see [`instance_state`]) is copied shallowly.
+pub fn copy<T>(slf: &PyCell<T>) -> PyResult<PyObject>
+where
+    T: PyClass + Clone,
+    PyClassInitializer<T>: From<T>,
+{
+    let py = slf.py();
+    let value = slf.try_borrow()?.clone();
+    let copy = new_instance(slf.get_type(), value)?;
+    if let Some(state) = instance_state(slf)? {
+        set_instance_state(copy.as_ref(py), state.as_ref(py))?;
+    }
+    Ok(copy)
+}
+
+/// Implements `__deepcopy__` for `#[pyclass(pickle)]` by cloning the Rust value.
+///
+/// The rest of the instance state (see [`instance_state`]) is deep-copied using the `copy` module.
+pub fn deepcopy<T>(slf: &PyCell<T>, memo: &PyAny) -> PyResult<PyObject>
+where
+    T: PyClass + Clone,
+    PyClassInitializer<T>: From<T>,
+{
+    let py = slf.py();
+    let value = slf.try_borrow()?.clone();
+    let copy = new_instance(slf.get_type(), value)?;
+    if let Ok(memo) = memo.downcast::<PyDict>() {
+        // Register the copy before recursing, so that reference cycles resolve to it.
+        let id = py
+            .import("builtins")?
+            .getattr(intern!(py, "id"))?
+            .call1((slf,))?;
+        memo.set_item(id, &copy)?;
+    }
+    if let Some(state) = instance_state(slf)? {
+        let state = py
+            .import("copy")?
+            .call_method1(intern!(py, "deepcopy"), (state, memo))?;
+        set_instance_state(copy.as_ref(py), state)?;
+    }
+    Ok(copy)
+}
+
+/// Creates an instance of `cls`, which must be `T` or a subclass of it defined in Python,
+/// wrapping `value`.
+pub(crate) fn new_instance<T>(cls: &PyType, value: T) -> PyResult<PyObject>
+where
+    T: PyClass,
+    PyClassInitializer<T>: From<T>,
+{
+    if !cls.is_subclass_of::<T>()? {
+        return Err(PyTypeError::new_err(format!(
+            "{} is not a subclass of {}",
+            cls.name()?,
+            T::NAME
+        )));
+    }
+    // Instances of a subclass defined in Rust hold more Rust data than `value` initializes.
+    if !has_layout_of::<T>(cls)? {
+        return Err(PyTypeError::new_err(format!(
+            "cannot create a {} from a {} value, because it is a subclass defined in Rust",
+            cls.name()?,
+            T::NAME
+        )));
+    }
+    let py = cls.py();
+    unsafe {
+        let cell =
+            PyClassInitializer::from(value).create_cell_from_subtype(py, cls.as_type_ptr())?;
+        Ok(PyObject::from_owned_ptr(py, cell.cast()))
+    }
+}
+
+/// Checks that `cls`, a subclass of `T`, only adds to the layout of `T` what Python classes can
+/// add (`__dict__`, `__weakref__` and `__slots__`), i.e. that there is no Rust class between them.
+fn has_layout_of<T: PyClass>(cls: &PyType) -> PyResult<bool> {
+    static PYTHON_CLASS_DEALLOC: GILOnceCell<usize> = GILOnceCell::new();
+
+    let py = cls.py();
+    // All classes created by `type.__new__` share the same `tp_dealloc`.
+    let python_class_dealloc = *PYTHON_CLASS_DEALLOC.get_or_try_init(py, || {
+        let cls: &PyType = PyType::type_object(py)
+            .call1(("_", PyTuple::empty(py), PyDict::new(py)))?
+            .downcast()?;
+        Ok::<_, PyErr>(unsafe { get_tp_dealloc(cls.as_type_ptr()) })
+    })?;
+
+    let target = T::type_object_raw(py);
+    let mut ty = cls;
+    while ty.as_type_ptr() != target {
+        if unsafe { get_tp_dealloc(ty.as_type_ptr()) } != python_class_dealloc {
+            return Ok(false);
+        }
+        ty = ty.getattr(intern!(py, "__base__"))?.downcast()?;
+    }
+    Ok(true)
+}
+
+unsafe fn get_tp_dealloc(tp: *mut ffi::PyTypeObject) -> usize {
+    #[cfg(not(Py_LIMITED_API))]
+    {
+        (*tp).tp_dealloc.map_or(0, |dealloc| dealloc as usize)
+    }
+
+    #[cfg(Py_LIMITED_API)]
+    {
+        ffi::PyType_GetSlot(tp, ffi::Py_tp_dealloc) as usize
+    }
+}
+
+/// Returns the state of `obj` other than its Rust value, as `object.__reduce_ex__` would save
+/// it for a Python class: the result of a custom `__getstate__` if the class defines one, and
+/// otherwise the instance `__dict__` and the values of any `__slots__`, as a `(dict, slots)`
+/// tuple if there are slots. Returns `None` if there is no state.
+pub(crate) fn instance_state(obj: &PyAny) -> PyResult<Option<PyObject>> {
+    let py = obj.py();
+    let cls = obj.get_type();
+
+    if let Ok(getstate) = cls.getattr(intern!(py, "__getstate__")) {
+        // Since Python 3.11 `object` implements `__getstate__`, which saves the default state.
+        let default = PyAny::type_object(py).getattr(intern!(py, "__getstate__"));
+        if !matches!(default, Ok(default) if getstate.is(default)) {
+            let state = obj.call_method0(intern!(py, "__getstate__"))?;
+            return Ok(if state.is_none() {
+                None
+            } else {
+                Some(state.into())
+            });
+        }
+    }
+
+    let dict = instance_dict(obj)?;
+    let slot_names = py
+        .import("copyreg")?
+        .call_method1(intern!(py, "_slotnames"), (cls,))?;
+    let slots = PyDict::new(py);
+    for name in slot_names.iter()? {
+        let name: &PyString = name?.downcast()?;
+        if obj.hasattr(name)? {
+            slots.set_item(name, obj.getattr(name)?)?;
+        }
+    }
+
+    Ok(match (dict, slots.is_empty()) {
+        (None, true) => None,
+        (Some(dict), true) => Some(dict.into()),
+        (dict, false) => Some((dict, slots).into_py(py)),
+    })
+}
+
+/// Restores state returned by [`instance_state`] on `obj`, the way `copy` and `pickle` do.
+fn set_instance_state(obj: &PyAny, state: &PyAny) -> PyResult<()> {
+    let py = obj.py();
+    if obj.hasattr(intern!(py, "__setstate__"))? {
+        obj.call_method1(intern!(py, "__setstate__"), (state,))?;
+        return Ok(());
+    }
+
+    let (dict_state, slot_state) = match state.downcast::<PyTuple>() {
+        Ok(state) if state.len() == 2 => (state.get_item(0)?, Some(state.get_item(1)?)),
+        _ => (state, None),
+    };
+    if !dict_state.is_none() {
+        obj.getattr(intern!(py, "__dict__"))?
+            .call_method1(intern!(py, "update"), (dict_state,))?;
+    }
+    if let Some(slot_state) = slot_state.filter(|state| !state.is_none()) {
+        for item in slot_state.call_method0(intern!(py, "items"))?.iter()? {
+            let (name, value): (&PyString, &PyAny) = item?.extract()?;
+            obj.setattr(name, value)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the instance `__dict__` of `obj`, if it has a non-empty one.
+fn instance_dict(obj: &PyAny) -> PyResult<Option<&PyDict>> {
+    let py = obj.py();
+    if !obj.hasattr(intern!(py, "__dict__"))? {
+        return Ok(None);
+    }
+    let dict: &PyDict = obj.getattr(intern!(py, "__dict__"))?.downcast()?;
+    Ok(if dict.is_empty() { None } else { Some(dict) })
+}
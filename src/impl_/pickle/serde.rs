@@ -0,0 +1,617 @@
+//! Support for `#[pyclass(pickle = serde)]`.
+//!
+//! The Rust value is serialized into plain Python objects, which are then pickled as the state
+//! of the object:
+//!
+//! - booleans, integers, floats, strings and bytes map to the corresponding Python types;
+//! - `None`, `()` and unit structs map to `None`, and `Some(value)` maps to `value`;
+//! - sequences, tuples and tuple structs map to `tuple`;
+//! - maps and structs map to `dict`;
+//! - unit variants map to their name, other variants to a single-item `dict` mapping the name
+//!   of the variant to its contents.
+
+use crate::{
+    exceptions::{PyTypeError, PyValueError},
+    intern,
+    pyclass_init::PyClassInitializer,
+    types::{
+        iter::PyDictIterator, PyBool, PyBytes, PyDict, PyFloat, PyLong, PyString, PyTuple, PyType,
+    },
+    IntoPy, PyAny, PyCell, PyClass, PyErr, PyObject, PyResult, Python, ToPyObject,
+};
+use ::serde::{
+    de::{self, DeserializeOwned, DeserializeSeed, Visitor},
+    ser::{self, Serialize},
+    Deserialize,
+};
+use std::fmt;
+
+/// Implements `__reduce__` for `#[pyclass(pickle = serde)]`.
+pub fn reduce<T>(slf: &PyCell<T>) -> PyResult<PyObject>
+where
+    T: PyClass + Serialize,
+{
+    let py = slf.py();
+    let state = slf.try_borrow()?.serialize(Serializer { py })?;
+    // The macro generates this classmethod alongside `__reduce__`.
+    let unpickle = slf.get_type().getattr(intern!(py, "__pyo3_unpickle__"))?;
+    Ok(match super::instance_state(slf)? {
+        Some(instance_state) => (unpickle, (state,), instance_state).into_py(py),
+        None => (unpickle, (state,)).into_py(py),
+    })
+}
+
+/// Implements the `__pyo3_unpickle__` classmethod for `#[pyclass(pickle = serde)]`.
+pub fn unpickle<T>(cls: &PyType, state: &PyAny) -> PyResult<PyObject>
+where
+    T: PyClass + DeserializeOwned,
+    PyClassInitializer<T>: From<T>,
+{
+    let value = T::deserialize(Deserializer(state))?;
+    super::new_instance(cls, value)
+}
+
+#[derive(Debug)]
+struct Error(PyErr);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(PyValueError::new_err(msg.to_string()))
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(PyValueError::new_err(msg.to_string()))
+    }
+}
+
+impl From<PyErr> for Error {
+    fn from(err: PyErr) -> Self {
+        Error(err)
+    }
+}
+
+impl From<Error> for PyErr {
+    fn from(err: Error) -> Self {
+        err.0
+    }
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+struct Serializer<'py> {
+    py: Python<'py>,
+}
+
+impl<'py> ser::Serializer for Serializer<'py> {
+    type Ok = PyObject;
+    type Error = Error;
+
+    type SerializeSeq = SerializeTuple<'py>;
+    type SerializeTuple = SerializeTuple<'py>;
+    type SerializeTupleStruct = SerializeTuple<'py>;
+    type SerializeTupleVariant = SerializeVariant<SerializeTuple<'py>>;
+    type SerializeMap = SerializeDict<'py>;
+    type SerializeStruct = SerializeDict<'py>;
+    type SerializeStructVariant = SerializeVariant<SerializeDict<'py>>;
+
+    fn serialize_bool(self, v: bool) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_char(self, v: char) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<PyObject> {
+        Ok(v.to_object(self.py))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<PyObject> {
+        Ok(PyBytes::new(self.py, v).into())
+    }
+
+    fn serialize_none(self) -> Result<PyObject> {
+        Ok(self.py.None())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<PyObject> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<PyObject> {
+        Ok(self.py.None())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<PyObject> {
+        Ok(self.py.None())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<PyObject> {
+        Ok(variant.to_object(self.py))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<PyObject> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<PyObject> {
+        let py = self.py;
+        let dict = PyDict::new(py);
+        dict.set_item(variant, value.serialize(self)?)?;
+        Ok(dict.into())
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeTuple<'py>> {
+        Ok(SerializeTuple {
+            py: self.py,
+            items: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeTuple<'py>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeTuple<'py>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeTuple<'py>>> {
+        Ok(SerializeVariant {
+            variant,
+            inner: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SerializeDict<'py>> {
+        Ok(SerializeDict {
+            dict: PyDict::new(self.py),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeDict<'py>> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeDict<'py>>> {
+        Ok(SerializeVariant {
+            variant,
+            inner: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+struct SerializeTuple<'py> {
+    py: Python<'py>,
+    items: Vec<PyObject>,
+}
+
+impl SerializeTuple<'_> {
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.items
+            .push(value.serialize(Serializer { py: self.py })?);
+        Ok(())
+    }
+
+    fn finish(self) -> PyObject {
+        PyTuple::new(self.py, self.items).into()
+    }
+}
+
+impl ser::SerializeSeq for SerializeTuple<'_> {
+    type Ok = PyObject;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<PyObject> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTuple for SerializeTuple<'_> {
+    type Ok = PyObject;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<PyObject> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeTuple<'_> {
+    type Ok = PyObject;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<PyObject> {
+        Ok(self.finish())
+    }
+}
+
+struct SerializeDict<'py> {
+    dict: &'py PyDict,
+    key: Option<PyObject>,
+}
+
+impl SerializeDict<'_> {
+    fn serializer(&self) -> Serializer<'_> {
+        Serializer { py: self.dict.py() }
+    }
+
+    fn finish(self) -> PyObject {
+        self.dict.into()
+    }
+}
+
+impl ser::SerializeMap for SerializeDict<'_> {
+    type Ok = PyObject;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        self.key = Some(key.serialize(self.serializer())?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let key = self
+            .key
+            .take()
+            .expect("serialize_value called before serialize_key");
+        self.dict
+            .set_item(key, value.serialize(self.serializer())?)?;
+        Ok(())
+    }
+
+    fn end(self) -> Result<PyObject> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeStruct for SerializeDict<'_> {
+    type Ok = PyObject;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.dict
+            .set_item(key, value.serialize(self.serializer())?)?;
+        Ok(())
+    }
+
+    fn end(self) -> Result<PyObject> {
+        Ok(self.finish())
+    }
+}
+
+struct SerializeVariant<S> {
+    variant: &'static str,
+    inner: S,
+}
+
+impl ser::SerializeTupleVariant for SerializeVariant<SerializeTuple<'_>> {
+    type Ok = PyObject;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.inner.push(value)
+    }
+
+    fn end(self) -> Result<PyObject> {
+        let py = self.inner.py;
+        let dict = PyDict::new(py);
+        dict.set_item(self.variant, self.inner.finish())?;
+        Ok(dict.into())
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeVariant<SerializeDict<'_>> {
+    type Ok = PyObject;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> Result<PyObject> {
+        let py = self.inner.dict.py();
+        let dict = PyDict::new(py);
+        dict.set_item(self.variant, self.inner.finish())?;
+        Ok(dict.into())
+    }
+}
+
+struct Deserializer<'py>(&'py PyAny);
+
+impl<'de, 'py> de::Deserializer<'de> for Deserializer<'py> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let obj = self.0;
+        if obj.is_none() {
+            visitor.visit_unit()
+        } else if let Ok(b) = obj.downcast::<PyBool>() {
+            visitor.visit_bool(b.is_true())
+        } else if obj.is_instance_of::<PyLong>() {
+            if let Ok(v) = obj.extract::<i64>() {
+                visitor.visit_i64(v)
+            } else if let Ok(v) = obj.extract::<u64>() {
+                visitor.visit_u64(v)
+            } else if let Ok(v) = obj.extract::<i128>() {
+                visitor.visit_i128(v)
+            } else {
+                visitor.visit_u128(obj.extract()?)
+            }
+        } else if let Ok(f) = obj.downcast::<PyFloat>() {
+            visitor.visit_f64(f.value())
+        } else if let Ok(s) = obj.downcast::<PyString>() {
+            visitor.visit_str(s.to_str()?)
+        } else if let Ok(b) = obj.downcast::<PyBytes>() {
+            visitor.visit_bytes(b.as_bytes())
+        } else if let Ok(t) = obj.downcast::<PyTuple>() {
+            visitor.visit_seq(SeqAccess { tuple: t, index: 0 })
+        } else if let Ok(d) = obj.downcast::<PyDict>() {
+            visitor.visit_map(MapAccess {
+                iter: d.iter(),
+                value: None,
+            })
+        } else {
+            Err(Error(PyTypeError::new_err(format!(
+                "cannot deserialize object of type '{}'",
+                obj.get_type().name()?
+            ))))
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.0.is_none() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let obj = self.0;
+        if obj.is_instance_of::<PyString>() {
+            return visitor.visit_enum(EnumAccess {
+                variant: obj,
+                value: None,
+            });
+        }
+        if let Ok(dict) = obj.downcast::<PyDict>() {
+            if let (1, Some((variant, value))) = (dict.len(), dict.iter().next()) {
+                return visitor.visit_enum(EnumAccess {
+                    variant,
+                    value: Some(value),
+                });
+            }
+        }
+        Err(Error(PyTypeError::new_err(
+            "expected a string or a dict with a single key for an enum",
+        )))
+    }
+
+    ::serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct SeqAccess<'py> {
+    tuple: &'py PyTuple,
+    index: usize,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.index >= self.tuple.len() {
+            return Ok(None);
+        }
+        let item = self.tuple.get_item(self.index)?;
+        self.index += 1;
+        seed.deserialize(Deserializer(item)).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.tuple.len() - self.index)
+    }
+}
+
+struct MapAccess<'py> {
+    iter: PyDictIterator<'py>,
+    value: Option<&'py PyAny>,
+}
+
+impl<'de> de::MapAccess<'de> for MapAccess<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(Deserializer(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let value = self
+            .value
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+        seed.deserialize(Deserializer(value))
+    }
+}
+
+struct EnumAccess<'py> {
+    variant: &'py PyAny,
+    value: Option<&'py PyAny>,
+}
+
+impl<'py> EnumAccess<'py> {
+    fn value(self) -> Result<Deserializer<'py>> {
+        self.value
+            .map(Deserializer)
+            .ok_or_else(|| de::Error::invalid_type(de::Unexpected::UnitVariant, &"variant data"))
+    }
+}
+
+impl<'de, 'py> de::EnumAccess<'de> for EnumAccess<'py> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self)> {
+        let variant = seed.deserialize(Deserializer(self.variant))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for EnumAccess<'_> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        match self.value {
+            Some(value) => <()>::deserialize(Deserializer(value)),
+            None => Ok(()),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self.value()?)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        de::Deserializer::deserialize_any(self.value()?, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        de::Deserializer::deserialize_any(self.value()?, visitor)
+    }
+}
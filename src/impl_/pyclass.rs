@@ -153,6 +153,9 @@ pub trait PyClassImpl: Sized + 'static {
     /// #[pyclass(sequence)]
     const IS_SEQUENCE: bool = false;
 
+    /// #[pyclass(extends_py = "module.Class")]
+    const PYTHON_BASE_TYPE: Option<&'static str> = None;
+
     /// Layout
     type Layout: PyLayout<Self>;
 
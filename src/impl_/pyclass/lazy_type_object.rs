@@ -33,19 +33,14 @@ struct LazyTypeObjectInner {
 impl<T> LazyTypeObject<T> {
     /// Creates an uninitialized `LazyTypeObject`.
     pub const fn new() -> Self {
-        LazyTypeObject(
-            LazyTypeObjectInner {
-                value: GILOnceCell::new(),
-                initializing_threads: GILProtected::new(RefCell::new(Vec::new())),
-                tp_dict_filled: GILOnceCell::new(),
-            },
-            PhantomData,
-        )
+        LazyTypeObject(LazyTypeObjectInner::new(), PhantomData)
     }
 }
 
 impl<T: PyClass> LazyTypeObject<T> {
     /// Gets the type object contained by this `LazyTypeObject`, initializing it if needed.
+    ///
+    /// Each subinterpreter gets its own type object.
     pub fn get_or_init<'py>(&'py self, py: Python<'py>) -> &'py PyType {
         self.get_or_try_init(py).unwrap_or_else(|err| {
             err.print(py);
@@ -55,12 +50,37 @@ impl<T: PyClass> LazyTypeObject<T> {
 
     /// Fallible version of the above.
     pub(crate) fn get_or_try_init<'py>(&'py self, py: Python<'py>) -> PyResult<&'py PyType> {
-        self.0
+        self.inner(py)?
             .get_or_try_init(py, create_type_object::<T>, T::NAME, T::items_iter())
     }
+
+    fn inner<'py>(&'py self, py: Python<'py>) -> PyResult<&'py LazyTypeObjectInner> {
+        // The copies for subinterpreters are keyed by the static `LazyTypeObject` of `T`, so
+        // that they can also be used by other (non-static) `LazyTypeObject<T>`s.
+        #[cfg(all(Py_3_9, not(any(PyPy, Py_LIMITED_API))))]
+        {
+            if let Some(local) = crate::interpreter::subinterpreter_local(
+                py,
+                &T::lazy_type_object().0,
+                LazyTypeObjectInner::new,
+            )? {
+                return Ok(local);
+            }
+        }
+        let _ = py;
+        Ok(&self.0)
+    }
 }
 
 impl LazyTypeObjectInner {
+    const fn new() -> Self {
+        LazyTypeObjectInner {
+            value: GILOnceCell::new(),
+            initializing_threads: GILProtected::new(RefCell::new(Vec::new())),
+            tp_dict_filled: GILOnceCell::new(),
+        }
+    }
+
     // Uses dynamically dispatched fn(Python<'py>) -> PyResult<Py<PyType>
     // so that this code is only instantiated once, instead of for every T
     // like the generic LazyTypeObject<T> methods above.
@@ -0,0 +1,557 @@
+#![cfg(all(feature = "macros", not(Py_LIMITED_API)))]
+
+//! Import hooks implemented in Rust.
+//!
+//! A [`ModuleFinder`] provides Python modules and packages to the `import` statement, for
+//! example from sources embedded into the binary with [`include_str!`]. It is installed on
+//! [`sys.meta_path`] with [`add_meta_path_finder`], and supports relative imports, tracebacks
+//! showing the source code, and reading package resources with [`importlib.resources`].
+//!
+//! [`EmbeddedModules`] is a ready-made finder for modules stored in memory.
+//!
+//! This module requires the `macros` feature, and is not available with the limited API
+//! (`abi3`).
+//!
+//! [`sys.meta_path`]: https://docs.python.org/3/library/sys.html#sys.meta_path
+//! [`importlib.resources`]: https://docs.python.org/3/library/importlib.resources.html
+
+use crate::exceptions::{PyFileNotFoundError, PyImportError, PyNotADirectoryError};
+use crate::intern;
+use crate::marshal;
+use crate::types::{
+    CompileFlags, CompileMode, PyBytes, PyCode, PyDict, PyIterator, PyList, PyModule, PyTuple,
+};
+use crate::{IntoPy, Py, PyAny, PyCell, PyObject, PyResult, Python};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+/// The code of a module provided by a [`ModuleFinder`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ModuleCode {
+    /// Python source code.
+    Source(Cow<'static, str>),
+    /// A code object serialized with [`marshal::dumps`], or Python's `marshal.dumps`.
+    ///
+    /// This is not the format of `.pyc` files, which start with a header. The marshal format is
+    /// specific to the Python version which produced it.
+    Bytecode(Cow<'static, [u8]>),
+}
+
+/// A module provided by a [`ModuleFinder`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FoundModule {
+    /// The code which is run to initialize the module.
+    pub code: ModuleCode,
+    /// Whether the module is a package, which can contain submodules and resources.
+    pub is_package: bool,
+    /// The file name used in tracebacks for code compiled from source.
+    pub origin: String,
+}
+
+/// Finds and loads modules for the `import` statement.
+///
+/// Modules are identified by their fully qualified name, such as `package.submodule`.
+pub trait ModuleFinder: Send + Sync + 'static {
+    /// Returns the module `fullname`, or `None` if this finder does not provide it.
+    fn find_module(&self, fullname: &str) -> Option<FoundModule>;
+
+    /// Returns the contents of the resource `name` of `package`.
+    fn resource(&self, package: &str, name: &str) -> Option<Cow<'static, [u8]>> {
+        let _ = (package, name);
+        None
+    }
+
+    /// Returns the names of the resources of `package`.
+    fn resource_names(&self, package: &str) -> Vec<String> {
+        let _ = package;
+        Vec::new()
+    }
+}
+
+/// Appends `finder` to `sys.meta_path`.
+///
+/// Finders on `sys.meta_path` are asked in order, so modules found by Python's default finders,
+/// for example in the current directory, take precedence over those provided by `finder`.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::import_hooks::{add_meta_path_finder, EmbeddedModules};
+/// use pyo3::prelude::*;
+///
+/// # fn main() -> PyResult<()> {
+/// Python::with_gil(|py| {
+///     let modules = EmbeddedModules::new()
+///         .package("rules", "from .pricing import discount")
+///         .module("rules.pricing", "def discount(price): return price * 0.9");
+///     add_meta_path_finder(py, modules)?;
+///
+///     let discount: f64 = py.import("rules")?.getattr("discount")?.call1((100.0,))?.extract()?;
+///     assert_eq!(discount, 90.0);
+///     Ok(())
+/// })
+/// # }
+/// ```
+pub fn add_meta_path_finder(py: Python<'_>, finder: impl ModuleFinder) -> PyResult<()> {
+    let finder = Finder {
+        finder: Arc::new(finder),
+    };
+    let meta_path: &PyList = py.import("sys")?.getattr("meta_path")?.downcast()?;
+    meta_path.append(Py::new(py, finder)?)
+}
+
+/// The meta path finder installed by [`add_meta_path_finder`], following `importlib.abc`.
+#[crate::pyclass(crate = "crate", module = "pyo3.import_hooks")]
+struct Finder {
+    finder: Arc<dyn ModuleFinder>,
+}
+
+#[crate::pymethods(crate = "crate")]
+impl Finder {
+    #[pyo3(signature = (fullname, path = None, target = None))]
+    fn find_spec(
+        &self,
+        py: Python<'_>,
+        fullname: &str,
+        path: Option<&PyAny>,
+        target: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let _ = (path, target);
+        let found = match self.finder.find_module(fullname) {
+            Some(found) => found,
+            None => return Ok(py.None()),
+        };
+        let loader = Loader {
+            finder: self.finder.clone(),
+            is_package: found.is_package,
+        };
+        let kwargs = PyDict::new(py);
+        kwargs.set_item(intern!(py, "origin"), &found.origin)?;
+        kwargs.set_item(intern!(py, "is_package"), found.is_package)?;
+        // Passed on to `Loader::exec_module`, so that the module is only looked up once.
+        kwargs.set_item(
+            intern!(py, "loader_state"),
+            Py::new(py, LoaderState(found))?,
+        )?;
+        let spec = py
+            .import(intern!(py, "importlib.machinery"))?
+            .getattr(intern!(py, "ModuleSpec"))?
+            .call((fullname, Py::new(py, loader)?), Some(kwargs))?;
+        Ok(spec.into())
+    }
+
+    fn invalidate_caches(&self) {}
+}
+
+/// The module found by [`Finder::find_spec`], stored as `ModuleSpec.loader_state`.
+#[crate::pyclass(crate = "crate", module = "pyo3.import_hooks", frozen)]
+struct LoaderState(FoundModule);
+
+#[crate::pyclass(crate = "crate", module = "pyo3.import_hooks")]
+struct Loader {
+    finder: Arc<dyn ModuleFinder>,
+    is_package: bool,
+}
+
+#[crate::pymethods(crate = "crate")]
+impl Loader {
+    fn create_module(&self, py: Python<'_>, spec: &PyAny) -> PyObject {
+        let _ = spec;
+        py.None()
+    }
+
+    fn exec_module(&self, module: &PyModule) -> PyResult<()> {
+        let py = module.py();
+        let state = module
+            .getattr(intern!(py, "__spec__"))?
+            .getattr(intern!(py, "loader_state"))?;
+        let code = match state.extract::<&PyCell<LoaderState>>() {
+            Ok(state) => load_code(py, &state.get().0)?,
+            // The spec was not created by `Finder::find_spec`.
+            Err(_) => self.get_code(py, module.name()?)?,
+        };
+        code.eval(Some(module.dict()), None)?;
+        Ok(())
+    }
+
+    fn is_package(&self, fullname: &str) -> bool {
+        let _ = fullname;
+        self.is_package
+    }
+
+    fn get_code<'py>(&self, py: Python<'py>, fullname: &str) -> PyResult<&'py PyCode> {
+        load_code(py, &self.find(fullname)?)
+    }
+
+    fn get_source(&self, fullname: &str) -> PyResult<Option<String>> {
+        Ok(match self.find(fullname)?.code {
+            ModuleCode::Source(source) => Some(source.into_owned()),
+            ModuleCode::Bytecode(_) => None,
+        })
+    }
+
+    fn get_resource_reader(&self, fullname: &str) -> Option<ResourceReader> {
+        if self.is_package {
+            Some(ResourceReader {
+                finder: self.finder.clone(),
+                package: fullname.to_owned(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl Loader {
+    fn find(&self, fullname: &str) -> PyResult<FoundModule> {
+        self.finder
+            .find_module(fullname)
+            .ok_or_else(|| PyImportError::new_err(format!("no module named {:?}", fullname)))
+    }
+}
+
+fn load_code<'py>(py: Python<'py>, found: &FoundModule) -> PyResult<&'py PyCode> {
+    match &found.code {
+        ModuleCode::Source(source) => py.compile(
+            source,
+            &found.origin,
+            CompileMode::Exec,
+            &CompileFlags::new(),
+        ),
+        ModuleCode::Bytecode(bytecode) => Ok(marshal::loads(py, bytecode)?.downcast()?),
+    }
+}
+
+/// The resources of a package, following `importlib.abc.ResourceReader`.
+#[crate::pyclass(crate = "crate", module = "pyo3.import_hooks")]
+struct ResourceReader {
+    finder: Arc<dyn ModuleFinder>,
+    package: String,
+}
+
+#[crate::pymethods(crate = "crate")]
+impl ResourceReader {
+    fn open_resource<'py>(&self, py: Python<'py>, resource: &str) -> PyResult<&'py PyAny> {
+        match self.finder.resource(&self.package, resource) {
+            Some(data) => bytes_io(py, &data),
+            None => Err(PyFileNotFoundError::new_err(resource.to_owned())),
+        }
+    }
+
+    fn resource_path(&self, resource: &str) -> PyResult<()> {
+        Err(PyFileNotFoundError::new_err(resource.to_owned()))
+    }
+
+    fn is_resource(&self, name: &str) -> bool {
+        self.finder.resource(&self.package, name).is_some()
+    }
+
+    fn contents(&self) -> Vec<String> {
+        self.finder.resource_names(&self.package)
+    }
+
+    fn files(&self) -> Resource {
+        Resource {
+            finder: self.finder.clone(),
+            package: self.package.clone(),
+            name: None,
+        }
+    }
+}
+
+/// A package (if `name` is `None`) or one of its resources, following
+/// `importlib.resources.abc.Traversable`.
+#[crate::pyclass(crate = "crate", module = "pyo3.import_hooks")]
+struct Resource {
+    finder: Arc<dyn ModuleFinder>,
+    package: String,
+    name: Option<String>,
+}
+
+#[crate::pymethods(crate = "crate")]
+impl Resource {
+    #[getter]
+    fn name(&self) -> &str {
+        match &self.name {
+            Some(name) => name,
+            None => self.package.rsplit('.').next().unwrap_or(&self.package),
+        }
+    }
+
+    fn is_dir(&self) -> bool {
+        self.name.is_none()
+    }
+
+    fn is_file(&self) -> bool {
+        self.data().is_some()
+    }
+
+    fn iterdir<'py>(&self, py: Python<'py>) -> PyResult<&'py PyIterator> {
+        if let Some(name) = &self.name {
+            return Err(PyNotADirectoryError::new_err(name.clone()));
+        }
+        let children: Vec<_> = self
+            .finder
+            .resource_names(&self.package)
+            .into_iter()
+            .map(|name| self.child(name).into_py(py))
+            .collect();
+        PyIterator::from_object(py, PyList::new(py, children))
+    }
+
+    #[pyo3(signature = (*descendants))]
+    fn joinpath(&self, descendants: Vec<&str>) -> Resource {
+        let mut name = descendants.join("/");
+        if let Some(parent) = &self.name {
+            name = format!("{}/{}", parent, name);
+        }
+        self.child(name)
+    }
+
+    fn __truediv__(&self, child: &str) -> Resource {
+        self.joinpath(vec![child])
+    }
+
+    #[pyo3(signature = (mode = "r", *args, **kwargs))]
+    fn open<'py>(
+        &self,
+        py: Python<'py>,
+        mode: &str,
+        args: &PyTuple,
+        kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let data = self
+            .data()
+            .ok_or_else(|| PyFileNotFoundError::new_err(self.name().to_owned()))?;
+        let binary = bytes_io(py, &data)?;
+        if mode.contains('b') {
+            return Ok(binary);
+        }
+        let args: Vec<&PyAny> = std::iter::once(binary).chain(args.iter()).collect();
+        let args = PyTuple::new(py, args);
+        py.import(intern!(py, "io"))?
+            .getattr(intern!(py, "TextIOWrapper"))?
+            .call(args, kwargs)
+    }
+
+    fn read_bytes(&self) -> PyResult<Cow<'static, [u8]>> {
+        self.data()
+            .ok_or_else(|| PyFileNotFoundError::new_err(self.name().to_owned()))
+    }
+
+    #[pyo3(signature = (encoding = None))]
+    fn read_text<'py>(&self, py: Python<'py>, encoding: Option<&str>) -> PyResult<&'py PyAny> {
+        let kwargs = PyDict::new(py);
+        kwargs.set_item(intern!(py, "encoding"), encoding)?;
+        self.open(py, "r", PyTuple::empty(py), Some(kwargs))?
+            .call_method0(intern!(py, "read"))
+    }
+}
+
+impl Resource {
+    fn child(&self, name: String) -> Resource {
+        Resource {
+            finder: self.finder.clone(),
+            package: self.package.clone(),
+            name: Some(name),
+        }
+    }
+
+    fn data(&self) -> Option<Cow<'static, [u8]>> {
+        self.finder.resource(&self.package, self.name.as_ref()?)
+    }
+}
+
+fn bytes_io<'py>(py: Python<'py>, data: &[u8]) -> PyResult<&'py PyAny> {
+    py.import(intern!(py, "io"))?
+        .getattr(intern!(py, "BytesIO"))?
+        .call1((PyBytes::new(py, data),))
+}
+
+/// A [`ModuleFinder`] for modules and resources stored in memory.
+///
+/// Modules are given as source code or as bytecode serialized with [`marshal::dumps`]. Source
+/// files are reported in tracebacks as if the module `a.b` was loaded from `a/b.py`, or from
+/// `a/b/__init__.py` if it is a package.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::import_hooks::{add_meta_path_finder, EmbeddedModules};
+/// use pyo3::prelude::*;
+/// use pyo3::types::IntoPyDict;
+///
+/// # fn main() -> PyResult<()> {
+/// Python::with_gil(|py| {
+///     let modules = EmbeddedModules::new()
+///         .package("assets", "")
+///         .resource("assets", "greeting.txt", &b"hello"[..]);
+///     add_meta_path_finder(py, modules)?;
+///
+///     let locals = [("resources", py.import("importlib.resources")?)].into_py_dict(py);
+///     let greeting: Vec<u8> = py
+///         .eval("resources.files('assets').joinpath('greeting.txt').read_bytes()", None, Some(locals))?
+///         .extract()?;
+///     assert_eq!(greeting, b"hello");
+///     Ok(())
+/// })
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct EmbeddedModules {
+    modules: HashMap<String, FoundModule>,
+    resources: HashMap<String, BTreeMap<String, Cow<'static, [u8]>>>,
+}
+
+impl EmbeddedModules {
+    /// Creates an empty set of modules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the module `name` with the given source code.
+    pub fn module(self, name: &str, source: impl Into<Cow<'static, str>>) -> Self {
+        self.add(name, ModuleCode::Source(source.into()), false)
+    }
+
+    /// Adds the package `name`, with `source` as the code of its `__init__.py`.
+    pub fn package(self, name: &str, source: impl Into<Cow<'static, str>>) -> Self {
+        self.add(name, ModuleCode::Source(source.into()), true)
+    }
+
+    /// Adds the module or package `name` with code serialized with [`marshal::dumps`].
+    pub fn bytecode(
+        self,
+        name: &str,
+        bytecode: impl Into<Cow<'static, [u8]>>,
+        is_package: bool,
+    ) -> Self {
+        self.add(name, ModuleCode::Bytecode(bytecode.into()), is_package)
+    }
+
+    /// Adds the resource `name` to `package`, to be read with `importlib.resources`.
+    pub fn resource(
+        mut self,
+        package: &str,
+        name: &str,
+        data: impl Into<Cow<'static, [u8]>>,
+    ) -> Self {
+        self.resources
+            .entry(package.to_owned())
+            .or_default()
+            .insert(name.to_owned(), data.into());
+        self
+    }
+
+    fn add(mut self, name: &str, code: ModuleCode, is_package: bool) -> Self {
+        let path = name.replace('.', "/");
+        let origin = if is_package {
+            format!("{}/__init__.py", path)
+        } else {
+            format!("{}.py", path)
+        };
+        self.modules.insert(
+            name.to_owned(),
+            FoundModule {
+                code,
+                is_package,
+                origin,
+            },
+        );
+        self
+    }
+}
+
+impl ModuleFinder for EmbeddedModules {
+    fn find_module(&self, fullname: &str) -> Option<FoundModule> {
+        self.modules.get(fullname).cloned()
+    }
+
+    fn resource(&self, package: &str, name: &str) -> Option<Cow<'static, [u8]>> {
+        self.resources.get(package)?.get(name).cloned()
+    }
+
+    fn resource_names(&self, package: &str) -> Vec<String> {
+        self.resources
+            .get(package)
+            .map(|resources| resources.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_meta_path_finder, EmbeddedModules, ModuleFinder};
+    use crate::marshal;
+    use crate::types::PyDict;
+    use crate::Python;
+
+    #[test]
+    fn test_embedded_modules() {
+        Python::with_gil(|py| {
+            let code = py
+                .compile(
+                    "VALUE = 42",
+                    "compiled.py",
+                    crate::types::CompileMode::Exec,
+                    &crate::types::CompileFlags::new(),
+                )
+                .unwrap();
+            let bytecode = marshal::dumps(py, code, marshal::VERSION)
+                .unwrap()
+                .as_bytes()
+                .to_vec();
+
+            let modules = EmbeddedModules::new()
+                .package("pyo3_test_embedded", "from . import helpers")
+                .module(
+                    "pyo3_test_embedded.helpers",
+                    "from .compiled import VALUE\ndef fail():\n    raise ValueError(VALUE)",
+                )
+                .bytecode("pyo3_test_embedded.compiled", bytecode, false)
+                .resource("pyo3_test_embedded", "data.txt", &b"data"[..]);
+            assert_eq!(modules.resource_names("pyo3_test_embedded"), ["data.txt"]);
+            add_meta_path_finder(py, modules).unwrap();
+
+            let locals = PyDict::new(py);
+            py.run(
+                r#"
+import importlib.resources
+import traceback
+import pyo3_test_embedded
+from pyo3_test_embedded.helpers import fail
+
+assert pyo3_test_embedded.helpers.VALUE == 42
+assert pyo3_test_embedded.__path__ == []
+assert pyo3_test_embedded.__spec__.origin == "pyo3_test_embedded/__init__.py"
+if hasattr(importlib.resources, "files"):
+    files = importlib.resources.files("pyo3_test_embedded")
+    assert [f.name for f in files.iterdir()] == ["data.txt"]
+    assert (files / "data.txt").read_text() == "data"
+    assert not files.joinpath("missing.txt").is_file()
+else:
+    assert importlib.resources.read_text("pyo3_test_embedded", "data.txt") == "data"
+    assert not importlib.resources.is_resource("pyo3_test_embedded", "missing.txt")
+
+try:
+    fail()
+except ValueError:
+    tb = traceback.format_exc()
+assert 'pyo3_test_embedded/helpers.py", line 3, in fail' in tb, tb
+assert "raise ValueError(VALUE)" in tb, tb
+
+try:
+    import pyo3_test_embedded.missing
+except ImportError:
+    pass
+else:
+    assert False
+"#,
+                None,
+                Some(locals),
+            )
+            .unwrap();
+        });
+    }
+}
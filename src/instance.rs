@@ -307,6 +307,8 @@ where
     /// ```
     pub fn as_ref<'py>(&'py self, _py: Python<'py>) -> &'py T::AsRefTarget {
         let any = self.as_ptr() as *const PyAny;
+        #[cfg(all(Py_3_9, not(any(PyPy, Py_LIMITED_API))))]
+        crate::interpreter::debug_assert_same_interpreter::<T>(unsafe { &*any });
         unsafe { PyNativeType::unchecked_downcast(&*any) }
     }
 
@@ -353,6 +355,10 @@ where
     /// }
     /// ```
     pub fn into_ref(self, py: Python<'_>) -> &T::AsRefTarget {
+        #[cfg(all(Py_3_9, not(any(PyPy, Py_LIMITED_API))))]
+        crate::interpreter::debug_assert_same_interpreter::<T>(unsafe {
+            &*(self.as_ptr() as *const PyAny)
+        });
         unsafe { py.from_owned_ptr(self.into_ptr()) }
     }
 }
@@ -509,6 +515,35 @@ where
 }
 
 impl<T> Py<T> {
+    /// Borrows this `Py<T>` as a [`Bound<'py, T>`](Bound), without changing the reference count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pyo3::prelude::*;
+    /// # use pyo3::types::PyList;
+    /// #
+    /// Python::with_gil(|py| {
+    ///     let list: Py<PyList> = PyList::new_bound(py, [1, 2, 3]).unbind();
+    ///     let list: &Bound<'_, PyList> = list.bind(py);
+    ///     assert_eq!(list.len(), 3);
+    /// });
+    /// ```
+    #[inline]
+    pub fn bind<'py>(&self, _py: Python<'py>) -> &Bound<'py, T> {
+        // Safety: `Bound` has the same layout as `Py`, and the GIL is held.
+        unsafe { &*(self as *const Py<T>).cast::<Bound<'py, T>>() }
+    }
+
+    /// Converts this `Py<T>` into a [`Bound<'py, T>`](Bound).
+    ///
+    /// Unlike [`Py::into_ref`], this does not register the object in PyO3's object storage; its
+    /// reference count is decreased as soon as the returned value is dropped.
+    #[inline]
+    pub fn into_bound(self, py: Python<'_>) -> Bound<'_, T> {
+        Bound(py, mem::ManuallyDrop::new(self))
+    }
+
     /// Returns whether `self` and `other` point to the same object. To compare
     /// the equality of two objects (the `==` operator), use [`eq`](PyAny::eq).
     ///
@@ -1118,11 +1153,338 @@ impl PyObject {
     }
 }
 
+/// An owned reference to a Python object, bound to the lifetime `'py` of the GIL.
+///
+/// `Bound<'py, T>` combines the ownership of [`Py<T>`] with the convenience of GIL-bound
+/// references such as `&'py PyAny`: it carries a [`Python<'py>`](crate::Python) token, so its
+/// methods do not need one, but unlike GIL-bound references it is not stored in PyO3's object
+/// storage. The reference count is decreased as soon as the `Bound` is dropped, which keeps
+/// memory usage flat in long-running loops inside a single [`Python::with_gil`] call.
+///
+/// `Bound<'py, T>` dereferences to the GIL-bound reference of `T` (`&PyList` for
+/// `Bound<'py, PyList>`, `&PyCell<T>` for a `#[pyclass]`), so the whole existing API is available
+/// on it. The methods which create new Python objects are also implemented on `Bound` directly,
+/// returning `Bound` values instead of GIL-bound references; methods which are only available
+/// through the dereference still return GIL-bound references. Conversions in both directions
+/// make it possible to migrate code gradually:
+///
+///  - [`Py::bind`] and [`Py::into_bound`] create a `Bound` from a `Py`, and [`Bound::unbind`]
+///    converts it back.
+///  - `Bound::from(&'py T)` creates a `Bound` from a GIL-bound reference, and
+///    [`Bound::into_gil_ref`] converts it back.
+///  - [`FromPyObject::extract_bound`] extracts Rust values from a `Bound`, which is also what
+///    [`Bound::extract`] uses.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::types::PyList;
+///
+/// # fn main() -> PyResult<()> {
+/// Python::with_gil(|py| -> PyResult<()> {
+///     let list = PyList::new_bound(py, [1, 2, 3]);
+///     let mut total = 0;
+///     for _ in 0..1000 {
+///         // The temporary objects created here are freed on each iteration.
+///         for item in &list {
+///             total += item.extract::<i32>()?;
+///         }
+///     }
+///     assert_eq!(total, 6000);
+///     Ok(())
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+#[repr(transparent)]
+pub struct Bound<'py, T>(Python<'py>, mem::ManuallyDrop<Py<T>>);
+
+impl<'py, T> Bound<'py, T> {
+    /// Creates a new `Bound` from an owned pointer.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a valid, owned pointer to an object of type `T`.
+    /// Panics if `ptr` is null.
+    #[inline]
+    pub unsafe fn from_owned_ptr(py: Python<'py>, ptr: *mut ffi::PyObject) -> Self {
+        Bound(py, mem::ManuallyDrop::new(Py::from_owned_ptr(py, ptr)))
+    }
+
+    /// Creates a new `Bound` from an owned pointer, or fetches the current Python exception if
+    /// `ptr` is null.
+    ///
+    /// # Safety
+    ///
+    /// If non-null, `ptr` must be a valid, owned pointer to an object of type `T`.
+    #[inline]
+    pub unsafe fn from_owned_ptr_or_err(
+        py: Python<'py>,
+        ptr: *mut ffi::PyObject,
+    ) -> PyResult<Self> {
+        Py::from_owned_ptr_or_err(py, ptr).map(|obj| Bound(py, mem::ManuallyDrop::new(obj)))
+    }
+
+    /// Creates a new `Bound` from a borrowed pointer, increasing its reference count.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a valid pointer to an object of type `T`.
+    /// Panics if `ptr` is null.
+    #[inline]
+    pub unsafe fn from_borrowed_ptr(py: Python<'py>, ptr: *mut ffi::PyObject) -> Self {
+        Bound(py, mem::ManuallyDrop::new(Py::from_borrowed_ptr(py, ptr)))
+    }
+
+    /// Returns the GIL token this reference is bound to.
+    #[inline]
+    pub fn py(&self) -> Python<'py> {
+        self.0
+    }
+
+    /// Returns the underlying FFI pointer as a borrowed pointer.
+    #[inline]
+    pub fn as_ptr(&self) -> *mut ffi::PyObject {
+        self.1.as_ptr()
+    }
+
+    /// Borrows `self` as a `Bound` to `PyAny`.
+    #[inline]
+    pub fn as_any(&self) -> &Bound<'py, PyAny> {
+        // Safety: `Bound<T>` has the same layout for all `T`.
+        unsafe { &*(self as *const Self).cast::<Bound<'py, PyAny>>() }
+    }
+
+    /// Converts `self` into a `Bound` to `PyAny`.
+    #[inline]
+    pub fn into_any(self) -> Bound<'py, PyAny> {
+        unsafe { Bound::from_owned_ptr(self.0, self.into_ptr()) }
+    }
+
+    /// Borrows the GIL-independent [`Py<T>`] held by `self`.
+    #[inline]
+    pub fn as_unbound(&self) -> &Py<T> {
+        &self.1
+    }
+
+    /// Converts `self` into a GIL-independent [`Py<T>`].
+    #[inline]
+    pub fn unbind(self) -> Py<T> {
+        unsafe { mem::ManuallyDrop::take(&mut mem::ManuallyDrop::new(self).1) }
+    }
+
+    /// Returns whether `self` and `other` point to the same object.
+    ///
+    /// This is equivalent to the Python expression `self is other`.
+    #[inline]
+    pub fn is<U: AsPyPointer>(&self, other: &U) -> bool {
+        self.as_ptr() == other.as_ptr()
+    }
+
+    /// Downcasts `self` to a concrete Python type or pyclass.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::{PyDict, PyList};
+    ///
+    /// Python::with_gil(|py| {
+    ///     let any = PyDict::new_bound(py).into_any();
+    ///     assert!(any.downcast::<PyDict>().is_ok());
+    ///     assert!(any.downcast::<PyList>().is_err());
+    /// });
+    /// ```
+    pub fn downcast<U>(&self) -> Result<&Bound<'py, U>, PyDowncastError<'_>>
+    where
+        U: PyTypeInfo,
+    {
+        let any: &PyAny = self.as_any();
+        if U::is_type_of(any) {
+            // Safety: the type was checked above.
+            Ok(unsafe { self.downcast_unchecked() })
+        } else {
+            Err(PyDowncastError::new(any, U::NAME))
+        }
+    }
+
+    /// Downcasts `self` to a concrete Python type or pyclass, consuming it.
+    pub fn downcast_into<U>(self) -> PyResult<Bound<'py, U>>
+    where
+        U: PyTypeInfo,
+    {
+        self.downcast::<U>()?;
+        Ok(unsafe { Bound::from_owned_ptr(self.0, self.into_ptr()) })
+    }
+
+    /// Casts `self` to a concrete Python type without checking validity.
+    ///
+    /// # Safety
+    ///
+    /// Callers must ensure that the type is valid or risk type confusion.
+    #[inline]
+    pub unsafe fn downcast_unchecked<U>(&self) -> &Bound<'py, U> {
+        &*(self as *const Self).cast::<Bound<'py, U>>()
+    }
+
+    /// Extracts some type from the Python object.
+    ///
+    /// This is a wrapper function around [`FromPyObject::extract_bound`].
+    #[inline]
+    pub fn extract<'a, D>(&'a self) -> PyResult<D>
+    where
+        D: FromPyObject<'a>,
+    {
+        D::extract_bound(self.as_any())
+    }
+}
+
+impl<'py, T> Bound<'py, T>
+where
+    T: PyTypeInfo,
+{
+    /// Borrows the GIL-bound reference to the contained `T`.
+    ///
+    /// This is the same as dereferencing `self`.
+    #[inline]
+    pub fn as_gil_ref(&self) -> &T::AsRefTarget {
+        let any = self.as_ptr() as *const PyAny;
+        unsafe { PyNativeType::unchecked_downcast(&*any) }
+    }
+
+    /// Converts `self` into a GIL-bound reference to the contained `T`.
+    ///
+    /// Like [`Py::into_ref`], this registers the object in PyO3's object storage, so that its
+    /// reference count will not be decreased until the GIL lifetime ends.
+    #[inline]
+    pub fn into_gil_ref(self) -> &'py T::AsRefTarget {
+        unsafe { self.0.from_owned_ptr(self.into_ptr()) }
+    }
+}
+
+impl<'py, T> std::ops::Deref for Bound<'py, T>
+where
+    T: PyTypeInfo,
+{
+    type Target = T::AsRefTarget;
+
+    #[inline]
+    fn deref(&self) -> &T::AsRefTarget {
+        self.as_gil_ref()
+    }
+}
+
+impl<'py, T> std::convert::From<&'py T> for Bound<'py, T>
+where
+    T: PyTypeInfo + PyNativeType + AsPyPointer,
+{
+    #[inline]
+    fn from(obj: &'py T) -> Self {
+        unsafe { Bound::from_borrowed_ptr(obj.py(), obj.as_ptr()) }
+    }
+}
+
+impl<'py, T> std::convert::From<&'py PyCell<T>> for Bound<'py, T>
+where
+    T: PyClass,
+{
+    #[inline]
+    fn from(cell: &'py PyCell<T>) -> Self {
+        unsafe { Bound::from_borrowed_ptr(cell.py(), cell.as_ptr()) }
+    }
+}
+
+impl<T> std::convert::From<Bound<'_, T>> for Py<T> {
+    #[inline]
+    fn from(bound: Bound<'_, T>) -> Self {
+        bound.unbind()
+    }
+}
+
+/// Cloning a `Bound` increments the reference count of the object.
+impl<T> Clone for Bound<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe { Bound::from_borrowed_ptr(self.0, self.as_ptr()) }
+    }
+}
+
+/// Dropping a `Bound` immediately decrements the reference count of the object.
+impl<T> Drop for Bound<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { ffi::Py_DECREF(self.as_ptr()) }
+    }
+}
+
+impl<T> AsPyPointer for Bound<'_, T> {
+    #[inline]
+    fn as_ptr(&self) -> *mut ffi::PyObject {
+        self.1.as_ptr()
+    }
+}
+
+impl<T> IntoPyPointer for Bound<'_, T> {
+    #[inline]
+    fn into_ptr(self) -> *mut ffi::PyObject {
+        self.unbind().into_ptr()
+    }
+}
+
+impl<T> ToPyObject for Bound<'_, T> {
+    #[inline]
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        unsafe { PyObject::from_borrowed_ptr(py, self.as_ptr()) }
+    }
+}
+
+impl<T> IntoPy<PyObject> for Bound<'_, T> {
+    #[inline]
+    fn into_py(self, _py: Python<'_>) -> PyObject {
+        self.into_any().unbind()
+    }
+}
+
+impl<'py, T> FromPyObject<'py> for Bound<'py, T>
+where
+    T: PyTypeInfo,
+{
+    /// Extracts `Self` from the source `PyObject`.
+    fn extract(ob: &'py PyAny) -> PyResult<Self> {
+        if T::is_type_of(ob) {
+            Ok(unsafe { Bound::from_borrowed_ptr(ob.py(), ob.as_ptr()) })
+        } else {
+            Err(PyDowncastError::new(ob, T::NAME).into())
+        }
+    }
+}
+
+impl<T> std::fmt::Display for Bound<'_, T>
+where
+    T: PyTypeInfo,
+    T::AsRefTarget: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.as_gil_ref(), f)
+    }
+}
+
+impl<T> std::fmt::Debug for Bound<'_, T>
+where
+    T: PyTypeInfo,
+    T::AsRefTarget: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.as_gil_ref(), f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Py, PyObject};
+    use super::{Bound, Py, PyObject};
     use crate::types::{PyDict, PyString};
-    use crate::{PyAny, PyResult, Python, ToPyObject};
+    use crate::{ffi, AsPyPointer, PyAny, PyResult, Python, ToPyObject};
 
     #[test]
     fn test_call0() {
@@ -1252,6 +1614,100 @@ a = A()
         });
     }
 
+    #[test]
+    fn test_bound_reference_counting() {
+        Python::with_gil(|py| {
+            let obj: PyObject = PyDict::new(py).into();
+            let ptr = obj.as_ptr();
+            let refcnt = || unsafe { ffi::Py_REFCNT(ptr) };
+            assert_eq!(refcnt(), 2);
+
+            let bound = obj.bind(py);
+            assert!(bound.is(&obj));
+            assert_eq!(refcnt(), 2);
+
+            let owned = obj.clone_ref(py).into_bound(py);
+            let cloned = owned.clone();
+            assert_eq!(refcnt(), 4);
+            drop(cloned);
+            assert_eq!(refcnt(), 3);
+            let unbound: PyObject = owned.unbind();
+            assert_eq!(refcnt(), 3);
+            drop(unbound);
+            assert_eq!(refcnt(), 2);
+
+            // Converting to a GIL-bound reference registers it in the pool.
+            let pool = unsafe { py.new_pool() };
+            let gil_ref: &PyAny = obj.clone_ref(py).into_bound(py).into_gil_ref();
+            assert!(gil_ref.is(&obj));
+            assert_eq!(refcnt(), 3);
+            drop(pool);
+            assert_eq!(refcnt(), 2);
+        });
+    }
+
+    #[test]
+    fn test_bound_downcast_and_extract() {
+        Python::with_gil(|py| {
+            let any = PyString::new_bound(py, "hello").into_any();
+            assert_eq!(any.extract::<&str>().unwrap(), "hello");
+            assert_eq!(
+                any.downcast::<PyString>().unwrap().to_str().unwrap(),
+                "hello"
+            );
+
+            let err = any.downcast::<PyDict>().unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "'str' object cannot be converted to 'PyDict'"
+            );
+            assert!(any.clone().downcast_into::<PyDict>().is_err());
+            let string = any.downcast_into::<PyString>().unwrap();
+            assert_eq!(format!("{} {:?}", string, string), "hello 'hello'");
+
+            let from_gil_ref: Bound<'_, PyString> = PyString::new(py, "world").into();
+            assert_eq!(from_gil_ref.to_str().unwrap(), "world");
+            let obj = from_gil_ref.to_object(py);
+            let extracted: Bound<'_, PyString> = obj.extract(py).unwrap();
+            assert!(extracted.is(&from_gil_ref));
+        });
+    }
+
+    #[test]
+    fn test_bound_call() {
+        Python::with_gil(|py| {
+            let builtins = py.import("builtins").unwrap();
+            let builtins = Bound::from(builtins);
+            let int = builtins.getattr("int").unwrap();
+            assert_eq!(int.call0().unwrap().extract::<i32>().unwrap(), 0);
+            assert_eq!(int.call1(("12",)).unwrap().extract::<i32>().unwrap(), 12);
+
+            let kwargs = PyDict::new_bound(py);
+            kwargs.set_item("base", 16).unwrap();
+            let value = int.call(("ff",), Some(&kwargs)).unwrap();
+            assert_eq!(value.extract::<i32>().unwrap(), 255);
+            assert_eq!(
+                value
+                    .call_method0("bit_length")
+                    .unwrap()
+                    .extract::<i32>()
+                    .unwrap(),
+                8
+            );
+            assert_eq!(
+                value
+                    .call_method1("to_bytes", (2, "big"))
+                    .unwrap()
+                    .extract::<Vec<u8>>()
+                    .unwrap(),
+                [0, 255]
+            );
+            assert_eq!(value.repr().unwrap().to_str().unwrap(), "255");
+            assert_eq!(value.str().unwrap().to_str().unwrap(), "255");
+            assert!(value.getattr("nonexistent").is_err());
+        });
+    }
+
     #[cfg(feature = "macros")]
     mod using_macros {
         use super::*;
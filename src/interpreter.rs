@@ -0,0 +1,440 @@
+#![cfg(all(Py_3_8, not(any(PyPy, Py_LIMITED_API))))]
+
+//! Configuration of embedded Python interpreters.
+//!
+//! [`InterpreterBuilder`] is a safe wrapper around the [PEP 587] initialization configuration,
+//! for applications which need more control over the interpreter than
+//! [`prepare_freethreaded_python`](crate::prepare_freethreaded_python) offers.
+//!
+//! [`SubInterpreter`] runs code in an isolated subinterpreter of the main interpreter.
+//!
+//! [PEP 587]: https://peps.python.org/pep-0587/
+
+use crate::exceptions::PyRuntimeError;
+use crate::gil::{self, GILPool};
+use crate::{ffi, PyResult};
+use std::ffi::{CStr, OsStr, OsString};
+use std::fmt;
+use std::mem::ManuallyDrop;
+use std::sync::atomic::Ordering;
+
+#[cfg(Py_3_9)]
+mod subinterpreter;
+
+#[cfg(Py_3_9)]
+pub use self::subinterpreter::SubInterpreter;
+#[cfg(Py_3_9)]
+pub(crate) use self::subinterpreter::{debug_assert_same_interpreter, subinterpreter_local};
+
+/// Builder for initializing an embedded Python interpreter with a custom configuration.
+///
+/// Options which are not set keep the defaults of the [Python configuration], or of the
+/// [isolated configuration] when [`isolated`](InterpreterBuilder::isolated) is enabled. Signal
+/// handlers are not installed unless requested, in line with
+/// [`prepare_freethreaded_python`](crate::prepare_freethreaded_python).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pyo3::interpreter::InterpreterBuilder;
+/// use pyo3::prelude::*;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let interpreter = InterpreterBuilder::new()
+///     .isolated(true)
+///     .utf8_mode(true)
+///     .site_import(false)
+///     .argv(["my-service", "--verbose"])
+///     .initialize()?;
+///
+/// Python::with_gil(|py| py.run("import sys; print(sys.argv)", None, None))?;
+///
+/// // Finalizes the interpreter.
+/// drop(interpreter);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [Python configuration]: https://docs.python.org/3/c-api/init_config.html#init-python-config
+/// [isolated configuration]: https://docs.python.org/3/c-api/init_config.html#init-isolated-conf
+#[derive(Clone, Debug, Default)]
+pub struct InterpreterBuilder {
+    isolated: bool,
+    utf8_mode: Option<bool>,
+    ignore_environment: Option<bool>,
+    install_signal_handlers: bool,
+    site_import: Option<bool>,
+    home: Option<OsString>,
+    module_search_paths: Option<Vec<OsString>>,
+    argv: Option<Vec<OsString>>,
+}
+
+impl InterpreterBuilder {
+    /// Creates a builder with the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts from the isolated configuration, which ignores environment variables, the user site
+    /// directory and the current directory when computing `sys.path`.
+    pub fn isolated(mut self, isolated: bool) -> Self {
+        self.isolated = isolated;
+        self
+    }
+
+    /// Enables or disables the Python UTF-8 mode.
+    pub fn utf8_mode(mut self, utf8_mode: bool) -> Self {
+        self.utf8_mode = Some(utf8_mode);
+        self
+    }
+
+    /// Ignores `PYTHON*` environment variables such as `PYTHONPATH` and `PYTHONHOME`.
+    pub fn ignore_environment(mut self, ignore_environment: bool) -> Self {
+        self.ignore_environment = Some(ignore_environment);
+        self
+    }
+
+    /// Installs Python's signal handlers, so that for example `SIGINT` raises
+    /// `KeyboardInterrupt`.
+    ///
+    /// Python signal handling depends on the notion of a 'main thread', which will be the thread
+    /// calling [`initialize`](InterpreterBuilder::initialize).
+    pub fn install_signal_handlers(mut self, install_signal_handlers: bool) -> Self {
+        self.install_signal_handlers = install_signal_handlers;
+        self
+    }
+
+    /// Whether to import the `site` module at startup.
+    pub fn site_import(mut self, site_import: bool) -> Self {
+        self.site_import = Some(site_import);
+        self
+    }
+
+    /// Sets the Python home directory, as done by the `PYTHONHOME` environment variable.
+    pub fn home(mut self, home: impl AsRef<OsStr>) -> Self {
+        self.home = Some(home.as_ref().to_owned());
+        self
+    }
+
+    /// Sets `sys.path` explicitly instead of computing it at startup.
+    ///
+    /// The paths must include the standard library, which is needed to initialize the
+    /// interpreter.
+    pub fn module_search_paths<I>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<OsStr>,
+    {
+        self.module_search_paths = Some(to_os_strings(paths));
+        self
+    }
+
+    /// Sets `sys.argv`.
+    ///
+    /// The arguments are not parsed as Python command line options.
+    pub fn argv<I>(mut self, argv: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<OsStr>,
+    {
+        self.argv = Some(to_os_strings(argv));
+        self
+    }
+
+    /// Initializes the Python interpreter with this configuration.
+    ///
+    /// The returned guard finalizes the interpreter when dropped. In the meantime the interpreter
+    /// can be used from any thread with [`Python::with_gil`](crate::Python::with_gil).
+    ///
+    /// Fails if the interpreter is already initialized, or if Python rejects the configuration.
+    pub fn initialize(self) -> Result<InterpreterGuard, InitializationError> {
+        if unsafe { ffi::Py_IsInitialized() } != 0 || gil::FINALIZED.load(Ordering::Acquire) {
+            return Err(InitializationError::AlreadyInitialized);
+        }
+        // Validate strings up front, so that nothing is initialized on failure.
+        for s in self
+            .home
+            .iter()
+            .chain(self.module_search_paths.iter().flatten())
+            .chain(self.argv.iter().flatten())
+        {
+            if s.to_string_lossy().contains('\0') {
+                return Err(InitializationError::InteriorNul(s.clone()));
+            }
+        }
+
+        unsafe {
+            let mut preconfig = std::mem::MaybeUninit::<ffi::PyPreConfig>::uninit();
+            if self.isolated {
+                ffi::PyPreConfig_InitIsolatedConfig(preconfig.as_mut_ptr());
+            } else {
+                ffi::PyPreConfig_InitPythonConfig(preconfig.as_mut_ptr());
+            }
+            let mut preconfig = preconfig.assume_init();
+            if let Some(utf8_mode) = self.utf8_mode {
+                preconfig.utf8_mode = utf8_mode.into();
+            }
+            if let Some(ignore_environment) = self.ignore_environment {
+                preconfig.use_environment = (!ignore_environment).into();
+            }
+            check_status(ffi::Py_PreInitialize(&preconfig))?;
+
+            let mut config = std::mem::MaybeUninit::<ffi::PyConfig>::uninit();
+            if self.isolated {
+                ffi::PyConfig_InitIsolatedConfig(config.as_mut_ptr());
+            } else {
+                ffi::PyConfig_InitPythonConfig(config.as_mut_ptr());
+            }
+            let mut config = config.assume_init();
+            let result = self
+                .apply(&mut config)
+                .and_then(|()| check_status(ffi::Py_InitializeFromConfig(&config)));
+            ffi::PyConfig_Clear(&mut config);
+            result?;
+
+            // Import the threading module - this ensures that it will associate this thread as
+            // the "main" thread, which is important to avoid an `AssertionError` at finalization.
+            let pool = GILPool::new();
+            let imported = pool.python().import("threading").is_ok();
+            drop(pool);
+            if !imported {
+                ffi::Py_Finalize();
+                return Err(InitializationError::Status {
+                    function: None,
+                    message: "failed to import the threading module".to_owned(),
+                });
+            }
+
+            // Release the GIL.
+            let tstate = ffi::PyEval_SaveThread();
+            Ok(InterpreterGuard { tstate })
+        }
+    }
+
+    /// Copies the options into a `PyConfig` created by one of the `PyConfig_Init*` functions.
+    unsafe fn apply(&self, config: &mut ffi::PyConfig) -> Result<(), InitializationError> {
+        config.install_signal_handlers = self.install_signal_handlers.into();
+        config.parse_argv = 0;
+        if let Some(ignore_environment) = self.ignore_environment {
+            config.use_environment = (!ignore_environment).into();
+        }
+        if let Some(site_import) = self.site_import {
+            config.site_import = site_import.into();
+        }
+        if let Some(home) = &self.home {
+            let home = WideString::new(home)?;
+            check_status(ffi::PyConfig_SetString(
+                config,
+                &mut config.home,
+                home.as_ptr(),
+            ))?;
+        }
+        if let Some(paths) = &self.module_search_paths {
+            config.module_search_paths_set = 1;
+            for path in paths {
+                let path = WideString::new(path)?;
+                check_status(ffi::PyWideStringList_Append(
+                    &mut config.module_search_paths,
+                    path.as_ptr(),
+                ))?;
+            }
+        }
+        if let Some(argv) = &self.argv {
+            let argv = argv
+                .iter()
+                .map(|arg| WideString::new(arg))
+                .collect::<Result<Vec<_>, _>>()?;
+            let mut ptrs: Vec<_> = argv.iter().map(WideString::as_ptr).collect();
+            check_status(ffi::PyConfig_SetArgv(
+                config,
+                ptrs.len() as ffi::Py_ssize_t,
+                ptrs.as_mut_ptr(),
+            ))?;
+        }
+        Ok(())
+    }
+}
+
+/// Guard for an interpreter started by [`InterpreterBuilder::initialize`].
+///
+/// Dropping the guard, or calling [`finalize`](InterpreterGuard::finalize), finalizes the
+/// interpreter, which must happen on the thread which initialized it while that thread does not
+/// hold the GIL. If the GIL is held, dropping the guard prints a warning and leaks the
+/// interpreter instead. After finalization the interpreter cannot be used again:
+/// [`Python::with_gil`](crate::Python::with_gil) will panic, and Python objects which are still
+/// alive are leaked.
+#[must_use = "dropping the guard finalizes the interpreter"]
+pub struct InterpreterGuard {
+    tstate: *mut ffi::PyThreadState,
+}
+
+impl fmt::Debug for InterpreterGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InterpreterGuard").finish()
+    }
+}
+
+impl InterpreterGuard {
+    /// Finalizes the interpreter.
+    ///
+    /// Fails if the current thread holds the GIL, in which case the interpreter is leaked
+    /// rather than finalized.
+    pub fn finalize(self) -> PyResult<()> {
+        let guard = ManuallyDrop::new(self);
+        if gil::gil_is_acquired() {
+            return Err(PyRuntimeError::new_err(FINALIZE_WITH_GIL));
+        }
+        unsafe { guard.finalize_unchecked() };
+        Ok(())
+    }
+
+    /// Safety: the current thread must not hold the GIL.
+    unsafe fn finalize_unchecked(&self) {
+        ffi::PyEval_RestoreThread(self.tstate);
+        // Process any pending reference count updates before finalizing.
+        drop(GILPool::new());
+        gil::FINALIZED.store(true, Ordering::Release);
+        ffi::Py_Finalize();
+    }
+}
+
+const FINALIZE_WITH_GIL: &str = "cannot finalize the Python interpreter while the GIL is held";
+
+impl Drop for InterpreterGuard {
+    fn drop(&mut self) {
+        if gil::gil_is_acquired() {
+            eprintln!("{}, leaking it instead", FINALIZE_WITH_GIL);
+            return;
+        }
+        unsafe { self.finalize_unchecked() };
+    }
+}
+
+/// Error returned by [`InterpreterBuilder::initialize`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InitializationError {
+    /// A Python interpreter was already initialized in this process.
+    AlreadyInitialized,
+    /// A configuration string contained a nul byte.
+    InteriorNul(OsString),
+    /// Python failed to initialize.
+    Status {
+        /// The name of the C function which reported the failure, if known.
+        function: Option<String>,
+        /// The error message reported by Python.
+        message: String,
+    },
+    /// Python requested to exit the process with the given exit code.
+    Exit(i32),
+}
+
+impl fmt::Display for InitializationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InitializationError::AlreadyInitialized => {
+                f.write_str("the Python interpreter is already initialized")
+            }
+            InitializationError::InteriorNul(s) => {
+                write!(f, "configuration string {:?} contains a nul byte", s)
+            }
+            InitializationError::Status {
+                function: Some(function),
+                message,
+            } => write!(f, "{}: {}", function, message),
+            InitializationError::Status {
+                function: None,
+                message,
+            } => f.write_str(message),
+            InitializationError::Exit(code) => {
+                write!(f, "Python requested to exit with code {}", code)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InitializationError {}
+
+fn check_status(status: ffi::PyStatus) -> Result<(), InitializationError> {
+    unsafe {
+        if ffi::PyStatus_Exception(status) == 0 {
+            return Ok(());
+        }
+        if ffi::PyStatus_IsExit(status) != 0 {
+            return Err(InitializationError::Exit(status.exitcode));
+        }
+        let to_string = |ptr: *const std::os::raw::c_char| {
+            if ptr.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+            }
+        };
+        Err(InitializationError::Status {
+            function: to_string(status.func),
+            message: to_string(status.err_msg).unwrap_or_else(|| "unknown error".to_owned()),
+        })
+    }
+}
+
+fn to_os_strings<I>(items: I) -> Vec<OsString>
+where
+    I: IntoIterator,
+    I::Item: AsRef<OsStr>,
+{
+    items
+        .into_iter()
+        .map(|item| item.as_ref().to_owned())
+        .collect()
+}
+
+/// A nul-terminated `wchar_t` string, as expected by the `PyConfig` API.
+#[cfg(not(windows))]
+struct WideString(std::ptr::NonNull<libc::wchar_t>);
+
+#[cfg(not(windows))]
+impl WideString {
+    /// Decodes `s` with the locale encoding, so Python must be preinitialized.
+    fn new(s: &OsStr) -> Result<Self, InitializationError> {
+        use std::os::unix::ffi::OsStrExt;
+        let bytes = std::ffi::CString::new(s.as_bytes())
+            .map_err(|_| InitializationError::InteriorNul(s.to_owned()))?;
+        let ptr = unsafe { ffi::Py_DecodeLocale(bytes.as_ptr(), std::ptr::null_mut()) };
+        std::ptr::NonNull::new(ptr)
+            .map(WideString)
+            .ok_or_else(|| InitializationError::Status {
+                function: Some("Py_DecodeLocale".to_owned()),
+                message: format!("failed to decode {:?}", s),
+            })
+    }
+
+    fn as_ptr(&self) -> *const libc::wchar_t {
+        self.0.as_ptr()
+    }
+}
+
+#[cfg(not(windows))]
+impl Drop for WideString {
+    fn drop(&mut self) {
+        unsafe { ffi::PyMem_RawFree(self.0.as_ptr().cast()) }
+    }
+}
+
+/// A nul-terminated `wchar_t` string, as expected by the `PyConfig` API.
+#[cfg(windows)]
+struct WideString(Vec<libc::wchar_t>);
+
+#[cfg(windows)]
+impl WideString {
+    fn new(s: &OsStr) -> Result<Self, InitializationError> {
+        use std::os::windows::ffi::OsStrExt;
+        let wide: Vec<_> = s.encode_wide().chain(std::iter::once(0)).collect();
+        if wide[..wide.len() - 1].contains(&0) {
+            return Err(InitializationError::InteriorNul(s.to_owned()));
+        }
+        Ok(WideString(wide))
+    }
+
+    fn as_ptr(&self) -> *const libc::wchar_t {
+        self.0.as_ptr()
+    }
+}
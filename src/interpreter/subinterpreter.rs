@@ -0,0 +1,260 @@
+use crate::exceptions::PyRuntimeError;
+use crate::gil::GILPool;
+use crate::marker::Ungil;
+use crate::sync::GILOnceCell;
+use crate::types::{PyCapsule, PyString};
+use crate::{ffi, AsPyPointer, Py, PyAny, PyErr, PyResult, PyTypeInfo, Python};
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set once PyO3 has created a subinterpreter. Until then, no per-interpreter bookkeeping is done.
+static SUBINTERPRETERS_USED: AtomicBool = AtomicBool::new(false);
+
+/// An isolated Python subinterpreter.
+///
+/// Each subinterpreter has its own modules, `sys` state and `__main__` namespace, which makes
+/// them suitable for running untrusted or tenant-specific scripts side by side. The
+/// subinterpreter is destroyed when this value is dropped.
+///
+/// Subinterpreters share the GIL of the main interpreter. PyO3 creates separate `#[pyclass]` type
+/// objects, [`intern!`](crate::intern) strings and exception types for each interpreter, and
+/// [`GILOnceCell::per_interpreter`](crate::sync::GILOnceCell::per_interpreter) can be used to do
+/// the same for other cached Python objects.
+///
+/// Python objects must not be shared between interpreters. As each interpreter has its own
+/// `#[pyclass]` type objects, extracting or downcasting a `#[pyclass]` instance created in a
+/// different interpreter fails with a `TypeError`. [`Py::as_ref`](crate::Py::as_ref) and
+/// [`Py::into_ref`](crate::Py::into_ref) cannot report an error; they only check `#[pyclass]`
+/// instances, only in debug builds, and panic if the check fails. Objects of Python's built-in
+/// types are never checked.
+///
+/// `#[pymodule]`s can only be initialized once per process, so they cannot be imported in
+/// subinterpreters.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::interpreter::SubInterpreter;
+/// use pyo3::prelude::*;
+///
+/// # fn main() -> PyResult<()> {
+/// let tenant = Python::with_gil(SubInterpreter::new)?;
+/// tenant.run("import sys; sys.tenant = 'a'")?;
+///
+/// let value: String = tenant.with_gil(|py| py.eval("sys.tenant", None, None)?.extract())?;
+/// assert_eq!(value, "a");
+///
+/// // The main interpreter is not affected.
+/// Python::with_gil(|py| assert!(py.eval("__import__('sys').tenant", None, None).is_err()));
+/// # Ok(())
+/// # }
+/// ```
+pub struct SubInterpreter {
+    tstate: *mut ffi::PyThreadState,
+    id: i64,
+}
+
+impl SubInterpreter {
+    /// Creates a new subinterpreter.
+    ///
+    /// The subinterpreter is bound to the current thread.
+    pub fn new(py: Python<'_>) -> PyResult<Self> {
+        // Create the shared key in the calling interpreter rather than a subinterpreter.
+        interpreter_locals_key(py);
+        SUBINTERPRETERS_USED.store(true, Ordering::Release);
+        unsafe {
+            let prev = ffi::PyThreadState_Get();
+            let tstate = new_interpreter()?;
+            let id = current_interpreter_id();
+            ffi::PyThreadState_Swap(prev);
+            Ok(SubInterpreter { tstate, id })
+        }
+    }
+
+    /// Returns the unique ID of this interpreter.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Acquires the GIL and runs `f` in this subinterpreter.
+    ///
+    /// Objects of the calling interpreter must not be used in `f`, which is why `f` and its
+    /// return value must be [`Ungil`]. Owned references such as [`Py<T>`](crate::Py) are
+    /// allowed, but will only be checked at runtime.
+    pub fn with_gil<F, R>(&self, f: F) -> R
+    where
+        F: Ungil + for<'py> FnOnce(Python<'py>) -> R,
+        R: Ungil,
+    {
+        Python::with_gil(|_| unsafe {
+            let _swap = SwapThreadState(ffi::PyThreadState_Swap(self.tstate));
+            let pool = GILPool::new();
+            f(pool.python())
+        })
+    }
+
+    /// Runs Python code in the `__main__` module of this subinterpreter.
+    ///
+    /// Exceptions raised by the code are reported as a `RuntimeError` containing the original
+    /// exception, as they cannot be shared with the calling interpreter.
+    pub fn run(&self, code: &str) -> PyResult<()> {
+        self.with_gil(|py| {
+            py.run(code, None, None)
+                .map_err(|err| PyRuntimeError::new_err(err.to_string()))
+        })
+    }
+}
+
+impl Drop for SubInterpreter {
+    fn drop(&mut self) {
+        Python::with_gil(|_| unsafe {
+            let prev = ffi::PyThreadState_Swap(self.tstate);
+            ffi::Py_EndInterpreter(self.tstate);
+            ffi::PyThreadState_Swap(prev);
+        })
+    }
+}
+
+/// Restores the previous thread state on drop.
+struct SwapThreadState(*mut ffi::PyThreadState);
+
+impl Drop for SwapThreadState {
+    fn drop(&mut self) {
+        unsafe { ffi::PyThreadState_Swap(self.0) };
+    }
+}
+
+#[cfg(not(Py_3_12))]
+unsafe fn new_interpreter() -> PyResult<*mut ffi::PyThreadState> {
+    let tstate = ffi::Py_NewInterpreter();
+    if tstate.is_null() {
+        Err(PyRuntimeError::new_err("failed to create a subinterpreter"))
+    } else {
+        Ok(tstate)
+    }
+}
+
+#[cfg(Py_3_12)]
+unsafe fn new_interpreter() -> PyResult<*mut ffi::PyThreadState> {
+    // Equivalent to `Py_NewInterpreter`: the GIL and allocator are shared with the main
+    // interpreter, which PyO3's GIL handling relies on.
+    let config = ffi::PyInterpreterConfig {
+        use_main_obmalloc: 1,
+        allow_fork: 1,
+        allow_exec: 1,
+        allow_threads: 1,
+        allow_daemon_threads: 1,
+        check_multi_interp_extensions: 0,
+        gil: ffi::PyInterpreterConfig_SHARED_GIL,
+    };
+    let mut tstate = std::ptr::null_mut();
+    let status = ffi::Py_NewInterpreterFromConfig(&mut tstate, &config);
+    if ffi::PyStatus_Exception(status) != 0 {
+        let message = if status.err_msg.is_null() {
+            "failed to create a subinterpreter".into()
+        } else {
+            std::ffi::CStr::from_ptr(status.err_msg).to_string_lossy()
+        };
+        Err(PyRuntimeError::new_err(message.into_owned()))
+    } else {
+        Ok(tstate)
+    }
+}
+
+fn current_interpreter_id() -> i64 {
+    unsafe { ffi::PyInterpreterState_GetID(ffi::PyInterpreterState_Get()) }
+}
+
+/// The values of [`subinterpreter_local`] belonging to one subinterpreter, keyed by the address
+/// and type of the main interpreter's value.
+struct InterpreterLocals(RefCell<HashMap<(usize, TypeId), Box<dyn Any + Send>>>);
+
+/// Returns the key under which [`InterpreterLocals`] are stored in the interpreter state
+/// dictionary.
+///
+/// The key is created once and shared by all interpreters, which is sound because PyO3's
+/// subinterpreters share the GIL and the object allocator of the main interpreter.
+fn interpreter_locals_key(py: Python<'_>) -> *mut ffi::PyObject {
+    static KEY: GILOnceCell<Py<PyString>> = GILOnceCell::new();
+    KEY.get_or_init(py, || PyString::new(py, "pyo3.interpreter_locals").into())
+        .as_ptr()
+}
+
+/// Returns the copy of `main` belonging to the current subinterpreter, or `None` in the main
+/// interpreter.
+///
+/// The copy is created with `init` on first use, and is stored in the interpreter's state
+/// dictionary so that it is dropped together with the interpreter.
+pub(crate) fn subinterpreter_local<'py, T: Send + 'static>(
+    py: Python<'py>,
+    main: &'static T,
+    init: fn() -> T,
+) -> PyResult<Option<&'py T>> {
+    if !SUBINTERPRETERS_USED.load(Ordering::Acquire) {
+        return Ok(None);
+    }
+    let locals = unsafe {
+        let interp = ffi::PyInterpreterState_Get();
+        if interp == ffi::PyInterpreterState_Main() {
+            return Ok(None);
+        }
+        interpreter_locals(py, ffi::PyInterpreterState_GetDict(interp))?
+    };
+
+    let key = (main as *const T as usize, TypeId::of::<T>());
+    let existing = locals
+        .0
+        .borrow()
+        .get(&key)
+        .map(|value| value.as_ref() as *const dyn Any);
+    let value = match existing {
+        Some(value) => value,
+        None => {
+            let mut values = locals.0.borrow_mut();
+            let value = values.entry(key).or_insert_with(|| Box::new(init()));
+            value.as_ref() as *const dyn Any
+        }
+    };
+    // The boxed values are never removed or replaced, so they live as long as the capsule
+    // holding them, which is only dropped together with the interpreter.
+    let value = unsafe { &*value };
+    Ok(value.downcast_ref::<T>())
+}
+
+/// Looks up the [`InterpreterLocals`] stored in `dict`, creating them if needed.
+unsafe fn interpreter_locals<'py>(
+    py: Python<'py>,
+    dict: *mut ffi::PyObject,
+) -> PyResult<&'py InterpreterLocals> {
+    let key = interpreter_locals_key(py);
+    let mut capsule = ffi::PyDict_GetItemWithError(dict, key);
+    if capsule.is_null() {
+        if let Some(err) = PyErr::take(py) {
+            return Err(err);
+        }
+        let new = PyCapsule::new(py, InterpreterLocals(RefCell::new(HashMap::new())), None)?;
+        crate::err::error_on_minusone(py, ffi::PyDict_SetItem(dict, key, new.as_ptr()))?;
+        capsule = new.as_ptr();
+    }
+    let capsule: &PyCapsule = (*(capsule as *const PyAny)).downcast()?;
+    Ok(capsule.reference::<InterpreterLocals>())
+}
+
+/// Asserts in debug builds that `obj` is an instance of `T` in the current interpreter.
+///
+/// Each interpreter has its own `#[pyclass]` type objects, so this fails for `#[pyclass]`
+/// instances created in another interpreter. Types shared by all interpreters, like Python's
+/// built-in types, cannot be checked. This is only a debug check: release builds do not detect
+/// objects used in the wrong interpreter.
+#[inline]
+pub(crate) fn debug_assert_same_interpreter<T: PyTypeInfo>(obj: &PyAny) {
+    if cfg!(debug_assertions) && SUBINTERPRETERS_USED.load(Ordering::Relaxed) {
+        debug_assert!(
+            T::is_type_of(obj),
+            "`{}` object used in an interpreter other than the one which created it",
+            T::NAME
+        );
+    }
+}
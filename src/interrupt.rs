@@ -0,0 +1,133 @@
+//! Noticing `SIGINT` while the GIL is released, for [`Python::allow_threads_interruptible`].
+//!
+//! [`Python::allow_threads_interruptible`]: crate::Python::allow_threads_interruptible
+
+use parking_lot::{const_mutex, Mutex};
+use std::os::raw::c_int;
+#[cfg(unix)]
+use std::os::raw::c_void;
+#[cfg(unix)]
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The number of `SIGINT`s received while the chaining handler was installed.
+static INTERRUPTS: AtomicUsize = AtomicUsize::new(0);
+
+/// The handler which was installed before [`on_interrupt`], called after counting a signal.
+static PREVIOUS_HANDLER: AtomicUsize = AtomicUsize::new(0);
+
+/// Whether the previous handler was installed with `SA_SIGINFO`, and so takes three arguments.
+#[cfg(unix)]
+static PREVIOUS_SIGINFO: AtomicBool = AtomicBool::new(false);
+
+/// Serializes installing the handler.
+static INSTALL: Mutex<()> = const_mutex(());
+
+/// Tells long-running Rust code that the user has pressed Ctrl-C.
+///
+/// Tokens are passed to the closure of [`Python::allow_threads_interruptible`], which should
+/// poll [`is_cancelled`](CancellationToken::is_cancelled) regularly and return early once it is
+/// set. Polling is a single atomic load, and does not need the GIL.
+///
+/// [`Python::allow_threads_interruptible`]: crate::Python::allow_threads_interruptible
+#[derive(Debug)]
+pub struct CancellationToken {
+    start: usize,
+}
+
+impl CancellationToken {
+    /// Creates a token which is set by the next `SIGINT`, installing the handler if needed.
+    pub(crate) fn new() -> Self {
+        install_handler();
+        CancellationToken {
+            start: INTERRUPTS.load(Ordering::SeqCst),
+        }
+    }
+
+    /// Returns whether `SIGINT` was received since the token was created.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        INTERRUPTS.load(Ordering::Relaxed) != self.start
+    }
+}
+
+/// Counts the signal and forwards it to the previous handler, usually Python's.
+#[cfg(unix)]
+extern "C" fn on_interrupt(signum: c_int, info: *mut libc::siginfo_t, context: *mut c_void) {
+    INTERRUPTS.fetch_add(1, Ordering::SeqCst);
+    let previous = PREVIOUS_HANDLER.load(Ordering::SeqCst);
+    if !is_function(previous) {
+        return;
+    }
+    unsafe {
+        if PREVIOUS_SIGINFO.load(Ordering::SeqCst) {
+            let previous: extern "C" fn(c_int, *mut libc::siginfo_t, *mut c_void) =
+                std::mem::transmute(previous);
+            previous(signum, info, context);
+        } else {
+            let previous: extern "C" fn(c_int) = std::mem::transmute(previous);
+            previous(signum);
+        }
+    }
+}
+
+/// Counts the signal and forwards it to the previous handler, usually Python's.
+#[cfg(not(unix))]
+extern "C" fn on_interrupt(signum: c_int) {
+    INTERRUPTS.fetch_add(1, Ordering::SeqCst);
+    let previous = PREVIOUS_HANDLER.load(Ordering::SeqCst);
+    if is_function(previous) {
+        let previous: extern "C" fn(c_int) = unsafe { std::mem::transmute(previous) };
+        previous(signum);
+    }
+}
+
+/// Returns whether a handler is a function, rather than `SIG_DFL`, `SIG_IGN` or `SIG_ERR`.
+fn is_function(handler: libc::sighandler_t) -> bool {
+    handler != libc::SIG_DFL && handler != libc::SIG_IGN && handler != libc::SIG_ERR
+}
+
+/// Installs [`on_interrupt`] in front of the current `SIGINT` handler.
+///
+/// Nothing is installed if the signal has no handler function, for example in embedded
+/// interpreters which were initialized without Python's signal handlers. The handler is kept
+/// installed; if Python replaces it, for example through `signal.signal`, it is installed again
+/// on the next call.
+#[cfg(unix)]
+fn install_handler() {
+    let _guard = INSTALL.lock();
+    let handler = on_interrupt as extern "C" fn(c_int, *mut libc::siginfo_t, *mut c_void);
+    unsafe {
+        let mut current: libc::sigaction = std::mem::zeroed();
+        if libc::sigaction(libc::SIGINT, std::ptr::null(), &mut current) != 0 {
+            return;
+        }
+        if is_function(current.sa_sigaction) && current.sa_sigaction != handler as usize {
+            PREVIOUS_HANDLER.store(current.sa_sigaction, Ordering::SeqCst);
+            PREVIOUS_SIGINFO.store(current.sa_flags & libc::SA_SIGINFO != 0, Ordering::SeqCst);
+            // Keep the mask and the other flags of the previous handler, e.g. `SA_ONSTACK`.
+            let mut action = current;
+            action.sa_sigaction = handler as usize;
+            action.sa_flags |= libc::SA_SIGINFO;
+            libc::sigaction(libc::SIGINT, &action, std::ptr::null_mut());
+        }
+    }
+}
+
+/// Installs [`on_interrupt`] in front of the current `SIGINT` handler.
+///
+/// See the Unix version above; `signal` can only read the handler by replacing it, so the
+/// previous handler is restored if it is not a function.
+#[cfg(not(unix))]
+fn install_handler() {
+    let _guard = INSTALL.lock();
+    let handler = on_interrupt as extern "C" fn(c_int) as libc::sighandler_t;
+    unsafe {
+        let current = libc::signal(libc::SIGINT, handler);
+        if is_function(current) && current != handler {
+            PREVIOUS_HANDLER.store(current, Ordering::SeqCst);
+        } else if current != handler {
+            libc::signal(libc::SIGINT, current);
+        }
+    }
+}
@@ -87,6 +87,10 @@
 //! - `multiple-pymethods`: Enables the use of multiple [`#[pymethods]`](macro@crate::pymethods)
 //! blocks per [`#[pyclass]`](macro@crate::pyclass). This adds a dependency on the [inventory]
 //! crate, which is not supported on all platforms.
+//! - `redirect-stdio`: Enables the [`stdio`] module, which redirects Python's `sys.stdout` and
+//! `sys.stderr` into Rust closures.
+//! - `reference-pool-debug`: Records where each reference count decrement deferred because the
+//! GIL was not held came from, see `pending_decref_origins`. Requires Rust 1.65 or greater.
 //!
 //! The following features enable interactions with other crates in the Rust ecosystem:
 //! - [`anyhow`]: Enables a conversion from [anyhow]’s [`Error`][anyhow_error] type to [`PyErr`].
@@ -95,6 +99,7 @@
 //! - [`hashbrown`]: Enables conversions between Python objects and [hashbrown]'s [`HashMap`] and
 //! [`HashSet`] types.
 //! - [`indexmap`][indexmap_feature]: Enables conversions between Python dictionary and [indexmap]'s [`IndexMap`].
+//! - [`log`]: Enables forwarding records between Python's `logging` module and the [log] crate.
 //! - [`num-bigint`]: Enables conversions between Python objects and [num-bigint]'s [`BigInt`] and
 //! [`BigUint`] types.
 //! - [`num-complex`]: Enables conversions between Python objects and [num-complex]'s [`Complex`]
@@ -274,6 +279,8 @@
 //! [`hashbrown`]: ./hashbrown/index.html "Documentation about the `hashbrown` feature."
 //! [indexmap_feature]: ./indexmap/index.html "Documentation about the `indexmap` feature."
 //! [`maturin`]: https://github.com/PyO3/maturin "Build and publish crates with pyo3, rust-cpython and cffi bindings as well as rust binaries as python packages"
+//! [`log`]: ./log/index.html "Documentation about the `log` feature."
+//! [log]: https://docs.rs/log "A lightweight logging facade for Rust."
 //! [`num-bigint`]: ./num_bigint/index.html "Documentation about the `num-bigint` feature."
 //! [`num-complex`]: ./num_complex/index.html "Documentation about the `num-complex` feature."
 //! [`pyo3-build-config`]: https://docs.rs/pyo3-build-config
@@ -305,10 +312,13 @@ pub use crate::conversion::{
     ToPyObject,
 };
 pub use crate::err::{PyDowncastError, PyErr, PyErrArguments, PyResult};
+#[cfg(feature = "reference-pool-debug")]
+pub use crate::gil::pending_decref_origins;
 pub use crate::gil::GILPool;
 #[cfg(not(PyPy))]
 pub use crate::gil::{prepare_freethreaded_python, with_embedded_python_interpreter};
-pub use crate::instance::{Py, PyNativeType, PyObject};
+pub use crate::gil::{reference_pool_stats, ReferencePoolStats};
+pub use crate::instance::{Bound, Py, PyNativeType, PyObject};
 pub use crate::marker::Python;
 pub use crate::pycell::{PyCell, PyRef, PyRefMut};
 pub use crate::pyclass::PyClass;
@@ -408,7 +418,10 @@ pub mod ffi;
 mod gil;
 #[doc(hidden)]
 pub mod impl_;
+pub mod import_hooks;
 mod instance;
+pub mod interpreter;
+mod interrupt;
 pub mod marker;
 pub mod marshal;
 #[macro_use]
@@ -418,6 +431,8 @@ pub mod prelude;
 pub mod pycell;
 pub mod pyclass;
 pub mod pyclass_init;
+pub mod sandbox;
+pub mod stdio;
 
 pub mod type_object;
 pub mod types;
@@ -435,7 +450,7 @@ pub mod once_cell {
 pub use crate::conversions::*;
 
 #[cfg(feature = "macros")]
-pub use pyo3_macros::{pyfunction, pymethods, pymodule, FromPyObject};
+pub use pyo3_macros::{pyfunction, pymethods, pymodule, FromPyObject, IntoPy};
 
 /// A proc macro used to expose Rust structs and fieldless enums as Python objects.
 ///
@@ -116,6 +116,33 @@ macro_rules! py_run_impl {
     }};
 }
 
+/// Creates a Python string from a format string and arguments, like [`format!`].
+///
+/// The text is written directly into the Python string, see
+/// [`PyString::from_fmt`](crate::types::PyString::from_fmt). Evaluates to a
+/// `PyResult<&PyString>`.
+///
+/// # Examples
+/// ```
+/// use pyo3::prelude::*;
+/// use pyo3::py_format;
+///
+/// # fn main() -> PyResult<()> {
+/// Python::with_gil(|py| {
+///     let (x, y) = (1.5, -2.0);
+///     let repr = py_format!(py, "Point(x={}, y={})", x, y)?;
+///     assert_eq!(repr.to_str()?, "Point(x=1.5, y=-2)");
+///     Ok(())
+/// })
+/// # }
+/// ```
+#[macro_export]
+macro_rules! py_format {
+    ($py:expr, $($arg:tt)*) => {
+        $crate::types::PyString::from_fmt($py, ::std::format_args!($($arg)*))
+    };
+}
+
 /// Wraps a Rust function annotated with [`#[pyfunction]`](macro@crate::pyfunction).
 ///
 /// This can be used with [`PyModule::add_function`](crate::types::PyModule::add_function) to add free
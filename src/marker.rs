@@ -120,8 +120,9 @@
 //! [`Rc`]: std::rc::Rc
 //! [`Py`]: crate::Py
 use crate::err::{self, PyDowncastError, PyErr, PyResult};
-use crate::gil::{GILGuard, GILPool, SuspendGIL};
+use crate::gil::{self, GILGuard, GILPool, SuspendGIL};
 use crate::impl_::not_send::NotSend;
+pub use crate::interrupt::CancellationToken;
 use crate::types::{PyAny, PyDict, PyModule, PyString, PyType};
 use crate::version::PythonVersionInfo;
 use crate::{
@@ -294,6 +295,90 @@ mod negative_impls {
     impl !Ungil for crate::ffi::PyArena {}
 }
 
+/// A shared borrow of data which stays valid while the GIL is released.
+///
+/// [`PyBuffer::as_slice`] returns [`ReadOnlyCell`]s, because Python code may modify the buffer
+/// contents whenever it runs, so these slices can't be passed into [`Python::allow_threads`].
+/// [`PyBuffer::detach_slice`] instead returns a `Detached` slice for read-only buffers, which can
+/// be used without the GIL for as long as the `PyBuffer` lives.
+///
+/// Like `&T`, a `Detached<'_, T>` can only cross into `allow_threads` if `T` is [`Sync`].
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::buffer::PyBuffer;
+/// use pyo3::prelude::*;
+///
+/// #[pyfunction]
+/// fn checksum(py: Python<'_>, data: PyBuffer<u8>) -> PyResult<u64> {
+///     let bytes = data
+///         .detach_slice(py)
+///         .ok_or_else(|| pyo3::exceptions::PyBufferError::new_err("expected read-only bytes"))?;
+///     Ok(py.allow_threads(move || bytes.iter().map(|&b| u64::from(b)).sum()))
+/// }
+/// #
+/// # Python::with_gil(|py| {
+/// #     let checksum = pyo3::wrap_pyfunction!(checksum, py).unwrap();
+/// #     let result: u64 = checksum.call1((&b"\x01\x02\x03"[..],)).unwrap().extract().unwrap();
+/// #     assert_eq!(result, 6);
+/// #     assert!(checksum.call1((pyo3::types::PyByteArray::new(py, b"\x01"),)).is_err());
+/// # });
+/// ```
+///
+/// The contents of a `#[pyclass]` don't need a `Detached` borrow: a reference obtained from a
+/// [`PyRef`], or from [`Py::get`] for frozen classes, can be used in `allow_threads` directly.
+///
+/// [`PyBuffer::as_slice`]: crate::buffer::PyBuffer::as_slice
+/// [`PyBuffer::detach_slice`]: crate::buffer::PyBuffer::detach_slice
+/// [`ReadOnlyCell`]: crate::buffer::ReadOnlyCell
+/// [`PyRef`]: crate::PyRef
+/// [`Py::get`]: crate::Py::get
+pub struct Detached<'a, T: ?Sized> {
+    value: &'a T,
+}
+
+impl<'a, T: ?Sized> Detached<'a, T> {
+    /// Creates a detached borrow.
+    ///
+    /// # Safety
+    ///
+    /// Neither Python code nor other Rust code may modify `value` while the borrow exists,
+    /// even while the GIL is released.
+    pub(crate) unsafe fn new(value: &'a T) -> Self {
+        Detached { value }
+    }
+
+    /// Returns the underlying reference.
+    #[inline]
+    pub fn get(self) -> &'a T {
+        self.value
+    }
+}
+
+impl<T: ?Sized> Clone for Detached<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Detached<'_, T> {}
+
+impl<T: ?Sized> std::ops::Deref for Detached<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T: ?Sized + std::fmt::Debug> std::fmt::Debug for Detached<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Detached").field(&self.value).finish()
+    }
+}
+
 /// A marker token that represents holding the GIL.
 ///
 /// It serves three main purposes:
@@ -526,6 +611,9 @@ impl<'py> Python<'py> {
     /// }
     /// ```
     ///
+    /// To borrow the contents of a read-only buffer without copying it, use a [`Detached`]
+    /// slice.
+    ///
     /// [`Py`]: crate::Py
     /// [`PyString`]: crate::types::PyString
     /// [auto-traits]: https://doc.rust-lang.org/nightly/unstable-book/language-features/auto-traits.html
@@ -543,6 +631,59 @@ impl<'py> Python<'py> {
         f()
     }
 
+    /// Like [`allow_threads`](Python::allow_threads), but lets `f` notice Ctrl-C.
+    ///
+    /// While the GIL is released, Python cannot run its `SIGINT` handler, so long-running Rust
+    /// code usually can't be interrupted. `f` receives a [`CancellationToken`] which is set when
+    /// the process receives `SIGINT`, and which it should poll to return early. Once `f` has
+    /// returned and the GIL is reacquired, Python's signal handlers are run, and the
+    /// `KeyboardInterrupt` they raise is returned instead of the result of `f`.
+    ///
+    /// The token is only set if Python's signal handlers are installed, as they are when running
+    /// in the `python` executable or in Jupyter. Embedded interpreters initialized with
+    /// [`prepare_freethreaded_python`](crate::prepare_freethreaded_python) don't install them.
+    /// Python only runs signal handlers on the main thread, so the `KeyboardInterrupt` is only
+    /// returned there.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::exceptions::PyKeyboardInterrupt;
+    /// use pyo3::prelude::*;
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     # // Install Python's handler, as `python` does.
+    ///     # py.run("import signal; signal.signal(signal.SIGINT, signal.default_int_handler)", None, None)?;
+    ///     let result = py.allow_threads_interruptible(|token| {
+    ///         let mut iterations = 0u64;
+    ///         while !token.is_cancelled() {
+    ///             iterations += 1;
+    ///             # if iterations == 1000 { unsafe { libc::raise(libc::SIGINT) }; }
+    ///         }
+    ///         iterations
+    ///     });
+    ///     assert!(result.unwrap_err().is_instance_of::<PyKeyboardInterrupt>(py));
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    pub fn allow_threads_interruptible<T, F>(self, f: F) -> PyResult<T>
+    where
+        F: Ungil + FnOnce(&CancellationToken) -> T,
+        T: Ungil,
+    {
+        let token = CancellationToken::new();
+        let result = {
+            let _guard = unsafe { SuspendGIL::new() };
+            f(&token)
+        };
+        if token.is_cancelled() {
+            self.check_signals()?;
+        }
+        Ok(result)
+    }
+
     /// Evaluates a Python expression in the given context and returns the result.
     ///
     /// If `globals` is `None`, it defaults to Python module `__main__`.
@@ -610,6 +751,72 @@ impl<'py> Python<'py> {
         })
     }
 
+    /// Compiles Python source code into a code object.
+    ///
+    /// `filename` is used in tracebacks and error messages, and `mode` selects the kind of code
+    /// expected in `source`. The returned [`PyCode`](crate::types::PyCode) can be run any number
+    /// of times with [`PyCode::eval`](crate::types::PyCode::eval).
+    ///
+    /// Syntax errors are raised as [`PySyntaxError`](crate::exceptions::PySyntaxError), which
+    /// gives access to the location of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyo3::exceptions::PySyntaxError;
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::{CompileFlags, CompileMode, IntoPyDict};
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     let flags = CompileFlags::new();
+    ///     let code = py.compile("x + 1", "<formula>", CompileMode::Eval, &flags)?;
+    ///     for x in 0..3 {
+    ///         let locals = [("x", x)].into_py_dict(py);
+    ///         let result: i32 = code.eval(None, Some(locals))?.extract()?;
+    ///         assert_eq!(result, x + 1);
+    ///     }
+    ///
+    ///     let err = py.compile("x +", "<formula>", CompileMode::Eval, &flags).unwrap_err();
+    ///     let err: &PySyntaxError = err.value(py).downcast()?;
+    ///     assert_eq!(err.lineno(), Some(1));
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    #[cfg(not(Py_LIMITED_API))]
+    pub fn compile(
+        self,
+        source: &str,
+        filename: &str,
+        mode: crate::types::CompileMode,
+        flags: &crate::types::CompileFlags,
+    ) -> PyResult<&'py crate::types::PyCode> {
+        let source = CString::new(source)?;
+        let filename = CString::new(filename)?;
+        let mut cf = flags.compiler_flags(self)?;
+        unsafe {
+            #[cfg(not(PyPy))]
+            let code_obj = ffi::Py_CompileStringExFlags(
+                source.as_ptr(),
+                filename.as_ptr(),
+                mode.start(),
+                &mut cf,
+                flags.optimize_level(),
+            );
+            #[cfg(PyPy)]
+            let code_obj = ffi::Py_CompileStringFlags(
+                source.as_ptr(),
+                filename.as_ptr(),
+                mode.start(),
+                &mut cf,
+            );
+            self.from_owned_ptr_or_err::<PyAny>(code_obj)?
+                .downcast()
+                .map_err(Into::into)
+        }
+    }
+
     /// Runs code in the given context.
     ///
     /// `start` indicates the type of input expected: one of `Py_single_input`,
@@ -665,6 +872,8 @@ impl<'py> Python<'py> {
     }
 
     /// Gets the Python builtin value `None`.
+    ///
+    /// Use [`PyNone::get`](crate::types::PyNone::get) for a typed reference instead.
     #[allow(non_snake_case)] // the Python keyword starts with uppercase
     #[inline]
     pub fn None(self) -> PyObject {
@@ -672,6 +881,8 @@ impl<'py> Python<'py> {
     }
 
     /// Gets the Python builtin value `Ellipsis`, or `...`.
+    ///
+    /// Use [`PyEllipsis::get`](crate::types::PyEllipsis::get) for a typed reference instead.
     #[allow(non_snake_case)] // the Python keyword starts with uppercase
     #[inline]
     pub fn Ellipsis(self) -> PyObject {
@@ -679,6 +890,8 @@ impl<'py> Python<'py> {
     }
 
     /// Gets the Python builtin value `NotImplemented`.
+    ///
+    /// Use [`PyNotImplemented::get`](crate::types::PyNotImplemented::get) for a typed reference instead.
     #[allow(non_snake_case)] // the Python keyword starts with uppercase
     #[inline]
     pub fn NotImplemented(self) -> PyObject {
@@ -884,6 +1097,36 @@ impl<'py> Python<'py> {
         err::error_on_minusone(self, v)
     }
 
+    /// Applies the reference count updates which were queued while the GIL was not held.
+    ///
+    /// Cloning or dropping a [`Py<T>`](crate::Py) on a thread which does not hold the GIL queues
+    /// the reference count update, which PyO3 normally applies the next time a thread acquires
+    /// the GIL. Long-running code which holds the GIL while other threads drop `Py<T>` values can
+    /// call this to release those objects without waiting for the GIL to be reacquired.
+    ///
+    /// See [`reference_pool_stats`](crate::reference_pool_stats) to observe the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    ///
+    /// Python::with_gil(|py| {
+    ///     let list = pyo3::types::PyList::empty_bound(py).unbind();
+    ///     let other = list.clone_ref(py);
+    ///
+    ///     // Drop a reference on a thread which doesn't hold the GIL.
+    ///     std::thread::spawn(move || drop(other)).join().unwrap();
+    ///     assert_eq!(list.get_refcnt(py), 2);
+    ///
+    ///     py.flush_pending_refs();
+    ///     assert_eq!(list.get_refcnt(py), 1);
+    /// });
+    /// ```
+    pub fn flush_pending_refs(self) {
+        gil::flush_pending_refs(self);
+    }
+
     /// Create a new pool for managing PyO3's owned references.
     ///
     /// When this `GILPool` is dropped, all PyO3 owned references created after this `GILPool` will
@@ -202,6 +202,8 @@ use crate::pyclass::{
 use crate::pyclass_init::PyClassInitializer;
 use crate::type_object::{PyLayout, PySizedLayout};
 use crate::types::PyAny;
+#[cfg(not(any(PyPy, all(Py_LIMITED_API, not(Py_3_10)))))]
+use crate::types::PyDict;
 use crate::{
     conversion::{AsPyPointer, FromPyPointer, ToPyObject},
     ffi::PyBaseObject_Type,
@@ -209,10 +211,14 @@ use crate::{
     PyTypeInfo,
 };
 use crate::{ffi, IntoPy, PyErr, PyNativeType, PyObject, PyResult, Python};
+#[cfg(not(any(PyPy, Py_LIMITED_API)))]
+use crate::{types::PyString, Py};
 use std::cell::UnsafeCell;
 use std::fmt;
 use std::mem::ManuallyDrop;
 use std::ops::{Deref, DerefMut};
+#[cfg(not(any(PyPy, Py_LIMITED_API)))]
+use std::os::raw::c_char;
 
 pub(crate) mod impl_;
 use impl_::{GetBorrowChecker, PyClassBorrowChecker, PyClassMutability};
@@ -492,6 +498,170 @@ impl<T: PyClass> PyCell<T> {
         std::mem::swap(&mut *self.borrow_mut(), &mut *other.borrow_mut())
     }
 
+    /// Returns the instance `__dict__`, creating it if it does not exist yet.
+    ///
+    /// Instances of `#[pyclass(dict)]` types (and of their subclasses) have a `__dict__` slot, as
+    /// do instances of Python subclasses which don't declare `__slots__`. The dictionary itself is
+    /// only allocated when it is first needed, so instances which never store dynamic attributes
+    /// only pay for a null pointer. Reading and writing the dictionary directly skips the attribute
+    /// lookup on the type which `getattr` and `setattr` have to do.
+    ///
+    /// Returns an `AttributeError` if the instance has no `__dict__`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    ///
+    /// #[pyclass(dict)]
+    /// struct Node {}
+    ///
+    /// Python::with_gil(|py| -> PyResult<()> {
+    ///     let node = PyCell::new(py, Node {})?;
+    ///     node.dict()?.set_item("label", "root")?;
+    ///     assert_eq!(node.getattr("label")?.extract::<&str>()?, "root");
+    ///     Ok(())
+    /// })
+    /// # .unwrap();
+    /// ```
+    #[cfg(not(any(PyPy, all(Py_LIMITED_API, not(Py_3_10)))))]
+    pub fn dict(&self) -> PyResult<&PyDict> {
+        unsafe {
+            let dict = ffi::PyObject_GenericGetDict(self.as_ptr(), std::ptr::null_mut());
+            self.py()
+                .from_owned_ptr_or_err::<PyAny>(dict)
+                .map(|dict| dict.downcast_unchecked())
+        }
+    }
+
+    /// Returns the value stored in the `__slots__` attribute `name`, or `None` if it hasn't been
+    /// assigned.
+    ///
+    /// Python subclasses of a `#[pyclass(subclass)]` can declare `__slots__`, which stores the
+    /// declared attributes at fixed offsets in each instance instead of in a `__dict__`. This reads
+    /// the slot directly instead of going through the full attribute lookup that `getattr` does.
+    ///
+    /// Returns an `AttributeError` if the type of the instance has no slot called `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::IntoPyDict;
+    ///
+    /// #[pyclass(subclass)]
+    /// struct Base {}
+    ///
+    /// #[pymethods]
+    /// impl Base {
+    ///     #[new]
+    ///     fn new() -> Self {
+    ///         Base {}
+    ///     }
+    /// }
+    ///
+    /// Python::with_gil(|py| -> PyResult<()> {
+    ///     let globals = [("Base", py.get_type::<Base>())].into_py_dict(py);
+    ///     py.run("class Sub(Base): __slots__ = ('tag',)", Some(globals), None)?;
+    ///     let obj = py.eval("Sub()", Some(globals), None)?;
+    ///     let cell: &PyCell<Base> = obj.downcast()?;
+    ///
+    ///     assert!(cell.get_slot("tag")?.is_none());
+    ///     cell.set_slot("tag", "leaf")?;
+    ///     assert_eq!(obj.getattr("tag")?.extract::<&str>()?, "leaf");
+    ///     Ok(())
+    /// })
+    /// # .unwrap();
+    /// ```
+    #[cfg(not(any(PyPy, Py_LIMITED_API)))]
+    pub fn get_slot<N>(&self, name: N) -> PyResult<Option<&PyAny>>
+    where
+        N: IntoPy<Py<PyString>>,
+    {
+        let (_descr, member) = self.slot_member(name.into_py(self.py()))?;
+        unsafe {
+            let slot =
+                (self.as_ptr() as *mut u8).offset((*member).offset) as *mut *mut ffi::PyObject;
+            Ok(self.py().from_borrowed_ptr_or_opt(*slot))
+        }
+    }
+
+    /// Stores `value` in the `__slots__` attribute `name`.
+    ///
+    /// See [`PyCell::get_slot`].
+    #[cfg(not(any(PyPy, Py_LIMITED_API)))]
+    pub fn set_slot<N, V>(&self, name: N, value: V) -> PyResult<()>
+    where
+        N: IntoPy<Py<PyString>>,
+        V: ToPyObject,
+    {
+        let py = self.py();
+        let (_descr, member) = self.slot_member(name.into_py(py))?;
+        let value = value.to_object(py);
+        unsafe {
+            crate::err::error_on_minusone(
+                py,
+                ffi::structmember::PyMember_SetOne(
+                    self.as_ptr() as *mut c_char,
+                    member,
+                    value.as_ptr(),
+                ),
+            )
+        }
+    }
+
+    /// Clears the `__slots__` attribute `name`, like `del obj.name`.
+    ///
+    /// Returns an `AttributeError` if the slot has not been assigned. See [`PyCell::get_slot`].
+    #[cfg(not(any(PyPy, Py_LIMITED_API)))]
+    pub fn del_slot<N>(&self, name: N) -> PyResult<()>
+    where
+        N: IntoPy<Py<PyString>>,
+    {
+        let py = self.py();
+        let (_descr, member) = self.slot_member(name.into_py(py))?;
+        unsafe {
+            crate::err::error_on_minusone(
+                py,
+                ffi::structmember::PyMember_SetOne(
+                    self.as_ptr() as *mut c_char,
+                    member,
+                    std::ptr::null_mut(),
+                ),
+            )
+        }
+    }
+
+    /// Finds the member descriptor which `__slots__` created for `name` on the type of `self`.
+    ///
+    /// The descriptor is returned along with its member definition to keep it alive.
+    #[cfg(not(any(PyPy, Py_LIMITED_API)))]
+    fn slot_member(
+        &self,
+        name: Py<PyString>,
+    ) -> PyResult<(PyObject, *mut ffi::structmember::PyMemberDef)> {
+        let py = self.py();
+        unsafe {
+            let descr = ffi::_PyType_Lookup(ffi::Py_TYPE(self.as_ptr()), name.as_ptr());
+            #[cfg(addr_of)]
+            let member_descr_type = std::ptr::addr_of_mut!(ffi::PyMemberDescr_Type);
+            #[cfg(not(addr_of))]
+            let member_descr_type = &mut ffi::PyMemberDescr_Type as *mut ffi::PyTypeObject;
+            if !descr.is_null() && ffi::Py_TYPE(descr) == member_descr_type {
+                let member = (*(descr as *mut ffi::PyMemberDescrObject)).d_member
+                    as *mut ffi::structmember::PyMemberDef;
+                if (*member).type_code == ffi::structmember::T_OBJECT_EX {
+                    return Ok((PyObject::from_borrowed_ptr(py, descr), member));
+                }
+            }
+            Err(crate::exceptions::PyAttributeError::new_err(format!(
+                "'{}' object has no slot '{}'",
+                self.get_type().name()?,
+                name.as_ref(py).to_str()?
+            )))
+        }
+    }
+
     pub(crate) fn get_ptr(&self) -> *mut T {
         self.contents.value.get()
     }
@@ -1,5 +1,5 @@
 use crate::{
-    exceptions::PyTypeError,
+    exceptions::{PyTypeError, PyValueError},
     ffi,
     impl_::pyclass::{
         assign_sequence_item_from_mapping, get_sequence_item_from_mapping, tp_dealloc,
@@ -9,7 +9,7 @@ use crate::{
         pymethods::{get_doc, get_name, Getter, Setter},
         trampoline::trampoline_inner,
     },
-    types::PyType,
+    types::{PyFrozenSet, PyString, PyType},
     Py, PyClass, PyGetterDef, PyMethodDefType, PyResult, PySetterDef, PyTypeInfo, Python,
 };
 use std::{
@@ -31,18 +31,145 @@ pub(crate) fn create_type_object<T>(py: Python<'_>) -> PyResult<PyClassTypeObjec
 where
     T: PyClass,
 {
-    unsafe {
+    let python_base = match T::PYTHON_BASE_TYPE {
+        Some(path) => Some(python_base_type::<T>(py, path)?),
+        None => None,
+    };
+    let base_type = match python_base {
+        Some(base) => base.as_type_ptr(),
+        None => T::BaseType::type_object_raw(py),
+    };
+
+    let type_object = unsafe {
         PyTypeBuilder::default()
             .type_doc(T::doc(py)?)
             .offsets(T::dict_offset(), T::weaklist_offset())
-            .slot(ffi::Py_tp_base, T::BaseType::type_object_raw(py))
+            .slot(ffi::Py_tp_base, base_type)
             .slot(ffi::Py_tp_dealloc, tp_dealloc::<T> as *mut c_void)
             .set_is_basetype(T::IS_BASETYPE)
             .set_is_mapping(T::IS_MAPPING)
             .set_is_sequence(T::IS_SEQUENCE)
             .class_items(T::items_iter())
-            .build(py, T::NAME, T::MODULE, std::mem::size_of::<T::Layout>())
+            .build(py, T::NAME, T::MODULE, std::mem::size_of::<T::Layout>())?
+    };
+
+    if let Some(base) = python_base {
+        inherit_abstract_methods(type_object.type_object.as_ref(py), base)?;
+    }
+
+    Ok(type_object)
+}
+
+/// Imports the base class named by `#[pyclass(extends_py = "module.Class")]` and checks that
+/// its instance layout is the same as the layout PyO3 assumes for `T::BaseType`.
+///
+/// Before Python 3.12 the new type is always created with `type` as its metaclass, so bases with
+/// any metaclass other than `type` or `abc.ABCMeta` are rejected. Since 3.12 `PyType_FromSpec`
+/// derives the metaclass from the bases instead, which emits a `DeprecationWarning` for
+/// `abc.ABCMeta` as it overrides `__new__`, and 3.14 makes that an error, so only `type` is
+/// accepted there.
+fn python_base_type<'py, T: PyClass>(py: Python<'py>, path: &str) -> PyResult<&'py PyType> {
+    let split = path.rfind('.').ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "expected a Python class path of the form \"module.Class\", got \"{}\"",
+            path
+        ))
+    })?;
+    let base: &PyType = py
+        .import(&path[..split])?
+        .getattr(&path[split + 1..])?
+        .downcast()?;
+    let layout_base = T::BaseType::type_object(py);
+
+    if !base.is_subclass(layout_base)? {
+        return Err(PyTypeError::new_err(format!(
+            "`{}` cannot be used as the base of `{}`: it is not a subclass of `{}`",
+            path,
+            T::NAME,
+            layout_base.name()?
+        )));
+    }
+
+    // The Rust struct is stored directly after the layout of `T::BaseType`, so the Python class
+    // must not add anything (such as `__dict__` or `__weakref__` slots) to that layout.
+    for attr in [
+        "__basicsize__",
+        "__itemsize__",
+        "__dictoffset__",
+        "__weakrefoffset__",
+    ]
+    .iter()
+    {
+        let expected: ffi::Py_ssize_t = layout_base.getattr(*attr)?.extract()?;
+        let actual: ffi::Py_ssize_t = base.getattr(*attr)?.extract()?;
+        if actual != expected {
+            return Err(PyTypeError::new_err(format!(
+                "`{}` cannot be used as the base of `{}`: its instance layout is incompatible \
+                 with `{}` ({} is {}, expected {}); \
+                 the base class should declare `__slots__ = ()`",
+                path,
+                T::NAME,
+                layout_base.name()?,
+                attr,
+                actual,
+                expected
+            )));
+        }
+    }
+
+    let metaclass = base.get_type();
+    #[cfg(not(Py_3_14))]
+    let supported = metaclass.is(PyType::type_object(py))
+        || metaclass.is(py
+            .import(intern!(py, "abc"))?
+            .getattr(intern!(py, "ABCMeta"))?);
+    #[cfg(not(Py_3_14))]
+    let expected = "`type` and `abc.ABCMeta` are";
+    #[cfg(Py_3_14)]
+    let supported = metaclass.is(PyType::type_object(py));
+    #[cfg(Py_3_14)]
+    let expected = "`type` is";
+    if !supported {
+        return Err(PyTypeError::new_err(format!(
+            "`{}` cannot be used as the base of `{}`: its metaclass `{}` is not supported, \
+             only {}",
+            path,
+            T::NAME,
+            metaclass.name()?,
+            expected
+        )));
+    }
+
+    Ok(base)
+}
+
+/// Marks the abstract methods of `base` which `ty` does not implement as abstract in `ty`, as
+/// `abc.ABCMeta` would, so that instantiating `ty` fails while any of them remain.
+fn inherit_abstract_methods(ty: &PyType, base: &PyType) -> PyResult<()> {
+    let py = ty.py();
+    let abstract_methods = match base.getattr(intern!(py, "__abstractmethods__")) {
+        Ok(abstract_methods) => abstract_methods,
+        Err(_) => return Ok(()),
+    };
+    let mut remaining = Vec::new();
+    for name in abstract_methods.iter()? {
+        let name = name?;
+        let is_abstract = match ty.getattr(name.downcast::<PyString>()?) {
+            Ok(value) => match value.getattr(intern!(py, "__isabstractmethod__")) {
+                Ok(flag) => flag.is_true()?,
+                Err(_) => false,
+            },
+            Err(_) => false,
+        };
+        if is_abstract {
+            remaining.push(name);
+        }
     }
+    // Setting `__abstractmethods__` is what sets `Py_TPFLAGS_IS_ABSTRACT` on the type.
+    ty.setattr(
+        intern!(py, "__abstractmethods__"),
+        PyFrozenSet::new(py, &remaining)?,
+    )
 }
 
 type PyTypeBuilderCleanup = Box<dyn Fn(&PyTypeBuilder, *mut ffi::PyTypeObject)>;
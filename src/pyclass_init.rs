@@ -1,6 +1,8 @@
 //! Contains initialization utilities for `#[pyclass]`.
 use crate::callback::IntoPyCallbackOutput;
+use crate::exceptions::PyTypeError;
 use crate::impl_::pyclass::{PyClassBaseType, PyClassDict, PyClassThreadChecker, PyClassWeakRef};
+use crate::types::PyType;
 use crate::{ffi, PyCell, PyClass, PyErr, PyResult, Python};
 use crate::{
     ffi::PyTypeObject,
@@ -45,6 +47,11 @@ impl<T: PyTypeInfo> PyObjectInit<T> for PyNativeTypeInitializer<T> {
             type_object: *mut PyTypeObject,
             subtype: *mut PyTypeObject,
         ) -> PyResult<*mut ffi::PyObject> {
+            // Checked by `object.__new__`, which is bypassed below
+            if ffi::PyType_HasFeature(subtype, ffi::Py_TPFLAGS_IS_ABSTRACT) != 0 {
+                return Err(abstract_class_error(py, subtype));
+            }
+
             // HACK (due to FIXME below): PyBaseObject_Type's tp_new isn't happy with NULL arguments
             #[cfg(addr_of)]
             let is_base_object = type_object == std::ptr::addr_of_mut!(ffi::PyBaseObject_Type);
@@ -88,6 +95,29 @@ impl<T: PyTypeInfo> PyObjectInit<T> for PyNativeTypeInitializer<T> {
     private_impl! {}
 }
 
+#[cold]
+unsafe fn abstract_class_error(py: Python<'_>, subtype: *mut PyTypeObject) -> PyErr {
+    let ty: &PyType = py.from_borrowed_ptr(subtype as *mut ffi::PyObject);
+    let message = (|| -> PyResult<String> {
+        let mut methods = ty
+            .getattr(intern!(py, "__abstractmethods__"))?
+            .iter()?
+            .map(|method| method?.extract())
+            .collect::<PyResult<Vec<String>>>()?;
+        methods.sort();
+        Ok(format!(
+            "Can't instantiate abstract class {} with abstract method{} {}",
+            ty.name()?,
+            if methods.len() == 1 { "" } else { "s" },
+            methods.join(", ")
+        ))
+    })();
+    match message {
+        Ok(message) => PyTypeError::new_err(message),
+        Err(err) => err,
+    }
+}
+
 /// Initializer for our `#[pyclass]` system.
 ///
 /// You can use this type to initialize complicatedly nested `#[pyclass]`.
@@ -0,0 +1,194 @@
+//! Helpers for running user-provided Python code under the control of the host application.
+//!
+//! [PEP 578] audit hooks are notified of security-relevant operations such as `open`, `import`
+//! and `subprocess.Popen`, and can log or deny them. [`add_audit_hook`] registers a Rust closure
+//! as an audit hook and [`audit`] raises custom events.
+//!
+//! [`restricted_globals`] creates a namespace for [`Python::run`] and [`Python::eval`] in which
+//! only a selection of the builtins is available.
+//!
+//! Neither of these is a security boundary: Python code has many ways to escape a restricted
+//! namespace, and audit hooks only see the operations which raise events. Untrusted code
+//! should be isolated by the operating system instead.
+//!
+//! [PEP 578]: https://peps.python.org/pep-0578/
+
+#[cfg(all(Py_3_8, not(any(PyPy, Py_LIMITED_API))))]
+use crate::err::{self, PyErr};
+#[cfg(all(Py_3_8, not(any(PyPy, Py_LIMITED_API))))]
+use crate::impl_::trampoline::trampoline_inner;
+use crate::types::PyDict;
+#[cfg(all(Py_3_8, not(any(PyPy, Py_LIMITED_API))))]
+use crate::types::PyTuple;
+#[cfg(all(Py_3_8, not(any(PyPy, Py_LIMITED_API))))]
+use crate::{ffi, AsPyPointer, IntoPy, Py};
+use crate::{PyResult, Python};
+#[cfg(all(Py_3_8, not(any(PyPy, Py_LIMITED_API))))]
+use std::{
+    ffi::{CStr, CString},
+    os::raw::{c_char, c_int, c_void},
+    panic::AssertUnwindSafe,
+};
+
+/// Registers `hook` to be called for every audit event raised in the process.
+///
+/// The hook receives the name of the event and its arguments. Returning an error aborts the
+/// operation which raised the event, and the error is raised in its place. Panics are
+/// converted to [`PanicException`](crate::panic::PanicException)s.
+///
+/// Audit hooks cannot be removed, so `hook` is leaked: it is never dropped, even after the
+/// interpreter is finalized.
+/// Registering a hook raises the `sys.addaudithook` event, which existing hooks may deny.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::exceptions::PyPermissionError;
+/// use pyo3::prelude::*;
+/// use pyo3::sandbox;
+///
+/// # fn main() -> PyResult<()> {
+/// Python::with_gil(|py| {
+///     sandbox::add_audit_hook(py, |event, _args| {
+///         if event == "my_app.forbidden" {
+///             return Err(PyPermissionError::new_err("not allowed"));
+///         }
+///         Ok(())
+///     })?;
+///
+///     let err = py.run("import sys; sys.audit('my_app.forbidden')", None, None).unwrap_err();
+///     assert!(err.is_instance_of::<PyPermissionError>(py));
+///     Ok(())
+/// })
+/// # }
+/// ```
+#[cfg(all(Py_3_8, not(any(PyPy, Py_LIMITED_API))))]
+pub fn add_audit_hook<F>(py: Python<'_>, hook: F) -> PyResult<()>
+where
+    F: Fn(&str, &PyTuple) -> PyResult<()> + Send + Sync + 'static,
+{
+    unsafe extern "C" fn call_hook<F>(
+        event: *const c_char,
+        args: *mut ffi::PyObject,
+        data: *mut c_void,
+    ) -> c_int
+    where
+        F: Fn(&str, &PyTuple) -> PyResult<()> + Send + Sync + 'static,
+    {
+        let hook = AssertUnwindSafe(&*(data as *const F));
+        trampoline_inner(move |py| {
+            let event = CStr::from_ptr(event).to_str()?;
+            let args: &PyTuple = py.from_borrowed_ptr(args);
+            (hook.0)(event, args)?;
+            Ok(0)
+        })
+    }
+
+    let data = Box::into_raw(Box::new(hook));
+    let result = unsafe { ffi::PySys_AddAuditHook(call_hook::<F>, data as *mut c_void) };
+    if result == -1 {
+        // The hook was not added, so it can be released again.
+        drop(unsafe { Box::from_raw(data) });
+        return Err(PyErr::fetch(py));
+    }
+    Ok(())
+}
+
+/// Raises the audit event `event` with the given arguments.
+///
+/// This calls all audit hooks, including those added with Python's `sys.addaudithook`, and
+/// returns the error of the first hook which denies the event.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::sandbox;
+///
+/// # fn main() -> PyResult<()> {
+/// Python::with_gil(|py| {
+///     sandbox::audit(py, "my_app.rule_loaded", ("discount", 42))
+/// })
+/// # }
+/// ```
+#[cfg(all(Py_3_8, not(any(PyPy, Py_LIMITED_API))))]
+pub fn audit(py: Python<'_>, event: &str, args: impl IntoPy<Py<PyTuple>>) -> PyResult<()> {
+    let event = CString::new(event)?;
+    let args = args.into_py(py);
+    // The "O" format passes the tuple itself as the event arguments.
+    let result = unsafe {
+        ffi::PySys_Audit(
+            event.as_ptr(),
+            "O\0".as_ptr() as *const c_char,
+            args.as_ptr(),
+        )
+    };
+    err::error_on_minusone(py, result)
+}
+
+/// Creates a globals dictionary in which only the builtins listed in `allowed` are available.
+///
+/// Names which are not builtins of the running Python version are ignored. Leaving out
+/// `__import__` disables the `import` statement.
+///
+/// This does not prevent code from reaching the full builtins through other objects, see the
+/// [module documentation](self).
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::sandbox;
+///
+/// # fn main() -> PyResult<()> {
+/// Python::with_gil(|py| {
+///     let globals = sandbox::restricted_globals(py, &["len", "min", "max"])?;
+///     let result: usize = py.eval("max(len('rule'), 2)", Some(globals), None)?.extract()?;
+///     assert_eq!(result, 4);
+///
+///     assert!(py.eval("open('/etc/passwd')", Some(globals), None).is_err());
+///     assert!(py.run("import os", Some(globals), None).is_err());
+///     Ok(())
+/// })
+/// # }
+/// ```
+pub fn restricted_globals<'py>(py: Python<'py>, allowed: &[&str]) -> PyResult<&'py PyDict> {
+    let all_builtins = py.import("builtins")?.dict();
+    let builtins = PyDict::new(py);
+    for &name in allowed {
+        if let Some(value) = all_builtins.get_item(name) {
+            builtins.set_item(name, value)?;
+        }
+    }
+    let globals = PyDict::new(py);
+    globals.set_item("__builtins__", builtins)?;
+    Ok(globals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::restricted_globals;
+    use crate::exceptions::{PyImportError, PyNameError};
+    use crate::Python;
+
+    #[test]
+    fn test_restricted_globals() {
+        Python::with_gil(|py| {
+            let globals = restricted_globals(py, &["len", "not_a_builtin"]).unwrap();
+            let builtins = globals.get_item("__builtins__").unwrap();
+            assert_eq!(builtins.len().unwrap(), 1);
+
+            let result: usize = py
+                .eval("len('abc')", Some(globals), None)
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(result, 3);
+
+            let err = py.eval("print", Some(globals), None).unwrap_err();
+            assert!(err.is_instance_of::<PyNameError>(py));
+            let err = py.run("import os", Some(globals), None).unwrap_err();
+            assert!(err.is_instance_of::<PyImportError>(py));
+        });
+    }
+}
@@ -0,0 +1,214 @@
+#![cfg(feature = "redirect-stdio")]
+
+//! Redirection of Python's `sys.stdout` and `sys.stderr` into Rust.
+//!
+//! [`redirect_stdout`] and [`redirect_stderr`] replace the Python streams with file-like objects
+//! which pass the written text to a Rust closure, one line at a time. This makes it possible to
+//! send the output of `print` into a Rust logging pipeline, for example.
+//!
+//! Only output written through the `sys` streams is redirected. Output written directly to the
+//! file descriptors, for example by C extensions or subprocesses, is not affected.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use pyo3::prelude::*;
+//! use pyo3::stdio;
+//!
+//! # fn main() -> PyResult<()> {
+//! Python::with_gil(|py| {
+//!     let previous = stdio::redirect_stdout(py, |line| eprintln!("[python] {}", line))?;
+//!     py.run("print('hello from Python')", None, None)?;
+//!
+//!     // Restore the original stream.
+//!     py.import("sys")?.setattr("stdout", previous)?;
+//!     Ok(())
+//! })
+//! # }
+//! ```
+
+use crate::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
+use crate::types::{PyIterator, PyString};
+use crate::{Py, PyAny, PyErr, PyObject, PyResult, Python};
+use parking_lot::Mutex;
+
+/// Replaces `sys.stdout` with a stream which passes each line written to it to `sink`.
+///
+/// Lines are passed without their trailing newline. Text which does not end with a newline is
+/// buffered until the next newline or until the stream is flushed, for example by
+/// `print(..., flush=True)` or at interpreter shutdown.
+///
+/// Returns the previous value of `sys.stdout`, so that it can be restored.
+pub fn redirect_stdout<F>(py: Python<'_>, sink: F) -> PyResult<PyObject>
+where
+    F: FnMut(&str) + Send + 'static,
+{
+    redirect(py, "stdout", sink)
+}
+
+/// Replaces `sys.stderr` with a stream which passes each line written to it to `sink`.
+///
+/// See [`redirect_stdout`] for details.
+pub fn redirect_stderr<F>(py: Python<'_>, sink: F) -> PyResult<PyObject>
+where
+    F: FnMut(&str) + Send + 'static,
+{
+    redirect(py, "stderr", sink)
+}
+
+struct LineBuffer<F> {
+    buffer: String,
+    sink: F,
+}
+
+impl<F: FnMut(&str)> LineBuffer<F> {
+    fn write(&mut self, text: &str) {
+        self.buffer.push_str(text);
+        while let Some(end) = self.buffer.find('\n') {
+            (self.sink)(&self.buffer[..end]);
+            self.buffer.drain(..=end);
+        }
+    }
+
+    fn flush(&mut self) {
+        if !self.buffer.is_empty() {
+            (self.sink)(&self.buffer);
+            self.buffer.clear();
+        }
+    }
+}
+
+fn redirect<F>(py: Python<'_>, name: &str, sink: F) -> PyResult<PyObject>
+where
+    F: FnMut(&str) + Send + 'static,
+{
+    let writer = RustWriter {
+        name: format!("<{}>", name),
+        buffer: Mutex::new(LineBuffer {
+            buffer: String::new(),
+            sink: Box::new(sink),
+        }),
+        closed: false,
+    };
+    let sys = py.import("sys")?;
+    let previous = sys.getattr(name)?.into();
+    sys.setattr(name, Py::new(py, writer)?)?;
+    Ok(previous)
+}
+
+type Sink = Box<dyn FnMut(&str) + Send>;
+
+/// The text stream installed by [`redirect`], following `io.TextIOBase`.
+#[crate::pyclass(crate = "crate", module = "pyo3.stdio")]
+struct RustWriter {
+    #[pyo3(get)]
+    name: String,
+    buffer: Mutex<LineBuffer<Sink>>,
+    #[pyo3(get)]
+    closed: bool,
+}
+
+#[crate::pymethods(crate = "crate")]
+impl RustWriter {
+    #[getter]
+    fn encoding(&self) -> &'static str {
+        "utf-8"
+    }
+
+    #[getter]
+    fn errors(&self) -> &'static str {
+        "strict"
+    }
+
+    fn readable(&self) -> bool {
+        false
+    }
+
+    fn writable(&self) -> bool {
+        true
+    }
+
+    fn seekable(&self) -> bool {
+        false
+    }
+
+    fn isatty(&self) -> bool {
+        false
+    }
+
+    fn fileno(&self, py: Python<'_>) -> PyResult<()> {
+        let unsupported = py
+            .import(intern!(py, "io"))?
+            .getattr(intern!(py, "UnsupportedOperation"))?
+            .call1(("redirected stream has no file descriptor",))?;
+        Err(PyErr::from_value(unsupported))
+    }
+
+    fn write(&self, s: &PyAny) -> PyResult<usize> {
+        let text: &PyString = s.downcast().map_err(|_| {
+            let type_name = s.get_type().name().unwrap_or("<unknown>");
+            PyTypeError::new_err(format!("write() argument must be str, not {}", type_name))
+        })?;
+        self.check_open()?;
+        let text = text.to_str()?;
+        self.lock()?.write(text);
+        Ok(text.chars().count())
+    }
+
+    fn writelines(&self, lines: &PyAny) -> PyResult<()> {
+        for line in PyIterator::from_object(lines.py(), lines)? {
+            self.write(line?)?;
+        }
+        Ok(())
+    }
+
+    fn flush(&self) -> PyResult<()> {
+        if !self.closed {
+            self.lock()?.flush();
+        }
+        Ok(())
+    }
+
+    fn close(&mut self) -> PyResult<()> {
+        self.flush()?;
+        self.closed = true;
+        Ok(())
+    }
+}
+
+impl RustWriter {
+    fn check_open(&self) -> PyResult<()> {
+        if self.closed {
+            Err(PyValueError::new_err("I/O operation on closed file."))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Locks the buffer, failing instead of deadlocking if the sink writes to its own stream.
+    fn lock(&self) -> PyResult<parking_lot::MutexGuard<'_, LineBuffer<Sink>>> {
+        self.buffer
+            .try_lock()
+            .ok_or_else(|| PyRuntimeError::new_err("reentrant write to redirected stream"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LineBuffer;
+
+    #[test]
+    fn test_line_buffer() {
+        let mut lines = Vec::new();
+        let mut buffer = LineBuffer {
+            buffer: String::new(),
+            sink: |line: &str| lines.push(line.to_owned()),
+        };
+        buffer.write("first");
+        buffer.write(" line\nsecond line\n\nthird");
+        buffer.flush();
+        buffer.flush();
+        drop(buffer);
+        assert_eq!(lines, ["first line", "second line", "", "third"]);
+    }
+}
@@ -1,5 +1,5 @@
 //! Synchronization mechanisms based on the Python GIL.
-use crate::{types::PyString, Py, Python};
+use crate::{types::PyString, Py, PyResult, Python};
 use std::cell::UnsafeCell;
 
 /// Value with concurrent access protected by the GIL.
@@ -169,6 +169,56 @@ impl<T> GILOnceCell<T> {
     }
 }
 
+impl<T: Send + 'static> GILOnceCell<T> {
+    /// Get the cell to use in the current interpreter.
+    ///
+    /// In the main interpreter this is `self`, while each subinterpreter created by PyO3 gets its
+    /// own initially empty cell. This allows caching Python objects in a `static` without sharing
+    /// them between interpreters.
+    ///
+    /// Fails if the cell of the current subinterpreter cannot be created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pyo3::sync::GILOnceCell;
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyType;
+    ///
+    /// static ORDERED_DICT: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+    ///
+    /// pub fn ordered_dict(py: Python<'_>) -> PyResult<&PyType> {
+    ///     ORDERED_DICT
+    ///         .per_interpreter(py)?
+    ///         .get_or_try_init(py, || py.import("collections")?.getattr("OrderedDict")?.extract())
+    ///         .map(|ty| ty.as_ref(py))
+    /// }
+    /// # Python::with_gil(|py| assert_eq!(ordered_dict(py).unwrap().name().unwrap(), "OrderedDict"));
+    /// ```
+    #[inline]
+    pub fn per_interpreter<'py>(&'static self, py: Python<'py>) -> PyResult<&'py GILOnceCell<T>> {
+        interpreter_local(py, self, GILOnceCell::new)
+    }
+}
+
+/// Returns `main` in the main interpreter, or a separate value created by `init` for each
+/// subinterpreter.
+#[inline]
+pub(crate) fn interpreter_local<'py, T: Send + 'static>(
+    py: Python<'py>,
+    main: &'static T,
+    init: fn() -> T,
+) -> PyResult<&'py T> {
+    #[cfg(all(Py_3_9, not(any(PyPy, Py_LIMITED_API))))]
+    {
+        if let Some(local) = crate::interpreter::subinterpreter_local(py, main, init)? {
+            return Ok(local);
+        }
+    }
+    let _ = (py, init);
+    Ok(main)
+}
+
 /// Interns `text` as a Python string and stores a reference to it in static storage.
 ///
 /// A reference to the same Python string is returned on each invocation.
@@ -226,10 +276,14 @@ impl Interned {
 
     /// Gets or creates the interned `str` value.
     #[inline]
-    pub fn get<'py>(&'py self, py: Python<'py>) -> &'py PyString {
-        self.1
-            .get_or_init(py, || PyString::intern(py, self.0).into())
-            .as_ref(py)
+    pub fn get<'py>(&'static self, py: Python<'py>) -> &'py PyString {
+        match self.1.per_interpreter(py) {
+            Ok(cell) => cell
+                .get_or_init(py, || PyString::intern(py, self.0).into())
+                .as_ref(py),
+            // Without a cell for this subinterpreter, intern the string without caching it.
+            Err(_) => PyString::intern(py, self.0),
+        }
     }
 }
 
@@ -6,7 +6,7 @@ use crate::type_object::PyTypeInfo;
 #[cfg(not(PyPy))]
 use crate::types::PySuper;
 use crate::types::{PyDict, PyIterator, PyList, PyString, PyTuple, PyType};
-use crate::{err, ffi, Py, PyNativeType, PyObject, Python};
+use crate::{err, ffi, Bound, Py, PyNativeType, PyObject, Python};
 use std::cell::UnsafeCell;
 use std::cmp::Ordering;
 use std::os::raw::c_int;
@@ -971,6 +971,166 @@ impl PyAny {
     }
 }
 
+/// Methods of `PyAny` which create new Python objects, returning [`Bound`] values instead of
+/// GIL-bound references.
+///
+/// These shadow the methods of the same name that `Bound` dereferences to.
+impl<'py, T> Bound<'py, T> {
+    /// Retrieves an attribute value.
+    ///
+    /// This is equivalent to the Python expression `self.attr_name`.
+    ///
+    /// See [`PyAny::getattr`].
+    pub fn getattr<N>(&self, attr_name: N) -> PyResult<Bound<'py, PyAny>>
+    where
+        N: IntoPy<Py<PyString>>,
+    {
+        let py = self.py();
+        let attr_name = attr_name.into_py(py);
+        unsafe {
+            Bound::from_owned_ptr_or_err(
+                py,
+                ffi::PyObject_GetAttr(self.as_ptr(), attr_name.as_ptr()),
+            )
+        }
+    }
+
+    /// Calls the object.
+    ///
+    /// This is equivalent to the Python expression `self(*args, **kwargs)`.
+    ///
+    /// See [`PyAny::call`].
+    pub fn call(
+        &self,
+        args: impl IntoPy<Py<PyTuple>>,
+        kwargs: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = self.py();
+        let args = args.into_py(py);
+        let kwargs = kwargs.map_or(std::ptr::null_mut(), Bound::as_ptr);
+        unsafe {
+            Bound::from_owned_ptr_or_err(
+                py,
+                ffi::PyObject_Call(self.as_ptr(), args.as_ptr(), kwargs),
+            )
+        }
+    }
+
+    /// Calls the object without arguments.
+    ///
+    /// This is equivalent to the Python expression `self()`.
+    pub fn call0(&self) -> PyResult<Bound<'py, PyAny>> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                not(PyPy),
+                any(Py_3_10, all(not(Py_LIMITED_API), Py_3_9)) // PyObject_CallNoArgs was added to python in 3.9 but to limited API in 3.10
+            ))] {
+                // Optimized path on python 3.9+
+                unsafe {
+                    Bound::from_owned_ptr_or_err(self.py(), ffi::PyObject_CallNoArgs(self.as_ptr()))
+                }
+            } else {
+                self.call((), None)
+            }
+        }
+    }
+
+    /// Calls the object with only positional arguments.
+    ///
+    /// This is equivalent to the Python expression `self(*args)`.
+    pub fn call1(&self, args: impl IntoPy<Py<PyTuple>>) -> PyResult<Bound<'py, PyAny>> {
+        self.call(args, None)
+    }
+
+    /// Calls a method on the object.
+    ///
+    /// This is equivalent to the Python expression `self.name(*args, **kwargs)`.
+    pub fn call_method<N, A>(
+        &self,
+        name: N,
+        args: A,
+        kwargs: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>>
+    where
+        N: IntoPy<Py<PyString>>,
+        A: IntoPy<Py<PyTuple>>,
+    {
+        self.getattr(name)?.call(args, kwargs)
+    }
+
+    /// Calls a method on the object without arguments.
+    ///
+    /// This is equivalent to the Python expression `self.name()`.
+    pub fn call_method0<N>(&self, name: N) -> PyResult<Bound<'py, PyAny>>
+    where
+        N: IntoPy<Py<PyString>>,
+    {
+        cfg_if::cfg_if! {
+            if #[cfg(all(Py_3_9, not(any(Py_LIMITED_API, PyPy))))] {
+                let py = self.py();
+
+                // Optimized path on python 3.9+
+                unsafe {
+                    let name: Py<PyString> = name.into_py(py);
+                    let ptr = ffi::PyObject_CallMethodNoArgs(self.as_ptr(), name.as_ptr());
+                    Bound::from_owned_ptr_or_err(py, ptr)
+                }
+            } else {
+                self.call_method(name, (), None)
+            }
+        }
+    }
+
+    /// Calls a method on the object with only positional arguments.
+    ///
+    /// This is equivalent to the Python expression `self.name(*args)`.
+    pub fn call_method1<N, A>(&self, name: N, args: A) -> PyResult<Bound<'py, PyAny>>
+    where
+        N: IntoPy<Py<PyString>>,
+        A: IntoPy<Py<PyTuple>>,
+    {
+        self.call_method(name, args, None)
+    }
+
+    /// Computes the "repr" representation of self.
+    ///
+    /// This is equivalent to the Python expression `repr(self)`.
+    pub fn repr(&self) -> PyResult<Bound<'py, PyString>> {
+        unsafe { Bound::from_owned_ptr_or_err(self.py(), ffi::PyObject_Repr(self.as_ptr())) }
+    }
+
+    /// Computes the "str" representation of self.
+    ///
+    /// This is equivalent to the Python expression `str(self)`.
+    pub fn str(&self) -> PyResult<Bound<'py, PyString>> {
+        unsafe { Bound::from_owned_ptr_or_err(self.py(), ffi::PyObject_Str(self.as_ptr())) }
+    }
+}
+
+impl<'py> Bound<'py, PyAny> {
+    /// Gets an item from the collection.
+    ///
+    /// This is equivalent to the Python expression `self[key]`.
+    pub fn get_item<K>(&self, key: K) -> PyResult<Bound<'py, PyAny>>
+    where
+        K: ToPyObject,
+    {
+        let py = self.py();
+        let key = key.to_object(py);
+        unsafe {
+            Bound::from_owned_ptr_or_err(py, ffi::PyObject_GetItem(self.as_ptr(), key.as_ptr()))
+        }
+    }
+
+    /// Takes an object and returns an iterator for it.
+    ///
+    /// This is typically a new iterator but if the argument is an iterator,
+    /// this returns itself.
+    pub fn iter(&self) -> PyResult<Bound<'py, PyIterator>> {
+        unsafe { Bound::from_owned_ptr_or_err(self.py(), ffi::PyObject_GetIter(self.as_ptr())) }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -1,9 +1,13 @@
 // Copyright (c) 2022-present PyO3 Project and Contributors
 
-use crate::ffi;
-use crate::PyAny;
+use crate::exceptions::PyValueError;
+use crate::types::PyDict;
+use crate::{ffi, AsPyPointer, PyAny, PyErr, PyResult, Python};
+use std::os::raw::c_int;
 
 /// Represents a Python code object.
+///
+/// Code objects can be created from source code with [`Python::compile`].
 #[repr(transparent)]
 pub struct PyCode(PyAny);
 
@@ -12,3 +16,339 @@ pyobject_native_type_core!(
     ffi::PyCode_Type,
     #checkfunction=ffi::PyCode_Check
 );
+
+impl PyCode {
+    /// Runs the code object in the given context.
+    ///
+    /// If `globals` is `None`, it defaults to Python module `__main__`.
+    /// If `locals` is `None`, it defaults to the value of `globals`.
+    ///
+    /// Code compiled with [`CompileMode::Eval`] returns the value of the expression, other code
+    /// returns `None`.
+    pub fn eval(&self, globals: Option<&PyDict>, locals: Option<&PyDict>) -> PyResult<&PyAny> {
+        let py = self.py();
+        unsafe {
+            let globals = match globals {
+                Some(globals) => globals.as_ptr(),
+                None => {
+                    let mptr = ffi::PyImport_AddModule("__main__\0".as_ptr() as *const _);
+                    if mptr.is_null() {
+                        return Err(PyErr::fetch(py));
+                    }
+                    ffi::PyModule_GetDict(mptr)
+                }
+            };
+            let locals = locals.map(AsPyPointer::as_ptr).unwrap_or(globals);
+            py.from_owned_ptr_or_err(ffi::PyEval_EvalCode(self.as_ptr(), globals, locals))
+        }
+    }
+
+    /// Returns the name of the file the code was compiled from (`co_filename`).
+    pub fn filename(&self) -> PyResult<&str> {
+        let py = self.py();
+        self.getattr(intern!(py, "co_filename"))?.extract()
+    }
+
+    /// Returns the name of the function, class or module of the code (`co_name`).
+    pub fn name(&self) -> PyResult<&str> {
+        let py = self.py();
+        self.getattr(intern!(py, "co_name"))?.extract()
+    }
+
+    /// Returns the qualified name of the function, class or module of the code (`co_qualname`).
+    ///
+    /// Code objects only carry their qualified name since Python 3.11. On older versions, this
+    /// returns the same as [`name`](PyCode::name).
+    pub fn qualname(&self) -> PyResult<&str> {
+        let py = self.py();
+        #[cfg(Py_3_11)]
+        let attr = intern!(py, "co_qualname");
+        #[cfg(not(Py_3_11))]
+        let attr = intern!(py, "co_name");
+        self.getattr(attr)?.extract()
+    }
+
+    /// Returns the number of the first line of the code (`co_firstlineno`).
+    pub fn first_line(&self) -> PyResult<usize> {
+        let py = self.py();
+        self.getattr(intern!(py, "co_firstlineno"))?.extract()
+    }
+}
+
+/// The kind of code to compile with [`Python::compile`].
+///
+/// These correspond to the `mode` argument of Python's builtin `compile` function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CompileMode {
+    /// A module or a sequence of statements, as used by [`Python::run`].
+    Exec,
+    /// A single expression, as used by [`Python::eval`].
+    Eval,
+    /// A single interactive statement. The values of expression statements are printed.
+    Single,
+}
+
+impl CompileMode {
+    pub(crate) fn start(self) -> c_int {
+        match self {
+            CompileMode::Exec => ffi::Py_file_input,
+            CompileMode::Eval => ffi::Py_eval_input,
+            CompileMode::Single => ffi::Py_single_input,
+        }
+    }
+}
+
+/// Options for [`Python::compile`].
+///
+/// By default no `__future__` features are enabled and the interpreter's optimization level is
+/// used.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::types::{CompileFlags, CompileMode};
+///
+/// # fn main() -> PyResult<()> {
+/// Python::with_gil(|py| {
+///     let flags = CompileFlags::new().future_feature("annotations").optimize(1);
+///     let code = py.compile("def f(x: Undefined): assert False", "<script>", CompileMode::Exec, &flags)?;
+///     code.eval(None, None)?;
+///     // Annotations are not evaluated and asserts are removed.
+///     py.eval("f(1)", None, None)?;
+///     Ok(())
+/// })
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompileFlags {
+    flags: c_int,
+    future_features: Vec<String>,
+    optimize: c_int,
+}
+
+impl CompileFlags {
+    /// Creates the default compile options.
+    pub fn new() -> Self {
+        CompileFlags {
+            flags: 0,
+            future_features: Vec::new(),
+            optimize: -1,
+        }
+    }
+
+    /// Enables the `__future__` feature `name`, as if the code started with
+    /// `from __future__ import <name>`.
+    ///
+    /// Unknown features are reported by [`Python::compile`].
+    pub fn future_feature(mut self, name: &str) -> Self {
+        self.future_features.push(name.to_owned());
+        self
+    }
+
+    /// Sets the optimization level, as with Python's `-O` option.
+    ///
+    /// `0` keeps `assert` statements and `__debug__` is true, `1` removes asserts and `2` also
+    /// removes docstrings. `-1`, the default, uses the level of the interpreter.
+    pub fn optimize(mut self, level: i32) -> Self {
+        self.optimize = level;
+        self
+    }
+
+    /// Allows `await`, `async for` and `async with` at the top level of the code.
+    ///
+    /// Evaluating such code returns a coroutine.
+    #[cfg(Py_3_8)]
+    pub fn allow_top_level_await(mut self, allow: bool) -> Self {
+        self.set(ffi::PyCF_ALLOW_TOP_LEVEL_AWAIT, allow);
+        self
+    }
+
+    /// Adds raw `PyCF_*` or `CO_FUTURE_*` flags, as accepted by the `flags` argument of
+    /// Python's builtin `compile` function.
+    pub fn raw_flags(mut self, flags: c_int) -> Self {
+        self.flags |= flags;
+        self
+    }
+
+    #[cfg(Py_3_8)]
+    fn set(&mut self, flag: c_int, value: bool) {
+        if value {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+
+    /// Resolves the flags to pass to the compiler.
+    pub(crate) fn compiler_flags(&self, py: Python<'_>) -> PyResult<ffi::PyCompilerFlags> {
+        let mut cf_flags = self.flags | ffi::PyCF_SOURCE_IS_UTF8;
+        if !self.future_features.is_empty() {
+            let future = py.import("__future__")?;
+            for name in &self.future_features {
+                let flag: c_int = future
+                    .getattr(name.as_str())
+                    .and_then(|feature| feature.getattr("compiler_flag"))
+                    .and_then(PyAny::extract)
+                    .map_err(|_| {
+                        PyValueError::new_err(format!("future feature {} is not defined", name))
+                    })?;
+                cf_flags |= flag;
+            }
+        }
+        Ok(ffi::PyCompilerFlags {
+            cf_flags,
+            #[cfg(Py_3_8)]
+            cf_feature_version: py.version_info().minor as c_int,
+        })
+    }
+
+    pub(crate) fn optimize_level(&self) -> c_int {
+        self.optimize
+    }
+}
+
+impl Default for CompileFlags {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CompileFlags, CompileMode};
+    use crate::exceptions::{PySyntaxError, PyValueError};
+    use crate::types::{PyCode, PyDict};
+    use crate::Python;
+
+    #[test]
+    fn test_compile_and_eval() {
+        Python::with_gil(|py| {
+            let code = py
+                .compile("x * 2", "<test>", CompileMode::Eval, &CompileFlags::new())
+                .unwrap();
+            for x in 0..3 {
+                let locals = PyDict::new(py);
+                locals.set_item("x", x).unwrap();
+                let result: i32 = code.eval(None, Some(locals)).unwrap().extract().unwrap();
+                assert_eq!(result, x * 2);
+            }
+
+            let globals = PyDict::new(py);
+            let code = py
+                .compile("y = 1", "<test>", CompileMode::Exec, &CompileFlags::new())
+                .unwrap();
+            assert!(code.eval(Some(globals), None).unwrap().is_none());
+            assert_eq!(globals.get_item("y").unwrap().extract::<i32>().unwrap(), 1);
+            assert_eq!(code.filename().unwrap(), "<test>");
+        });
+    }
+
+    #[test]
+    fn test_code_attributes() {
+        Python::with_gil(|py| {
+            let code = py
+                .compile(
+                    "class A:\n    def method(self):\n        pass",
+                    "module.py",
+                    CompileMode::Exec,
+                    &CompileFlags::new(),
+                )
+                .unwrap();
+            assert_eq!(code.name().unwrap(), "<module>");
+            assert_eq!(code.first_line().unwrap(), 1);
+
+            let globals = PyDict::new(py);
+            code.eval(Some(globals), None).unwrap();
+            let method: &PyCode = py
+                .eval("A.method.__code__", Some(globals), None)
+                .unwrap()
+                .downcast()
+                .unwrap();
+            assert_eq!(method.filename().unwrap(), "module.py");
+            assert_eq!(method.name().unwrap(), "method");
+            #[cfg(Py_3_11)]
+            assert_eq!(method.qualname().unwrap(), "A.method");
+            #[cfg(not(Py_3_11))]
+            assert_eq!(method.qualname().unwrap(), "method");
+            assert_eq!(method.first_line().unwrap(), 2);
+        });
+    }
+
+    #[test]
+    fn test_compile_flags() {
+        Python::with_gil(|py| {
+            let source = "assert False";
+            let flags = CompileFlags::new().optimize(0);
+            let code = py
+                .compile(source, "<test>", CompileMode::Exec, &flags)
+                .unwrap();
+            assert!(code.eval(Some(PyDict::new(py)), None).is_err());
+
+            let flags = CompileFlags::new().optimize(1);
+            let code = py
+                .compile(source, "<test>", CompileMode::Exec, &flags)
+                .unwrap();
+            code.eval(Some(PyDict::new(py)), None).unwrap();
+
+            let source = "def f(x: Undefined): pass\nresult = f.__annotations__['x']";
+            let globals = PyDict::new(py);
+            let flags = CompileFlags::new().future_feature("annotations");
+            let code = py
+                .compile(source, "<test>", CompileMode::Exec, &flags)
+                .unwrap();
+            code.eval(Some(globals), None).unwrap();
+            assert_eq!(
+                globals
+                    .get_item("result")
+                    .unwrap()
+                    .extract::<&str>()
+                    .unwrap(),
+                "Undefined"
+            );
+
+            let flags = CompileFlags::new().future_feature("braces");
+            let err = py
+                .compile("1", "<test>", CompileMode::Eval, &flags)
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    #[cfg(Py_3_8)]
+    fn test_compile_top_level_await() {
+        Python::with_gil(|py| {
+            let source = "import asyncio\nawait asyncio.sleep(0)";
+            assert!(py
+                .compile(source, "<test>", CompileMode::Exec, &CompileFlags::new())
+                .is_err());
+            let flags = CompileFlags::new().allow_top_level_await(true);
+            let code = py
+                .compile(source, "<test>", CompileMode::Exec, &flags)
+                .unwrap();
+            let coroutine = code.eval(Some(PyDict::new(py)), None).unwrap();
+            let asyncio = py.import("asyncio").unwrap();
+            asyncio.call_method1("run", (coroutine,)).unwrap();
+        });
+    }
+
+    #[test]
+    fn test_compile_syntax_error() {
+        Python::with_gil(|py| {
+            let err = py
+                .compile(
+                    "x = 1\ny = (",
+                    "script.py",
+                    CompileMode::Exec,
+                    &CompileFlags::new(),
+                )
+                .unwrap_err();
+            let err: &PySyntaxError = err.value(py).downcast().unwrap();
+            assert_eq!(err.filename(), Some("script.py"));
+            assert_eq!(err.lineno(), Some(2));
+            assert_eq!(err.offset(), Some(5));
+            assert_eq!(err.text().map(str::trim_end), Some("y = ("));
+        });
+    }
+}
@@ -3,10 +3,12 @@
 use super::PyMapping;
 use crate::err::{self, PyErr, PyResult};
 use crate::ffi::Py_ssize_t;
-use crate::types::{PyAny, PyList};
-use crate::{ffi, AsPyPointer, Python, ToPyObject};
+use crate::sync::GILOnceCell;
+use crate::types::{PyAny, PyIterator, PyList, PySet};
 #[cfg(not(PyPy))]
-use crate::{IntoPyPointer, PyObject};
+use crate::IntoPyPointer;
+use crate::{ffi, AsPyPointer, Bound, FromPyObject, PyObject, Python, ToPyObject};
+use std::marker::PhantomData;
 use std::ptr::NonNull;
 
 /// Represents a Python `dict`.
@@ -20,8 +22,9 @@ pyobject_native_type!(
     #checkfunction=ffi::PyDict_Check
 );
 
-/// Represents a Python `dict_keys`.
-#[cfg(not(PyPy))]
+/// Represents a Python `dict_keys`, a live view of the keys of a dictionary.
+///
+/// Created by [`PyDict::keys_view`].
 #[repr(transparent)]
 pub struct PyDictKeys(PyAny);
 
@@ -32,8 +35,15 @@ pyobject_native_type_core!(
     #checkfunction=ffi::PyDictKeys_Check
 );
 
-/// Represents a Python `dict_values`.
-#[cfg(not(PyPy))]
+#[cfg(PyPy)]
+pyobject_native_type_core!(
+    PyDictKeys,
+    *view_type_object(Python::assume_gil_acquired(), ViewKind::Keys)
+);
+
+/// Represents a Python `dict_values`, a live view of the values of a dictionary.
+///
+/// Created by [`PyDict::values_view`].
 #[repr(transparent)]
 pub struct PyDictValues(PyAny);
 
@@ -44,8 +54,15 @@ pyobject_native_type_core!(
     #checkfunction=ffi::PyDictValues_Check
 );
 
-/// Represents a Python `dict_items`.
-#[cfg(not(PyPy))]
+#[cfg(PyPy)]
+pyobject_native_type_core!(
+    PyDictValues,
+    *view_type_object(Python::assume_gil_acquired(), ViewKind::Values)
+);
+
+/// Represents a Python `dict_items`, a live view of the `(key, value)` pairs of a dictionary.
+///
+/// Created by [`PyDict::items_view`].
 #[repr(transparent)]
 pub struct PyDictItems(PyAny);
 
@@ -56,12 +73,62 @@ pyobject_native_type_core!(
     #checkfunction=ffi::PyDictItems_Check
 );
 
+#[cfg(PyPy)]
+pyobject_native_type_core!(
+    PyDictItems,
+    *view_type_object(Python::assume_gil_acquired(), ViewKind::Items)
+);
+
+/// PyPy does not export the view types, so they are looked up from an empty dictionary.
+#[cfg(PyPy)]
+#[derive(Clone, Copy)]
+enum ViewKind {
+    Keys,
+    Values,
+    Items,
+}
+
+#[cfg(PyPy)]
+fn view_type_object(py: Python<'_>, kind: ViewKind) -> *mut ffi::PyTypeObject {
+    use crate::sync::GILOnceCell;
+    use crate::types::PyType;
+    use crate::Py;
+
+    static KEYS: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+    static VALUES: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+    static ITEMS: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+    let (cell, method) = match kind {
+        ViewKind::Keys => (&KEYS, "keys"),
+        ViewKind::Values => (&VALUES, "values"),
+        ViewKind::Items => (&ITEMS, "items"),
+    };
+    let ty = cell.get_or_try_init(py, || {
+        PyDict::new(py)
+            .call_method0(method)
+            .map(|view| view.get_type().into())
+    });
+    match ty {
+        Ok(ty) => ty.as_ptr() as *mut ffi::PyTypeObject,
+        // `PyTypeInfo::type_object_raw` cannot report errors.
+        Err(err) => {
+            err.print(py);
+            panic!("failed to get the type of dict.{}()", method)
+        }
+    }
+}
+
 impl PyDict {
     /// Creates a new empty dictionary.
     pub fn new(py: Python<'_>) -> &PyDict {
         unsafe { py.from_owned_ptr::<PyDict>(ffi::PyDict_New()) }
     }
 
+    /// Creates a new empty dictionary, returned as a [`Bound`].
+    pub fn new_bound(py: Python<'_>) -> Bound<'_, PyDict> {
+        unsafe { Bound::from_owned_ptr(py, ffi::PyDict_New()) }
+    }
+
     /// Creates a new dictionary from the sequence given.
     ///
     /// The sequence must consist of `(PyObject, PyObject)`. This is
@@ -242,6 +309,53 @@ impl PyDict {
         }
     }
 
+    /// Returns a live view of the dict keys.
+    ///
+    /// This is equivalent to the Python expression `dict.keys(self)`. Unlike [`keys`](PyDict::keys),
+    /// this does not copy the keys, and the view reflects later changes to the dictionary.
+    pub fn keys_view(&self) -> PyResult<&PyDictKeys> {
+        static KEYS: GILOnceCell<PyObject> = GILOnceCell::new();
+        let view = self.call_dict_method(&KEYS, "keys")?;
+        view.downcast().map_err(PyErr::from)
+    }
+
+    /// Returns a live view of the dict values.
+    ///
+    /// This is equivalent to the Python expression `dict.values(self)`. Unlike
+    /// [`values`](PyDict::values), this does not copy the values, and the view reflects later
+    /// changes to the dictionary.
+    pub fn values_view(&self) -> PyResult<&PyDictValues> {
+        static VALUES: GILOnceCell<PyObject> = GILOnceCell::new();
+        let view = self.call_dict_method(&VALUES, "values")?;
+        view.downcast().map_err(PyErr::from)
+    }
+
+    /// Returns a live view of the dict items.
+    ///
+    /// This is equivalent to the Python expression `dict.items(self)`. Unlike
+    /// [`items`](PyDict::items), this does not copy the items, and the view reflects later
+    /// changes to the dictionary.
+    pub fn items_view(&self) -> PyResult<&PyDictItems> {
+        static ITEMS: GILOnceCell<PyObject> = GILOnceCell::new();
+        let view = self.call_dict_method(&ITEMS, "items")?;
+        view.downcast().map_err(PyErr::from)
+    }
+
+    /// Calls a method of `dict` on `self`, bypassing overrides in subclasses.
+    ///
+    /// The unbound method is looked up once and cached in `method`.
+    fn call_dict_method(
+        &self,
+        method: &'static GILOnceCell<PyObject>,
+        name: &str,
+    ) -> PyResult<&PyAny> {
+        let py = self.py();
+        let method = method.per_interpreter(py)?.get_or_try_init(py, || {
+            py.get_type::<PyDict>().getattr(name).map(PyObject::from)
+        })?;
+        method.as_ref(py).call1((self,))
+    }
+
     /// Returns an iterator of `(key, value)` pairs in this dictionary.
     ///
     /// # Panics
@@ -253,6 +367,49 @@ impl PyDict {
         IntoIterator::into_iter(self)
     }
 
+    /// Returns an iterator of `(key, value)` pairs in this dictionary, extracted as `K` and `V`.
+    ///
+    /// Keys and values are extracted directly, without creating a `&PyAny` for each of them.
+    /// Items which fail to extract produce an error, after which iteration can continue.
+    ///
+    /// # Panics
+    ///
+    /// Like [`iter`](PyDict::iter), this panics if the set of keys changes during iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyDict;
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     let dict: &PyDict = py.eval("{'a': 1, 'b': 2}", None, None)?.downcast()?;
+    ///     let mut total = 0;
+    ///     for item in dict.iter_as::<String, i64>() {
+    ///         let (key, value) = item?;
+    ///         assert!(key == "a" || key == "b");
+    ///         total += value;
+    ///     }
+    ///     assert_eq!(total, 3);
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    pub fn iter_as<K, V>(&self) -> PyDictTypedIterator<'_, K, V>
+    where
+        K: for<'a> FromPyObject<'a>,
+        V: for<'a> FromPyObject<'a>,
+    {
+        PyDictTypedIterator {
+            dict: self,
+            ppos: 0,
+            di_used: self._len(),
+            len: self._len(),
+            _marker: PhantomData,
+        }
+    }
+
     /// Returns `self` cast as a `PyMapping`.
     pub fn as_mapping(&self) -> &PyMapping {
         unsafe { self.downcast_unchecked() }
@@ -279,6 +436,206 @@ impl PyDict {
         let py = self.py();
         unsafe { err::error_on_minusone(py, ffi::PyDict_Merge(self.as_ptr(), other.as_ptr(), 0)) }
     }
+
+    /// Returns the value of `key`, inserting `default` first if `key` is not present.
+    ///
+    /// This is equivalent to the Python expression `self.setdefault(key, default)`.
+    pub fn setdefault<K, V>(&self, key: K, default: V) -> PyResult<&PyAny>
+    where
+        K: ToPyObject,
+        V: ToPyObject,
+    {
+        let py = self.py();
+        let key = key.to_object(py);
+        let default = default.to_object(py);
+        #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+        unsafe {
+            let ptr = ffi::PyDict_SetDefault(self.as_ptr(), key.as_ptr(), default.as_ptr());
+            if ptr.is_null() {
+                return Err(PyErr::fetch(py));
+            }
+            // PyDict_SetDefault returns a borrowed ptr, must make it owned for safety (see #890).
+            Ok(py.from_owned_ptr(ffi::_Py_NewRef(ptr)))
+        }
+        #[cfg(any(Py_LIMITED_API, PyPy))]
+        {
+            py.get_type::<PyDict>()
+                .getattr(intern!(py, "setdefault"))?
+                .call1((self, key, default))
+        }
+    }
+
+    /// Removes `key` from the dictionary and returns its value.
+    ///
+    /// Returns `Ok(None)` if `key` is not present. This is equivalent to the Python expression
+    /// `self.pop(key, None)`, except that a value of `None` can be told apart from a missing key.
+    pub fn pop<K>(&self, key: K) -> PyResult<Option<&PyAny>>
+    where
+        K: ToPyObject,
+    {
+        let py = self.py();
+        let key = key.to_object(py);
+        unsafe {
+            let value = ffi::PyDict_GetItemWithError(self.as_ptr(), key.as_ptr());
+            if value.is_null() {
+                return match PyErr::take(py) {
+                    Some(err) => Err(err),
+                    None => Ok(None),
+                };
+            }
+            // Keep the value alive while it is removed from the dictionary.
+            let value: &PyAny = py.from_owned_ptr(ffi::_Py_NewRef(value));
+            err::error_on_minusone(py, ffi::PyDict_DelItem(self.as_ptr(), key.as_ptr()))?;
+            Ok(Some(value))
+        }
+    }
+
+    /// Removes the most recently inserted item from the dictionary and returns it.
+    ///
+    /// Returns `Ok(None)` if the dictionary is empty. This is equivalent to the Python expression
+    /// `self.popitem()`, except that an empty dictionary does not raise a `KeyError`.
+    pub fn popitem(&self) -> PyResult<Option<(&PyAny, &PyAny)>> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let py = self.py();
+        let item = py
+            .get_type::<PyDict>()
+            .getattr(intern!(py, "popitem"))?
+            .call1((self,))?;
+        item.extract().map(Some)
+    }
+}
+
+macro_rules! dict_view_methods {
+    ($name:ident) => {
+        impl $name {
+            /// Returns the number of entries in the underlying dictionary.
+            ///
+            /// This is equivalent to the Python expression `len(self)`.
+            pub fn len(&self) -> PyResult<usize> {
+                let v = unsafe { ffi::PyObject_Size(self.as_ptr()) };
+                if v == -1 {
+                    Err(PyErr::fetch(self.py()))
+                } else {
+                    Ok(v as usize)
+                }
+            }
+
+            /// Checks if the underlying dictionary is empty.
+            pub fn is_empty(&self) -> PyResult<bool> {
+                self.len().map(|len| len == 0)
+            }
+
+            /// Determines if the view contains the specified value.
+            ///
+            /// This is equivalent to the Python expression `value in self`.
+            pub fn contains<V>(&self, value: V) -> PyResult<bool>
+            where
+                V: ToPyObject,
+            {
+                let py = self.py();
+                match unsafe {
+                    ffi::PySequence_Contains(self.as_ptr(), value.to_object(py).as_ptr())
+                } {
+                    0 => Ok(false),
+                    1 => Ok(true),
+                    _ => Err(PyErr::fetch(py)),
+                }
+            }
+
+            /// Returns an iterator over the entries of the view.
+            ///
+            /// Like iterating over the view in Python, the iterator produces an error if the
+            /// dictionary changes size during iteration. If the iterator cannot be created, the
+            /// error is produced as its first item.
+            pub fn iter(&self) -> PyDictViewIterator<'_> {
+                PyDictViewIterator {
+                    inner: PyAny::iter(self).map_err(Some),
+                }
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $name {
+            type Item = PyResult<&'a PyAny>;
+            type IntoIter = PyDictViewIterator<'a>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+    };
+}
+
+dict_view_methods!(PyDictKeys);
+dict_view_methods!(PyDictValues);
+dict_view_methods!(PyDictItems);
+
+impl PyDictKeys {
+    /// Returns the union of the keys and `other` as a new set.
+    ///
+    /// This is equivalent to the Python expression `self | other`.
+    pub fn union(&self, other: &PyAny) -> PyResult<&PySet> {
+        self.set_operation(other, ffi::PyNumber_Or)
+    }
+
+    /// Returns the intersection of the keys and `other` as a new set.
+    ///
+    /// This is equivalent to the Python expression `self & other`.
+    pub fn intersection(&self, other: &PyAny) -> PyResult<&PySet> {
+        self.set_operation(other, ffi::PyNumber_And)
+    }
+
+    /// Returns the keys which are not in `other` as a new set.
+    ///
+    /// This is equivalent to the Python expression `self - other`.
+    pub fn difference(&self, other: &PyAny) -> PyResult<&PySet> {
+        self.set_operation(other, ffi::PyNumber_Subtract)
+    }
+
+    /// Returns the keys which are in either the view or `other`, but not both, as a new set.
+    ///
+    /// This is equivalent to the Python expression `self ^ other`.
+    pub fn symmetric_difference(&self, other: &PyAny) -> PyResult<&PySet> {
+        self.set_operation(other, ffi::PyNumber_Xor)
+    }
+
+    /// Returns `true` if the keys have no elements in common with `other`.
+    ///
+    /// This is equivalent to the Python expression `self.isdisjoint(other)`.
+    pub fn isdisjoint(&self, other: &PyAny) -> PyResult<bool> {
+        self.call_method1(intern!(self.py(), "isdisjoint"), (other,))?
+            .extract()
+    }
+
+    fn set_operation(
+        &self,
+        other: &PyAny,
+        op: unsafe extern "C" fn(*mut ffi::PyObject, *mut ffi::PyObject) -> *mut ffi::PyObject,
+    ) -> PyResult<&PySet> {
+        let py = self.py();
+        let result: &PyAny =
+            unsafe { py.from_owned_ptr_or_err(op(self.as_ptr(), other.as_ptr()))? };
+        result.downcast().map_err(PyErr::from)
+    }
+}
+
+/// PyO3 implementation of an iterator over a [`PyDictKeys`], [`PyDictValues`] or [`PyDictItems`].
+pub struct PyDictViewIterator<'py> {
+    /// The error is taken after it has been produced.
+    inner: Result<&'py PyIterator, Option<PyErr>>,
+}
+
+impl<'py> Iterator for PyDictViewIterator<'py> {
+    type Item = PyResult<&'py PyAny>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            Ok(iter) => iter.next(),
+            Err(err) => err.take().map(Err),
+        }
+    }
 }
 
 /// PyO3 implementation of an iterator for a Python `dict` object.
@@ -376,6 +733,180 @@ impl<'py> PyDictIterator<'py> {
     }
 }
 
+/// Iterator over a Python `dict` which extracts keys and values as `K` and `V`.
+///
+/// Created by [`PyDict::iter_as`].
+pub struct PyDictTypedIterator<'py, K, V> {
+    dict: &'py PyDict,
+    ppos: ffi::Py_ssize_t,
+    di_used: ffi::Py_ssize_t,
+    len: ffi::Py_ssize_t,
+    _marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'py, K, V> Iterator for PyDictTypedIterator<'py, K, V>
+where
+    K: for<'a> FromPyObject<'a>,
+    V: for<'a> FromPyObject<'a>,
+{
+    type Item = PyResult<(K, V)>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ma_used = self.dict._len();
+
+        // The same checks as in `PyDictIterator::next`.
+        if self.di_used != ma_used {
+            self.di_used = -1;
+            panic!("dictionary changed size during iteration");
+        };
+
+        if self.len == -1 {
+            self.di_used = -1;
+            panic!("dictionary keys changed during iteration");
+        };
+
+        let mut key: *mut ffi::PyObject = std::ptr::null_mut();
+        let mut value: *mut ffi::PyObject = std::ptr::null_mut();
+        unsafe {
+            if ffi::PyDict_Next(self.dict.as_ptr(), &mut self.ppos, &mut key, &mut value) != 0 {
+                self.len -= 1;
+                let py = self.dict.py();
+                // PyDict_Next returns borrowed values; for safety must make them owned (see #890)
+                let key = Bound::<PyAny>::from_borrowed_ptr(py, key);
+                let value = Bound::<PyAny>::from_borrowed_ptr(py, value);
+                Some(key.extract().and_then(|k| Ok((k, value.extract()?))))
+            } else {
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<K, V> ExactSizeIterator for PyDictTypedIterator<'_, K, V>
+where
+    K: for<'a> FromPyObject<'a>,
+    V: for<'a> FromPyObject<'a>,
+{
+    fn len(&self) -> usize {
+        self.len as usize
+    }
+}
+
+impl<'py> Bound<'py, PyDict> {
+    /// Gets an item from the dictionary.
+    ///
+    /// Returns `None` if the item is not present, or if an error occurs.
+    ///
+    /// See [`PyDict::get_item`].
+    pub fn get_item<K>(&self, key: K) -> Option<Bound<'py, PyAny>>
+    where
+        K: ToPyObject,
+    {
+        let py = self.py();
+        unsafe {
+            let ptr = ffi::PyDict_GetItem(self.as_ptr(), key.to_object(py).as_ptr());
+            NonNull::new(ptr).map(|p| Bound::from_borrowed_ptr(py, p.as_ptr()))
+        }
+    }
+
+    /// Returns an iterator of `(key, value)` pairs in this dictionary.
+    ///
+    /// # Panics
+    ///
+    /// If PyO3 detects that the dictionary is mutated during iteration, it will panic.
+    /// It is allowed to modify values as you iterate over the dictionary, but only
+    /// so long as the set of keys does not change.
+    pub fn iter(&self) -> BoundDictIterator<'py> {
+        IntoIterator::into_iter(self.clone())
+    }
+}
+
+/// PyO3 implementation of an iterator for a `Bound<PyDict>`.
+pub struct BoundDictIterator<'py> {
+    dict: Bound<'py, PyDict>,
+    ppos: ffi::Py_ssize_t,
+    di_used: ffi::Py_ssize_t,
+    len: ffi::Py_ssize_t,
+}
+
+impl<'py> Iterator for BoundDictIterator<'py> {
+    type Item = (Bound<'py, PyAny>, Bound<'py, PyAny>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ma_used = self.dict._len();
+
+        // The same checks as in `PyDictIterator::next`.
+        if self.di_used != ma_used {
+            self.di_used = -1;
+            panic!("dictionary changed size during iteration");
+        };
+
+        if self.len == -1 {
+            self.di_used = -1;
+            panic!("dictionary keys changed during iteration");
+        };
+
+        let mut key: *mut ffi::PyObject = std::ptr::null_mut();
+        let mut value: *mut ffi::PyObject = std::ptr::null_mut();
+        unsafe {
+            if ffi::PyDict_Next(self.dict.as_ptr(), &mut self.ppos, &mut key, &mut value) != 0 {
+                self.len -= 1;
+                let py = self.dict.py();
+                // PyDict_Next returns borrowed values; for safety must make them owned (see #890)
+                Some((
+                    Bound::from_borrowed_ptr(py, key),
+                    Bound::from_borrowed_ptr(py, value),
+                ))
+            } else {
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for BoundDictIterator<'_> {
+    fn len(&self) -> usize {
+        self.len as usize
+    }
+}
+
+impl<'py> IntoIterator for Bound<'py, PyDict> {
+    type Item = (Bound<'py, PyAny>, Bound<'py, PyAny>);
+    type IntoIter = BoundDictIterator<'py>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BoundDictIterator {
+            ppos: 0,
+            di_used: self._len(),
+            len: self._len(),
+            dict: self,
+        }
+    }
+}
+
+impl<'py> IntoIterator for &Bound<'py, PyDict> {
+    type Item = (Bound<'py, PyAny>, Bound<'py, PyAny>);
+    type IntoIter = BoundDictIterator<'py>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 /// Conversion trait that allows a sequence of tuples to be converted into `PyDict`
 /// Primary use case for this trait is `call` and `call_method` methods as keywords argument.
 pub trait IntoPyDict {
@@ -824,6 +1355,42 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_bound() {
+        Python::with_gil(|py| {
+            let dict = PyDict::new_bound(py);
+            dict.set_item(7, 32).unwrap();
+            dict.set_item(8, 42).unwrap();
+            assert_eq!(dict.get_item(7).unwrap().extract::<i32>().unwrap(), 32);
+            assert!(dict.get_item(9).is_none());
+
+            let mut key_sum = 0;
+            let mut value_sum = 0;
+            for (key, value) in &dict {
+                key_sum += key.extract::<i32>().unwrap();
+                value_sum += value.extract::<i32>().unwrap();
+            }
+            assert_eq!(7 + 8, key_sum);
+            assert_eq!(32 + 42, value_sum);
+            assert_eq!(dict.iter().len(), 2);
+            assert_eq!(dict.into_iter().count(), 2);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "dictionary changed size during iteration")]
+    fn test_bound_iter_mutation() {
+        Python::with_gil(|py| {
+            let dict = PyDict::new_bound(py);
+            dict.set_item(1, 1).unwrap();
+            dict.set_item(2, 2).unwrap();
+            for (key, _) in &dict {
+                dict.set_item(key.extract::<i32>().unwrap() + 10, 0)
+                    .unwrap();
+            }
+        });
+    }
+
     #[test]
     fn test_hashmap_into_dict() {
         Python::with_gil(|py| {
@@ -893,7 +1460,6 @@ mod tests {
         });
     }
 
-    #[cfg(not(PyPy))]
     fn abc_dict(py: Python<'_>) -> &PyDict {
         let mut map = HashMap::<&'static str, i32>::new();
         map.insert("a", 1);
@@ -903,7 +1469,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(not(PyPy))]
     fn dict_keys_view() {
         Python::with_gil(|py| {
             let dict = abc_dict(py);
@@ -913,7 +1478,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(not(PyPy))]
     fn dict_values_view() {
         Python::with_gil(|py| {
             let dict = abc_dict(py);
@@ -923,7 +1487,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(not(PyPy))]
     fn dict_items_view() {
         Python::with_gil(|py| {
             let dict = abc_dict(py);
@@ -932,6 +1495,173 @@ mod tests {
         })
     }
 
+    #[test]
+    fn dict_views() {
+        Python::with_gil(|py| {
+            let dict = abc_dict(py);
+            let keys = dict.keys_view().unwrap();
+            let values = dict.values_view().unwrap();
+            let items = dict.items_view().unwrap();
+            assert_eq!(keys.len().unwrap(), 3);
+            assert!(keys.contains("a").unwrap());
+            assert!(!keys.contains("d").unwrap());
+            assert!(values.contains(2).unwrap());
+            assert!(items.contains(("c", 3)).unwrap());
+            assert!(!items.contains(("c", 4)).unwrap());
+
+            // The views are live
+            dict.set_item("d", 4).unwrap();
+            assert_eq!(keys.len().unwrap(), 4);
+            assert_eq!(values.len().unwrap(), 4);
+            assert_eq!(items.len().unwrap(), 4);
+            assert!(keys.contains("d").unwrap());
+
+            let mut key_sum = String::new();
+            for key in keys {
+                key_sum.push_str(key.unwrap().extract().unwrap());
+            }
+            assert_eq!(key_sum.len(), 4);
+            let value_sum: i32 = values
+                .iter()
+                .map(|v| v.unwrap().extract::<i32>().unwrap())
+                .sum();
+            assert_eq!(value_sum, 10);
+            for item in items {
+                let (k, v): (&str, i32) = item.unwrap().extract().unwrap();
+                assert_eq!(dict.get_item(k).unwrap().extract::<i32>().unwrap(), v);
+            }
+
+            dict.clear();
+            assert!(keys.is_empty().unwrap());
+            assert!(values.is_empty().unwrap());
+            assert!(items.is_empty().unwrap());
+        })
+    }
+
+    #[test]
+    fn dict_views_bypass_subclass_overrides() {
+        Python::with_gil(|py| {
+            let cls = py
+                .eval(
+                    "type('D', (dict,), {'keys': lambda self: [], 'items': None})",
+                    None,
+                    None,
+                )
+                .unwrap();
+            let dict: &PyDict = cls.call1(([("a", 1)],)).unwrap().downcast().unwrap();
+            assert_eq!(dict.keys_view().unwrap().len().unwrap(), 1);
+            assert_eq!(dict.items_view().unwrap().len().unwrap(), 1);
+        })
+    }
+
+    #[test]
+    fn dict_keys_set_operations() {
+        Python::with_gil(|py| {
+            let dict = abc_dict(py);
+            let keys = dict.keys_view().unwrap();
+            let other = PySet::new(py, &["b", "c", "d"]).unwrap();
+
+            let union = keys.union(other).unwrap();
+            assert_eq!(union.len(), 4);
+            let intersection = keys.intersection(other).unwrap();
+            assert_eq!(intersection.len(), 2);
+            assert!(intersection.contains("b").unwrap());
+            let difference = keys.difference(other).unwrap();
+            assert_eq!(difference.len(), 1);
+            assert!(difference.contains("a").unwrap());
+            let symmetric = keys.symmetric_difference(other).unwrap();
+            assert_eq!(symmetric.len(), 2);
+            assert!(symmetric.contains("a").unwrap());
+            assert!(symmetric.contains("d").unwrap());
+
+            assert!(!keys.isdisjoint(other).unwrap());
+            assert!(keys.isdisjoint(PySet::new(py, &["x"]).unwrap()).unwrap());
+
+            assert!(keys.union(py.None().as_ref(py)).is_err());
+        })
+    }
+
+    #[test]
+    fn dict_iter_as() {
+        Python::with_gil(|py| {
+            let dict = abc_dict(py);
+            let iter = dict.iter_as::<String, i64>();
+            assert_eq!(iter.len(), 3);
+            let mut items: Vec<(String, i64)> = iter.collect::<PyResult<_>>().unwrap();
+            items.sort();
+            assert_eq!(
+                items,
+                vec![
+                    ("a".to_owned(), 1),
+                    ("b".to_owned(), 2),
+                    ("c".to_owned(), 3)
+                ]
+            );
+
+            let mut iter = dict.iter_as::<String, String>();
+            assert!(iter.next().unwrap().is_err());
+            assert_eq!(iter.len(), 2);
+            assert_eq!(iter.count(), 2);
+        })
+    }
+
+    #[test]
+    #[should_panic(expected = "dictionary changed size during iteration")]
+    fn dict_iter_as_mutated() {
+        Python::with_gil(|py| {
+            let dict = abc_dict(py);
+            for item in dict.iter_as::<String, i64>() {
+                let (key, _) = item.unwrap();
+                dict.del_item(key).unwrap();
+            }
+        })
+    }
+
+    #[test]
+    fn dict_setdefault() {
+        Python::with_gil(|py| {
+            let dict = abc_dict(py);
+            assert_eq!(
+                dict.setdefault("a", 10).unwrap().extract::<i32>().unwrap(),
+                1
+            );
+            assert_eq!(
+                dict.setdefault("d", 4).unwrap().extract::<i32>().unwrap(),
+                4
+            );
+            assert_eq!(dict.len(), 4);
+            assert_eq!(dict.get_item("d").unwrap().extract::<i32>().unwrap(), 4);
+            assert!(dict.setdefault(PyList::empty(py), 1).is_err());
+        })
+    }
+
+    #[test]
+    fn dict_pop() {
+        Python::with_gil(|py| {
+            let dict = abc_dict(py);
+            assert_eq!(dict.pop("a").unwrap().unwrap().extract::<i32>().unwrap(), 1);
+            assert!(dict.pop("a").unwrap().is_none());
+            assert_eq!(dict.len(), 2);
+            dict.set_item("n", py.None()).unwrap();
+            assert!(dict.pop("n").unwrap().unwrap().is_none());
+            assert!(dict.pop(PyList::empty(py)).is_err());
+        })
+    }
+
+    #[test]
+    fn dict_popitem() {
+        Python::with_gil(|py| {
+            let dict = PyDict::new(py);
+            assert!(dict.popitem().unwrap().is_none());
+            dict.set_item("a", 1).unwrap();
+            dict.set_item("b", 2).unwrap();
+            let (key, value) = dict.popitem().unwrap().unwrap();
+            assert_eq!(key.extract::<&str>().unwrap(), "b");
+            assert_eq!(value.extract::<i32>().unwrap(), 2);
+            assert_eq!(dict.len(), 1);
+        })
+    }
+
     #[test]
     fn dict_update() {
         Python::with_gil(|py| {
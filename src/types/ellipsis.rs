@@ -0,0 +1,51 @@
+use crate::{ffi, PyAny, Python};
+use std::os::raw::c_int;
+
+/// Represents the Python `Ellipsis` object, also written as `...`.
+#[repr(transparent)]
+pub struct PyEllipsis(PyAny);
+
+pyobject_native_type_core!(
+    PyEllipsis,
+    *ffi::Py_TYPE(ffi::Py_Ellipsis()),
+    #checkfunction=is_ellipsis
+);
+
+impl PyEllipsis {
+    /// Returns the `Ellipsis` object.
+    #[inline]
+    pub fn get(_py: Python<'_>) -> &PyEllipsis {
+        unsafe { &*(ffi::Py_Ellipsis() as *const PyEllipsis) }
+    }
+}
+
+#[inline]
+unsafe fn is_ellipsis(op: *mut ffi::PyObject) -> c_int {
+    (op == ffi::Py_Ellipsis()) as c_int
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{PyDict, PyEllipsis};
+    use crate::{PyTypeInfo, Python};
+
+    #[test]
+    fn test_ellipsis() {
+        Python::with_gil(|py| {
+            let ellipsis = PyEllipsis::get(py);
+            assert!(ellipsis.is(&py.Ellipsis()));
+            assert!(ellipsis.is(py.eval("...", None, None).unwrap()));
+            assert_eq!(ellipsis.to_string(), "Ellipsis");
+            assert!(ellipsis.get_type().is(PyEllipsis::type_object(py)));
+        })
+    }
+
+    #[test]
+    fn test_ellipsis_downcast() {
+        Python::with_gil(|py| {
+            assert!(py.Ellipsis().as_ref(py).downcast::<PyEllipsis>().is_ok());
+            assert!(py.None().as_ref(py).downcast::<PyEllipsis>().is_err());
+            assert!(PyDict::new(py).downcast::<PyEllipsis>().is_err());
+        })
+    }
+}
@@ -1,9 +1,12 @@
 // Copyright (c) 2022-present PyO3 Project and Contributors
 
-use crate::ffi;
-use crate::PyAny;
+use crate::types::{PyCode, PyDict};
+use crate::{ffi, AsPyPointer, PyAny, PyResult, Python};
 
 /// Represents a Python frame.
+///
+/// Frames are only safe to inspect while they are alive, for example the frames of the current
+/// call stack or of a [`PyTraceback`](crate::types::PyTraceback).
 #[repr(transparent)]
 pub struct PyFrame(PyAny);
 
@@ -12,3 +15,145 @@ pyobject_native_type_core!(
     ffi::PyFrame_Type,
     #checkfunction=ffi::PyFrame_Check
 );
+
+impl PyFrame {
+    /// Returns the frame of the Python code which is currently executing, if any.
+    ///
+    /// This is usually the frame of the Python function which called into Rust.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyFrame;
+    ///
+    /// #[pyfunction]
+    /// fn caller_line(py: Python<'_>) -> Option<usize> {
+    ///     PyFrame::current(py)?.line_number()
+    /// }
+    /// #
+    /// # Python::with_gil(|py| {
+    /// #     let globals = pyo3::types::PyDict::new(py);
+    /// #     globals.set_item("caller_line", pyo3::wrap_pyfunction!(caller_line, py).unwrap()).unwrap();
+    /// #     py.run("\nline = caller_line()", Some(globals), None).unwrap();
+    /// #     assert_eq!(globals.get_item("line").unwrap().extract::<usize>().unwrap(), 2);
+    /// # });
+    /// ```
+    pub fn current(py: Python<'_>) -> Option<&PyFrame> {
+        unsafe { py.from_borrowed_ptr_or_opt(ffi::PyEval_GetFrame() as *mut ffi::PyObject) }
+    }
+
+    /// Returns the frame which called this one, if any.
+    pub fn f_back(&self) -> Option<&PyFrame> {
+        let frame = self.as_ptr() as *mut ffi::PyFrameObject;
+        unsafe {
+            #[cfg(Py_3_9)]
+            {
+                self.py()
+                    .from_owned_ptr_or_opt(ffi::PyFrame_GetBack(frame) as *mut ffi::PyObject)
+            }
+            #[cfg(not(Py_3_9))]
+            {
+                self.py()
+                    .from_borrowed_ptr_or_opt((*frame).f_back as *mut ffi::PyObject)
+            }
+        }
+    }
+
+    /// Returns the code object which is executed in this frame.
+    pub fn code(&self) -> &PyCode {
+        let frame = self.as_ptr() as *mut ffi::PyFrameObject;
+        unsafe {
+            #[cfg(Py_3_9)]
+            {
+                self.py()
+                    .from_owned_ptr(ffi::PyFrame_GetCode(frame) as *mut ffi::PyObject)
+            }
+            #[cfg(not(Py_3_9))]
+            {
+                self.py()
+                    .from_borrowed_ptr((*frame).f_code as *mut ffi::PyObject)
+            }
+        }
+    }
+
+    /// Returns the line which is currently executed in this frame.
+    ///
+    /// Returns `None` if the line is unknown, for example before the frame started executing.
+    pub fn line_number(&self) -> Option<usize> {
+        let line = unsafe { ffi::PyFrame_GetLineNumber(self.as_ptr() as *mut ffi::PyFrameObject) };
+        if line < 0 {
+            None
+        } else {
+            Some(line as usize)
+        }
+    }
+
+    /// Returns the local variables of this frame.
+    ///
+    /// For function frames this is a snapshot of the local variables, modifying it does not change
+    /// them. For module frames this is the same dictionary as [`globals`](PyFrame::globals).
+    pub fn locals(&self) -> PyResult<&PyDict> {
+        let py = self.py();
+        Ok(self.getattr(intern!(py, "f_locals"))?.downcast()?)
+    }
+
+    /// Returns the global variables of this frame.
+    pub fn globals(&self) -> PyResult<&PyDict> {
+        let py = self.py();
+        Ok(self.getattr(intern!(py, "f_globals"))?.downcast()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{PyDict, PyFrame};
+    use crate::Python;
+
+    #[test]
+    fn test_frame() {
+        Python::with_gil(|py| {
+            assert!(PyFrame::current(py).is_none());
+
+            let globals = PyDict::new(py);
+            globals.set_item("sys", py.import("sys").unwrap()).unwrap();
+            py.run(
+                r#"
+def outer():
+    marker = 42
+    return inner()
+
+def inner():
+    return sys._getframe()
+
+frame = outer()
+"#,
+                Some(globals),
+                None,
+            )
+            .unwrap();
+            let frame: &PyFrame = globals.get_item("frame").unwrap().downcast().unwrap();
+            assert_eq!(frame.code().name().unwrap(), "inner");
+            assert_eq!(frame.line_number(), Some(7));
+
+            let outer = frame.f_back().unwrap();
+            assert_eq!(outer.code().name().unwrap(), "outer");
+            assert_eq!(outer.line_number(), Some(4));
+            assert_eq!(
+                outer
+                    .locals()
+                    .unwrap()
+                    .get_item("marker")
+                    .unwrap()
+                    .extract::<i32>()
+                    .unwrap(),
+                42
+            );
+            assert!(outer.globals().unwrap().is(globals));
+
+            let module = outer.f_back().unwrap();
+            assert_eq!(module.code().name().unwrap(), "<module>");
+            assert!(module.f_back().is_none());
+        });
+    }
+}
@@ -109,12 +109,10 @@ impl PyCFunction {
         py_or_module: PyFunctionArguments<'py>,
     ) -> PyResult<&'py Self> {
         let (py, module) = py_or_module.into_py_and_maybe_module();
-        let (mod_ptr, module_name) = if let Some(m) = module {
-            let mod_ptr = m.as_ptr();
-            let name: Py<PyAny> = m.name()?.into_py(py);
-            (mod_ptr, name.as_ptr())
+        let (mod_ptr, module_name): (_, Option<Py<PyAny>>) = if let Some(m) = module {
+            (m.as_ptr(), Some(m.name()?.into_py(py)))
         } else {
-            (std::ptr::null_mut(), std::ptr::null_mut())
+            (std::ptr::null_mut(), None)
         };
         let (def, destructor) = method_def.as_method_def()?;
 
@@ -126,7 +124,9 @@ impl PyCFunction {
             py.from_owned_ptr_or_err::<PyCFunction>(ffi::PyCFunction_NewEx(
                 def,
                 mod_ptr,
-                module_name,
+                module_name
+                    .as_ref()
+                    .map_or(std::ptr::null_mut(), Py::as_ptr),
             ))
         }
     }
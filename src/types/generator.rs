@@ -0,0 +1,490 @@
+use crate::err::{PyErr, PyResult};
+use crate::exceptions::PyStopIteration;
+use crate::ffi;
+use crate::{AsPyPointer, PyAny, Python, ToPyObject};
+
+/// Represents a Python generator, as created by calling a function containing `yield`.
+///
+/// Generators can be driven from Rust with [`send`](PyGenerator::send), which tells yielded
+/// values apart from the generator's return value.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::types::{PyGenerator, PySendResult};
+///
+/// # fn main() -> PyResult<()> {
+/// Python::with_gil(|py| {
+///     let locals = pyo3::types::PyDict::new(py);
+///     py.run("def gen():\n    x = yield 1\n    return x * 2", None, Some(locals))?;
+///     let generator: &PyGenerator = locals.get_item("gen").unwrap().call0()?.downcast()?;
+///
+///     match generator.send(py.None())? {
+///         PySendResult::Next(value) => assert_eq!(value.extract::<i32>()?, 1),
+///         PySendResult::Return(_) => unreachable!(),
+///     }
+///     match generator.send(21)? {
+///         PySendResult::Next(_) => unreachable!(),
+///         PySendResult::Return(value) => assert_eq!(value.extract::<i32>()?, 42),
+///     }
+///     Ok(())
+/// })
+/// # }
+/// ```
+#[repr(transparent)]
+pub struct PyGenerator(PyAny);
+
+#[cfg(not(any(Py_LIMITED_API, PyPy)))]
+pyobject_native_type_core!(PyGenerator, ffi::PyGen_Type, #checkfunction=ffi::PyGen_Check);
+
+#[cfg(any(Py_LIMITED_API, PyPy))]
+pyobject_native_type_core!(
+    PyGenerator,
+    *types_module_type(Python::assume_gil_acquired(), Kind::Generator)
+);
+
+/// Represents a Python coroutine, as created by calling an `async def` function.
+///
+/// Like generators, coroutines can be driven from Rust with [`send`](PyCoroutine::send).
+#[repr(transparent)]
+pub struct PyCoroutine(PyAny);
+
+#[cfg(not(any(Py_LIMITED_API, PyPy)))]
+pyobject_native_type_core!(PyCoroutine, ffi::PyCoro_Type, #checkfunction=ffi::PyCoro_CheckExact);
+
+#[cfg(any(Py_LIMITED_API, PyPy))]
+pyobject_native_type_core!(
+    PyCoroutine,
+    *types_module_type(Python::assume_gil_acquired(), Kind::Coroutine)
+);
+
+/// Represents a Python asynchronous generator, as created by calling an `async def` function
+/// containing `yield`.
+///
+/// Each step of an asynchronous generator is an awaitable, which can in turn be driven with
+/// [`PyIterator::send`](crate::types::PyIterator::send).
+#[repr(transparent)]
+pub struct PyAsyncGenerator(PyAny);
+
+#[cfg(not(any(Py_LIMITED_API, PyPy)))]
+pyobject_native_type_core!(
+    PyAsyncGenerator,
+    ffi::PyAsyncGen_Type,
+    #checkfunction=ffi::PyAsyncGen_CheckExact
+);
+
+#[cfg(any(Py_LIMITED_API, PyPy))]
+pyobject_native_type_core!(
+    PyAsyncGenerator,
+    *types_module_type(Python::assume_gil_acquired(), Kind::AsyncGenerator)
+);
+
+/// The limited API and PyPy do not expose these type objects, so they are looked up in `types`.
+#[cfg(any(Py_LIMITED_API, PyPy))]
+#[derive(Clone, Copy)]
+enum Kind {
+    Generator,
+    Coroutine,
+    AsyncGenerator,
+}
+
+#[cfg(any(Py_LIMITED_API, PyPy))]
+fn types_module_type(py: Python<'_>, kind: Kind) -> *mut ffi::PyTypeObject {
+    use crate::sync::GILOnceCell;
+    use crate::types::PyType;
+    use crate::Py;
+
+    static GENERATOR: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+    static COROUTINE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+    static ASYNC_GENERATOR: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+    let (cell, name) = match kind {
+        Kind::Generator => (&GENERATOR, "GeneratorType"),
+        Kind::Coroutine => (&COROUTINE, "CoroutineType"),
+        Kind::AsyncGenerator => (&ASYNC_GENERATOR, "AsyncGeneratorType"),
+    };
+    cell.get_or_init(py, || {
+        py.import("types")
+            .and_then(|types| types.getattr(name))
+            .and_then(|ty| ty.extract::<&PyType>())
+            .unwrap_or_else(|err| panic!("failed to import types.{}: {}", name, err))
+            .into()
+    })
+    .as_ptr() as *mut ffi::PyTypeObject
+}
+
+/// The outcome of advancing a generator, coroutine or iterator by one step.
+#[derive(Debug)]
+pub enum PySendResult<'py> {
+    /// The generator yielded a value and can be resumed.
+    Next(&'py PyAny),
+    /// The generator finished, returning this value.
+    Return(&'py PyAny),
+}
+
+/// The execution state of a generator, coroutine or asynchronous generator.
+///
+/// These correspond to the states reported by `inspect.getgeneratorstate`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeneratorState {
+    /// Waiting to start execution.
+    Created,
+    /// Currently being executed by the interpreter.
+    Running,
+    /// Suspended at a `yield` or `await` expression.
+    Suspended,
+    /// Execution has completed.
+    Closed,
+}
+
+impl PyGenerator {
+    /// Resumes the generator, sending `value` as the result of the current `yield` expression.
+    ///
+    /// A generator which has not started yet must be sent `None`. On Python 3.10 and up this uses
+    /// `PyIter_Send`, so a return does not need to raise and catch `StopIteration`.
+    pub fn send<V>(&self, value: V) -> PyResult<PySendResult<'_>>
+    where
+        V: ToPyObject,
+    {
+        send_value(self, value)
+    }
+
+    /// Raises `err` at the current `yield` expression of the generator.
+    ///
+    /// Returns the next value yielded or returned by the generator, or the error it raises.
+    pub fn throw(&self, err: PyErr) -> PyResult<PySendResult<'_>> {
+        throw_into(self, intern!(self.py(), "throw"), err)
+    }
+
+    /// Raises `GeneratorExit` inside the generator, so that it finishes.
+    pub fn close(&self) -> PyResult<()> {
+        self.call_method0(intern!(self.py(), "close")).map(drop)
+    }
+
+    /// Returns the execution state of the generator.
+    pub fn state(&self) -> PyResult<GeneratorState> {
+        let py = self.py();
+        state_of(
+            self,
+            intern!(py, "gi_running"),
+            intern!(py, "gi_suspended"),
+            intern!(py, "gi_frame"),
+        )
+    }
+}
+
+impl PyCoroutine {
+    /// Resumes the coroutine, sending `value` as the result of the current `await` expression.
+    ///
+    /// See [`PyGenerator::send`].
+    pub fn send<V>(&self, value: V) -> PyResult<PySendResult<'_>>
+    where
+        V: ToPyObject,
+    {
+        send_value(self, value)
+    }
+
+    /// Raises `err` at the current `await` expression of the coroutine.
+    pub fn throw(&self, err: PyErr) -> PyResult<PySendResult<'_>> {
+        throw_into(self, intern!(self.py(), "throw"), err)
+    }
+
+    /// Raises `GeneratorExit` inside the coroutine, so that it finishes.
+    pub fn close(&self) -> PyResult<()> {
+        self.call_method0(intern!(self.py(), "close")).map(drop)
+    }
+
+    /// Returns the execution state of the coroutine.
+    pub fn state(&self) -> PyResult<GeneratorState> {
+        let py = self.py();
+        state_of(
+            self,
+            intern!(py, "cr_running"),
+            intern!(py, "cr_suspended"),
+            intern!(py, "cr_frame"),
+        )
+    }
+}
+
+impl PyAsyncGenerator {
+    /// Returns an awaitable which sends `value` into the asynchronous generator.
+    ///
+    /// This is equivalent to the Python expression `self.asend(value)`. The awaitable can be
+    /// driven with [`PyIterator::send`](crate::types::PyIterator::send); when it returns, its value is the next value yielded by the
+    /// generator. When the generator is exhausted it raises `StopAsyncIteration`.
+    pub fn asend<V>(&self, value: V) -> PyResult<&PyAny>
+    where
+        V: ToPyObject,
+    {
+        let py = self.py();
+        self.call_method1(intern!(py, "asend"), (value.to_object(py),))
+    }
+
+    /// Returns an awaitable which raises `err` inside the asynchronous generator.
+    ///
+    /// This is equivalent to the Python expression `self.athrow(err)`.
+    pub fn athrow(&self, err: PyErr) -> PyResult<&PyAny> {
+        let py = self.py();
+        self.call_method1(intern!(py, "athrow"), (err.into_value(py),))
+    }
+
+    /// Returns an awaitable which closes the asynchronous generator.
+    ///
+    /// This is equivalent to the Python expression `self.aclose()`.
+    pub fn aclose(&self) -> PyResult<&PyAny> {
+        self.call_method0(intern!(self.py(), "aclose"))
+    }
+
+    /// Returns the execution state of the asynchronous generator.
+    pub fn state(&self) -> PyResult<GeneratorState> {
+        let py = self.py();
+        state_of(
+            self,
+            intern!(py, "ag_running"),
+            intern!(py, "ag_suspended"),
+            intern!(py, "ag_frame"),
+        )
+    }
+}
+
+/// Advances `iter` by one step, sending it `value`.
+///
+/// On Python 3.10 and up this uses `PyIter_Send`; otherwise it calls the `send` method and
+/// converts `StopIteration` into [`PySendResult::Return`].
+pub(crate) fn send_value<'py, V>(iter: &'py PyAny, value: V) -> PyResult<PySendResult<'py>>
+where
+    V: ToPyObject,
+{
+    let py = iter.py();
+    let value = value.to_object(py);
+    #[cfg(all(Py_3_10, not(PyPy)))]
+    unsafe {
+        let mut result = std::ptr::null_mut();
+        match ffi::PyIter_Send(iter.as_ptr(), value.as_ptr(), &mut result) {
+            ffi::PySendResult::PYGEN_NEXT => Ok(PySendResult::Next(py.from_owned_ptr(result))),
+            ffi::PySendResult::PYGEN_RETURN => Ok(PySendResult::Return(py.from_owned_ptr(result))),
+            ffi::PySendResult::PYGEN_ERROR => Err(PyErr::fetch(py)),
+        }
+    }
+    #[cfg(not(all(Py_3_10, not(PyPy))))]
+    {
+        let send = intern!(py, "send");
+        if value.is_none(py) && !iter.hasattr(send)? {
+            // Like `PyIter_Send`, plain iterators are advanced with `__next__`. Every caller
+            // passes either an iterator or an object with a `send` method.
+            let next = unsafe { ffi::PyIter_Next(iter.as_ptr()) };
+            if next.is_null() {
+                return match PyErr::take(py) {
+                    Some(err) => stop_iteration_to_return(py, Err(err)),
+                    None => Ok(PySendResult::Return(py.None().into_ref(py))),
+                };
+            }
+            return Ok(PySendResult::Next(unsafe { py.from_owned_ptr(next) }));
+        }
+        stop_iteration_to_return(py, iter.call_method1(send, (value,)))
+    }
+}
+
+fn throw_into<'py>(
+    iter: &'py PyAny,
+    method: &crate::types::PyString,
+    err: PyErr,
+) -> PyResult<PySendResult<'py>> {
+    let py = iter.py();
+    stop_iteration_to_return(py, iter.call_method1(method, (err.into_value(py),)))
+}
+
+fn stop_iteration_to_return<'py>(
+    py: Python<'py>,
+    result: PyResult<&'py PyAny>,
+) -> PyResult<PySendResult<'py>> {
+    match result {
+        Ok(value) => Ok(PySendResult::Next(value)),
+        Err(err) if err.is_instance_of::<PyStopIteration>(py) => {
+            let value = err.into_value(py).getattr(py, intern!(py, "value"))?;
+            Ok(PySendResult::Return(value.into_ref(py)))
+        }
+        Err(err) => Err(err),
+    }
+}
+
+fn state_of(
+    obj: &PyAny,
+    running: &crate::types::PyString,
+    suspended: &crate::types::PyString,
+    frame: &crate::types::PyString,
+) -> PyResult<GeneratorState> {
+    // This follows `inspect.getgeneratorstate`.
+    if obj.getattr(running)?.is_true()? {
+        return Ok(GeneratorState::Running);
+    }
+    if obj.hasattr(suspended)? {
+        if obj.getattr(suspended)?.is_true()? {
+            return Ok(GeneratorState::Suspended);
+        }
+        if obj.getattr(frame)?.is_none() {
+            return Ok(GeneratorState::Closed);
+        }
+        return Ok(GeneratorState::Created);
+    }
+    let frame = obj.getattr(frame)?;
+    if frame.is_none() {
+        return Ok(GeneratorState::Closed);
+    }
+    // Before the first resumption `f_lasti` is -1, or from Python 3.11 the offset of the initial
+    // `RETURN_GENERATOR` instruction.
+    let py = obj.py();
+    let initial_lasti = if py.version_info() >= (3, 11) { 0 } else { -1 };
+    if frame.getattr(intern!(py, "f_lasti"))?.extract::<i64>()? == initial_lasti {
+        Ok(GeneratorState::Created)
+    } else {
+        Ok(GeneratorState::Suspended)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exceptions::{PyGeneratorExit, PyValueError};
+    use crate::types::{PyDict, PyIterator};
+
+    fn define<'py>(py: Python<'py>, code: &str, name: &str) -> &'py PyAny {
+        let globals = PyDict::new(py);
+        py.run(code, Some(globals), None).unwrap();
+        globals.get_item(name).unwrap()
+    }
+
+    fn expect_next(result: PySendResult<'_>) -> &PyAny {
+        match result {
+            PySendResult::Next(value) => value,
+            PySendResult::Return(value) => panic!("unexpected return of {}", value),
+        }
+    }
+
+    fn expect_return(result: PySendResult<'_>) -> &PyAny {
+        match result {
+            PySendResult::Next(value) => panic!("unexpected yield of {}", value),
+            PySendResult::Return(value) => value,
+        }
+    }
+
+    #[test]
+    fn test_generator_send() {
+        Python::with_gil(|py| {
+            let gen_fn = define(
+                py,
+                "def gen():\n    total = 0\n    while True:\n        x = yield total\n        if x is None:\n            return total\n        total += x",
+                "gen",
+            );
+            let generator: &PyGenerator = gen_fn.call0().unwrap().downcast().unwrap();
+            assert_eq!(generator.state().unwrap(), GeneratorState::Created);
+
+            let value = expect_next(generator.send(py.None()).unwrap());
+            assert_eq!(value.extract::<i32>().unwrap(), 0);
+            assert_eq!(generator.state().unwrap(), GeneratorState::Suspended);
+
+            let value = expect_next(generator.send(5).unwrap());
+            assert_eq!(value.extract::<i32>().unwrap(), 5);
+
+            let value = expect_return(generator.send(py.None()).unwrap());
+            assert_eq!(value.extract::<i32>().unwrap(), 5);
+            assert_eq!(generator.state().unwrap(), GeneratorState::Closed);
+
+            // Sending to a finished generator raises StopIteration without a value
+            assert!(expect_return(generator.send(py.None()).unwrap()).is_none());
+        })
+    }
+
+    #[test]
+    fn test_generator_throw_and_close() {
+        Python::with_gil(|py| {
+            let gen_fn = define(
+                py,
+                "def gen():\n    try:\n        yield 1\n    except ValueError:\n        yield 'caught'\n    yield 3",
+                "gen",
+            );
+            let generator: &PyGenerator = gen_fn.call0().unwrap().downcast().unwrap();
+            expect_next(generator.send(py.None()).unwrap());
+            let value = expect_next(generator.throw(PyValueError::new_err("boom")).unwrap());
+            assert_eq!(value.extract::<&str>().unwrap(), "caught");
+
+            let err = generator.throw(PyValueError::new_err("again")).unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+            assert_eq!(generator.state().unwrap(), GeneratorState::Closed);
+
+            let generator: &PyGenerator = gen_fn.call0().unwrap().downcast().unwrap();
+            expect_next(generator.send(py.None()).unwrap());
+            generator.close().unwrap();
+            assert_eq!(generator.state().unwrap(), GeneratorState::Closed);
+
+            let generator: &PyGenerator = gen_fn.call0().unwrap().downcast().unwrap();
+            let err = generator.throw(PyGeneratorExit::new_err(())).unwrap_err();
+            assert!(err.is_instance_of::<PyGeneratorExit>(py));
+        })
+    }
+
+    #[test]
+    fn test_coroutine() {
+        Python::with_gil(|py| {
+            let coro_fn = define(
+                py,
+                "import types\n@types.coroutine\ndef suspend():\n    return (yield 'suspended')\nasync def coro():\n    return await suspend() + 1",
+                "coro",
+            );
+            let coroutine: &PyCoroutine = coro_fn.call0().unwrap().downcast().unwrap();
+            assert!(coro_fn.downcast::<PyCoroutine>().is_err());
+            assert_eq!(coroutine.state().unwrap(), GeneratorState::Created);
+
+            let value = expect_next(coroutine.send(py.None()).unwrap());
+            assert_eq!(value.extract::<&str>().unwrap(), "suspended");
+            assert_eq!(coroutine.state().unwrap(), GeneratorState::Suspended);
+
+            let value = expect_return(coroutine.send(41).unwrap());
+            assert_eq!(value.extract::<i32>().unwrap(), 42);
+            assert_eq!(coroutine.state().unwrap(), GeneratorState::Closed);
+
+            let coroutine: &PyCoroutine = coro_fn.call0().unwrap().downcast().unwrap();
+            coroutine.close().unwrap();
+            assert_eq!(coroutine.state().unwrap(), GeneratorState::Closed);
+        })
+    }
+
+    #[test]
+    fn test_async_generator() {
+        Python::with_gil(|py| {
+            let agen_fn = define(py, "async def agen():\n    yield 1\n    yield 2", "agen");
+            let agen: &PyAsyncGenerator = agen_fn.call0().unwrap().downcast().unwrap();
+            assert!(agen.downcast::<PyGenerator>().is_err());
+            assert_eq!(agen.state().unwrap(), GeneratorState::Created);
+
+            let step: &PyIterator = agen.asend(py.None()).unwrap().downcast().unwrap();
+            let value = expect_return(step.send(py.None()).unwrap());
+            assert_eq!(value.extract::<i32>().unwrap(), 1);
+            assert_eq!(agen.state().unwrap(), GeneratorState::Suspended);
+
+            let step: &PyIterator = agen
+                .athrow(PyValueError::new_err("stop"))
+                .unwrap()
+                .downcast()
+                .unwrap();
+            let err = step.send(py.None()).unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+            assert_eq!(agen.state().unwrap(), GeneratorState::Closed);
+
+            let agen: &PyAsyncGenerator = agen_fn.call0().unwrap().downcast().unwrap();
+            let step: &PyIterator = agen.aclose().unwrap().downcast().unwrap();
+            assert!(expect_return(step.send(py.None()).unwrap()).is_none());
+        })
+    }
+
+    #[test]
+    fn test_iterator_send() {
+        Python::with_gil(|py| {
+            let iter = PyIterator::from_object(py, py.eval("[1]", None, None).unwrap()).unwrap();
+            let value = expect_next(iter.send(py.None()).unwrap());
+            assert_eq!(value.extract::<i32>().unwrap(), 1);
+            assert!(expect_return(iter.send(py.None()).unwrap()).is_none());
+        })
+    }
+}
@@ -2,8 +2,18 @@
 //
 // based on Daniel Grunwald's https://github.com/dgrunwald/rust-cpython
 
-use crate::{ffi, AsPyPointer, IntoPyPointer, Py, PyAny, PyErr, PyNativeType, PyResult, Python};
-use crate::{PyDowncastError, PyTryFrom};
+#[cfg(feature = "macros")]
+use crate::callback::IntoPyCallbackOutput;
+#[cfg(feature = "macros")]
+use crate::err::panic_after_error;
+use crate::types::generator::send_value;
+use crate::types::PySendResult;
+use crate::{
+    ffi, AsPyPointer, Bound, IntoPyPointer, Py, PyAny, PyErr, PyNativeType, PyResult, Python,
+};
+#[cfg(feature = "macros")]
+use crate::{IntoPy, PyObject};
+use crate::{PyDowncastError, PyTryFrom, ToPyObject};
 
 /// A Python iterator object.
 ///
@@ -40,6 +50,63 @@ impl PyIterator {
     {
         unsafe { py.from_owned_ptr_or_err(ffi::PyObject_GetIter(obj.as_ptr())) }
     }
+
+    /// Creates a Python iterator which lazily produces the items of a Rust iterator.
+    ///
+    /// Each item is converted to a Python object when Python requests it. Items which are `Err`
+    /// raise their error instead, after which iteration may continue. The iterator's
+    /// `__length_hint__` reports the lower bound of [`Iterator::size_hint`].
+    ///
+    /// To return a Rust iterator from a `#[pyfunction]`, wrap it in [`RustIterator`].
+    ///
+    /// This requires the `macros` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyIterator;
+    ///
+    /// # fn main() -> PyResult<()> {
+    /// Python::with_gil(|py| {
+    ///     let squares = PyIterator::from_rust(py, (1..=3).map(|x| x * x))?;
+    ///     let list: Vec<i32> = py.eval("list", None, None)?.call1((squares,))?.extract()?;
+    ///     assert_eq!(list, vec![1, 4, 9]);
+    ///     Ok(())
+    /// })
+    /// # }
+    /// ```
+    #[cfg(feature = "macros")]
+    pub fn from_rust<I>(py: Python<'_>, iter: I) -> PyResult<&PyIterator>
+    where
+        I: IntoIterator,
+        I::IntoIter: Send + 'static,
+        I::Item: IntoPyCallbackOutput<PyObject>,
+    {
+        let iterator = Py::new(
+            py,
+            RustIteratorObject {
+                iter: Some(Box::new(iter.into_iter())),
+            },
+        )?;
+        Ok(unsafe {
+            py.from_owned_ptr::<PyAny>(iterator.into_ptr())
+                .downcast_unchecked()
+        })
+    }
+
+    /// Advances the iterator by one step, sending it `value`.
+    ///
+    /// This works with iterators that implement `send`, such as generators and the awaitables
+    /// returned by asynchronous generators. For other iterators `value` must be `None`, which
+    /// behaves like `next()`. Unlike iteration, a finished iterator reports its return value
+    /// (`StopIteration.value`) as [`PySendResult::Return`].
+    pub fn send<V>(&self, value: V) -> PyResult<PySendResult<'_>>
+    where
+        V: ToPyObject,
+    {
+        send_value(self, value)
+    }
 }
 
 impl<'p> Iterator for &'p PyIterator {
@@ -61,6 +128,144 @@ impl<'p> Iterator for &'p PyIterator {
     }
 }
 
+impl<'py> Iterator for Bound<'py, PyIterator> {
+    type Item = PyResult<Bound<'py, PyAny>>;
+
+    /// Retrieves the next item from an iterator.
+    ///
+    /// Returns `None` when the iterator is exhausted.
+    /// If an exception occurs, returns `Some(Err(..))`.
+    /// Further `next()` calls after an exception occurs are likely
+    /// to repeatedly result in the same exception.
+    fn next(&mut self) -> Option<Self::Item> {
+        let py = self.py();
+
+        let ptr = unsafe { ffi::PyIter_Next(self.as_ptr()) };
+        if ptr.is_null() {
+            PyErr::take(py).map(Err)
+        } else {
+            Some(Ok(unsafe { Bound::from_owned_ptr(py, ptr) }))
+        }
+    }
+}
+
+impl<'py> std::ops::Deref for Bound<'py, PyIterator> {
+    type Target = PyIterator;
+
+    #[inline]
+    fn deref(&self) -> &PyIterator {
+        let any = self.as_ptr() as *const PyAny;
+        unsafe { PyNativeType::unchecked_downcast(&*any) }
+    }
+}
+
+/// The type-erased Rust iterator of a [`PyIterator::from_rust`] iterator.
+#[cfg(feature = "macros")]
+trait DynIterator: Send {
+    fn next(&mut self, py: Python<'_>) -> Option<PyResult<PyObject>>;
+
+    fn length_hint(&self) -> usize;
+}
+
+#[cfg(feature = "macros")]
+impl<I> DynIterator for I
+where
+    I: Iterator + Send,
+    I::Item: IntoPyCallbackOutput<PyObject>,
+{
+    fn next(&mut self, py: Python<'_>) -> Option<PyResult<PyObject>> {
+        Iterator::next(self).map(|item| item.convert(py))
+    }
+
+    fn length_hint(&self) -> usize {
+        self.size_hint().0
+    }
+}
+
+/// The Python iterator created by [`PyIterator::from_rust`].
+///
+/// If the Rust iterator advances its own Python iterator, the nested call fails because the
+/// object is already mutably borrowed.
+#[cfg(feature = "macros")]
+#[crate::pyclass(crate = "crate", name = "RustIterator", module = "pyo3")]
+struct RustIteratorObject {
+    /// Dropped once exhausted, so that it is not polled again.
+    iter: Option<Box<dyn DynIterator>>,
+}
+
+#[cfg(feature = "macros")]
+#[crate::pymethods(crate = "crate")]
+impl RustIteratorObject {
+    fn __iter__(slf: crate::PyRef<'_, Self>) -> crate::PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let item = self.iter.as_mut().and_then(|iter| iter.next(py));
+        if item.is_none() {
+            self.iter = None;
+        }
+        item.transpose()
+    }
+
+    fn __length_hint__(&self) -> usize {
+        self.iter.as_ref().map_or(0, |iter| iter.length_hint())
+    }
+}
+
+/// Wraps a Rust iterator so that it converts into a Python iterator.
+///
+/// This allows returning lazy sequences from `#[pyfunction]`s and `#[pymethods]` without
+/// writing a `#[pyclass]`. See [`PyIterator::from_rust`] for how items are converted.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::types::RustIterator;
+///
+/// #[pyfunction]
+/// fn countdown(from: u32) -> RustIterator<impl Iterator<Item = u32> + Send> {
+///     RustIterator((0..=from).rev())
+/// }
+/// #
+/// # Python::with_gil(|py| {
+/// #     let countdown = wrap_pyfunction!(countdown, py).unwrap();
+/// #     let values: Vec<u32> = py
+/// #         .eval("list", None, None)
+/// #         .unwrap()
+/// #         .call1((countdown.call1((3,)).unwrap(),))
+/// #         .unwrap()
+/// #         .extract()
+/// #         .unwrap();
+/// #     assert_eq!(values, vec![3, 2, 1, 0]);
+/// # });
+/// ```
+#[cfg(feature = "macros")]
+#[derive(Clone, Debug)]
+pub struct RustIterator<I>(pub I);
+
+#[cfg(feature = "macros")]
+impl<I> IntoPy<PyObject> for RustIterator<I>
+where
+    I: IntoIterator,
+    I::IntoIter: Send + 'static,
+    I::Item: IntoPyCallbackOutput<PyObject>,
+{
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        let iterator = RustIteratorObject {
+            iter: Some(Box::new(self.0.into_iter())),
+        };
+        match Py::new(py, iterator) {
+            Ok(iterator) => iterator.into_py(py),
+            Err(err) => {
+                err.restore(py);
+                panic_after_error(py)
+            }
+        }
+    }
+}
+
 // PyIter_Check does not exist in the limited API until 3.8
 impl<'v> PyTryFrom<'v> for PyIterator {
     fn try_from<V: Into<&'v PyAny>>(value: V) -> Result<&'v PyIterator, PyDowncastError<'v>> {
@@ -104,11 +309,87 @@ impl Py<PyIterator> {
 #[cfg(test)]
 mod tests {
     use super::PyIterator;
+    #[cfg(feature = "macros")]
+    use super::RustIterator;
     use crate::exceptions::PyTypeError;
     use crate::gil::GILPool;
     use crate::types::{PyDict, PyList};
+    #[cfg(feature = "macros")]
+    use crate::{IntoPy, PyObject, PyResult};
     use crate::{Py, PyAny, Python, ToPyObject};
 
+    #[test]
+    #[cfg(feature = "macros")]
+    fn from_rust_iter() {
+        Python::with_gil(|py| {
+            let mut iter = PyIterator::from_rust(py, vec![1, 2, 3]).unwrap();
+            assert_eq!(
+                iter.call_method0("__length_hint__")
+                    .unwrap()
+                    .extract::<usize>()
+                    .unwrap(),
+                3
+            );
+            let values: Vec<i32> = iter.map(|item| item.unwrap().extract().unwrap()).collect();
+            assert_eq!(values, vec![1, 2, 3]);
+            assert_eq!(
+                iter.call_method0("__length_hint__")
+                    .unwrap()
+                    .extract::<usize>()
+                    .unwrap(),
+                0
+            );
+            assert!(iter.next().is_none());
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn from_rust_iter_errors() {
+        Python::with_gil(|py| {
+            let items: Vec<PyResult<&'static str>> =
+                vec![Ok("a"), Err(PyTypeError::new_err("bad item")), Ok("c")];
+            let mut iter = PyIterator::from_rust(py, items).unwrap();
+            assert_eq!(
+                iter.next().unwrap().unwrap().extract::<&str>().unwrap(),
+                "a"
+            );
+            let err = iter.next().unwrap().unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+            assert_eq!(
+                iter.next().unwrap().unwrap().extract::<&str>().unwrap(),
+                "c"
+            );
+            assert!(iter.next().is_none());
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn from_rust_iter_in_python() {
+        Python::with_gil(|py| {
+            let iter = PyIterator::from_rust(py, (0..5).filter(|x| x % 2 == 0)).unwrap();
+            let locals = PyDict::new(py);
+            locals.set_item("it", iter).unwrap();
+            py.run(
+                "assert iter(it) is it\nassert [x * 10 for x in it] == [0, 20, 40]",
+                None,
+                Some(locals),
+            )
+            .unwrap();
+
+            let wrapped: PyObject = RustIterator(vec!["x", "x"]).into_py(py);
+            let joined: String = py
+                .eval("''.join", None, None)
+                .unwrap()
+                .call1((wrapped,))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(joined, "xx");
+        });
+    }
+
     #[test]
     fn vec_iter() {
         Python::with_gil(|py| {
@@ -8,7 +8,7 @@ use crate::err::{self, PyResult};
 use crate::ffi::{self, Py_ssize_t};
 use crate::internal_tricks::get_ssize_index;
 use crate::types::{PySequence, PyTuple};
-use crate::{AsPyPointer, IntoPyPointer, Py, PyAny, PyObject, Python, ToPyObject};
+use crate::{AsPyPointer, Bound, IntoPyPointer, Py, PyAny, PyObject, Python, ToPyObject};
 
 /// Represents a Python `list`.
 #[repr(transparent)]
@@ -95,6 +95,27 @@ impl PyList {
         unsafe { py.from_owned_ptr::<PyList>(ffi::PyList_New(0)) }
     }
 
+    /// Constructs a new list with the given elements, returned as a [`Bound`].
+    ///
+    /// See [`PyList::new`].
+    #[track_caller]
+    pub fn new_bound<T, U>(
+        py: Python<'_>,
+        elements: impl IntoIterator<Item = T, IntoIter = U>,
+    ) -> Bound<'_, PyList>
+    where
+        T: ToPyObject,
+        U: ExactSizeIterator<Item = T>,
+    {
+        let mut iter = elements.into_iter().map(|e| e.to_object(py));
+        new_from_iter(py, &mut iter).into_bound(py)
+    }
+
+    /// Constructs a new empty list, returned as a [`Bound`].
+    pub fn empty_bound(py: Python<'_>) -> Bound<'_, PyList> {
+        unsafe { Bound::from_owned_ptr(py, ffi::PyList_New(0)) }
+    }
+
     /// Returns the length of the list.
     pub fn len(&self) -> usize {
         unsafe {
@@ -346,6 +367,97 @@ impl<'a> std::iter::IntoIterator for &'a PyList {
     }
 }
 
+impl<'py> Bound<'py, PyList> {
+    /// Gets the list item at the specified index.
+    ///
+    /// See [`PyList::get_item`].
+    pub fn get_item(&self, index: usize) -> PyResult<Bound<'py, PyAny>> {
+        unsafe {
+            let item = ffi::PyList_GetItem(self.as_ptr(), index as Py_ssize_t);
+            // PyList_GetItem return borrowed ptr; must make owned for safety (see #890).
+            ffi::Py_XINCREF(item);
+            Bound::from_owned_ptr_or_err(self.py(), item)
+        }
+    }
+
+    /// Gets the list item at the specified index. Undefined behavior on bad index. Use with caution.
+    ///
+    /// # Safety
+    ///
+    /// Caller must verify that the index is within the bounds of the list.
+    #[cfg(not(Py_LIMITED_API))]
+    pub unsafe fn get_item_unchecked(&self, index: usize) -> Bound<'py, PyAny> {
+        let item = ffi::PyList_GET_ITEM(self.as_ptr(), index as Py_ssize_t);
+        // PyList_GET_ITEM return borrowed ptr; must make owned for safety (see #890).
+        Bound::from_borrowed_ptr(self.py(), item)
+    }
+
+    /// Returns an iterator over this list's items.
+    pub fn iter(&self) -> BoundListIterator<'py> {
+        BoundListIterator {
+            list: self.clone(),
+            index: 0,
+        }
+    }
+}
+
+/// Used by `Bound<PyList>::iter()`.
+pub struct BoundListIterator<'py> {
+    list: Bound<'py, PyList>,
+    index: usize,
+}
+
+impl<'py> Iterator for BoundListIterator<'py> {
+    type Item = Bound<'py, PyAny>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Bound<'py, PyAny>> {
+        if self.index < self.list.len() {
+            #[cfg(any(Py_LIMITED_API, PyPy))]
+            let item = self.list.get_item(self.index).expect("list.get failed");
+            #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+            let item = unsafe { self.list.get_item_unchecked(self.index) };
+            self.index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for BoundListIterator<'_> {
+    fn len(&self) -> usize {
+        self.list.len().saturating_sub(self.index)
+    }
+}
+
+impl<'py> IntoIterator for Bound<'py, PyList> {
+    type Item = Bound<'py, PyAny>;
+    type IntoIter = BoundListIterator<'py>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BoundListIterator {
+            list: self,
+            index: 0,
+        }
+    }
+}
+
+impl<'py> IntoIterator for &Bound<'py, PyList> {
+    type Item = Bound<'py, PyAny>;
+    type IntoIter = BoundListIterator<'py>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::types::{PyList, PyTuple};
@@ -525,6 +637,28 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_bound() {
+        Python::with_gil(|py| {
+            let list = PyList::new_bound(py, [1, 2, 3, 4]);
+            assert_eq!(list.len(), 4);
+            assert_eq!(list.get_item(1).unwrap().extract::<i32>().unwrap(), 2);
+            assert!(list.get_item(4).is_err());
+
+            let mut iter = list.iter();
+            assert_eq!(iter.size_hint(), (4, Some(4)));
+            iter.next();
+            assert_eq!(iter.len(), 3);
+            let items: Vec<i32> = (&list)
+                .into_iter()
+                .map(|item| item.extract().unwrap())
+                .collect();
+            assert_eq!(items, [1, 2, 3, 4]);
+            assert_eq!(list.into_iter().count(), 4);
+            assert!(PyList::empty_bound(py).is_empty());
+        });
+    }
+
     #[test]
     fn test_extract() {
         Python::with_gil(|py| {
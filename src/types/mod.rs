@@ -8,7 +8,7 @@ pub use self::bytearray::PyByteArray;
 pub use self::bytes::PyBytes;
 pub use self::capsule::PyCapsule;
 #[cfg(not(Py_LIMITED_API))]
-pub use self::code::PyCode;
+pub use self::code::{CompileFlags, CompileMode, PyCode};
 pub use self::complex::PyComplex;
 #[cfg(not(Py_LIMITED_API))]
 pub use self::datetime::{
@@ -16,8 +16,8 @@ pub use self::datetime::{
     PyTzInfo, PyTzInfoAccess,
 };
 pub use self::dict::{IntoPyDict, PyDict};
-#[cfg(not(PyPy))]
 pub use self::dict::{PyDictItems, PyDictKeys, PyDictValues};
+pub use self::ellipsis::PyEllipsis;
 pub use self::floatob::PyFloat;
 #[cfg(all(not(Py_LIMITED_API), not(PyPy)))]
 pub use self::frame::PyFrame;
@@ -25,22 +25,31 @@ pub use self::frozenset::PyFrozenSet;
 pub use self::function::PyCFunction;
 #[cfg(all(not(Py_LIMITED_API), not(PyPy)))]
 pub use self::function::PyFunction;
+pub use self::generator::{
+    GeneratorState, PyAsyncGenerator, PyCoroutine, PyGenerator, PySendResult,
+};
 pub use self::iterator::PyIterator;
+#[cfg(feature = "macros")]
+pub use self::iterator::RustIterator;
 pub use self::list::PyList;
 pub use self::mapping::PyMapping;
 pub use self::module::PyModule;
-pub use self::num::PyLong;
+pub use self::none::PyNone;
+pub use self::notimplemented::PyNotImplemented;
 pub use self::num::PyLong as PyInt;
+pub use self::num::{Endianness, PyLong};
 #[cfg(not(PyPy))]
 pub use self::pysuper::PySuper;
+pub use self::range::PyRange;
 pub use self::sequence::PySequence;
 pub use self::set::PySet;
 pub use self::slice::{PySlice, PySliceIndices};
 #[cfg(not(Py_LIMITED_API))]
 pub use self::string::PyStringData;
-pub use self::string::{PyString, PyString as PyUnicode};
+pub use self::string::{PyString, PyString as PyUnicode, PyStringWriter};
 pub use self::traceback::PyTraceback;
 pub use self::tuple::PyTuple;
+pub use self::typed::{PyDictOf, PyListOf, PySequenceOf, PyTupleOf};
 pub use self::typeobject::PyType;
 
 /// Iteration over Python collections.
@@ -74,9 +83,16 @@ pub use self::typeobject::PyType;
 /// the Limited API and PyPy, the underlying structures are opaque and that may not be possible.
 /// In these cases the iterators are implemented by forwarding to [`PyIterator`].
 pub mod iter {
-    pub use super::dict::PyDictIterator;
+    pub use super::dict::{
+        BoundDictIterator, PyDictIterator, PyDictTypedIterator, PyDictViewIterator,
+    };
     pub use super::frozenset::PyFrozenSetIterator;
+    pub use super::list::BoundListIterator;
+    pub use super::range::PyRangeIterator;
     pub use super::set::PySetIterator;
+    pub use super::traceback::PyTracebackIterator;
+    pub use super::tuple::BoundTupleIterator;
+    pub use super::typed::{PyDictOfIterator, PyTypedSequenceIterator};
 }
 
 // Implementations core to all native types
@@ -281,22 +297,28 @@ mod complex;
 #[cfg(not(Py_LIMITED_API))]
 mod datetime;
 mod dict;
+mod ellipsis;
 mod floatob;
 #[cfg(all(not(Py_LIMITED_API), not(PyPy)))]
 mod frame;
 mod frozenset;
 mod function;
+mod generator;
 mod iterator;
 pub(crate) mod list;
 mod mapping;
 mod module;
+mod none;
+mod notimplemented;
 mod num;
 #[cfg(not(PyPy))]
 mod pysuper;
+mod range;
 mod sequence;
 pub(crate) mod set;
 mod slice;
 mod string;
 mod traceback;
 mod tuple;
+mod typed;
 mod typeobject;
@@ -0,0 +1,54 @@
+use crate::{ffi, PyAny, Python};
+use std::os::raw::c_int;
+
+/// Represents the Python `None` object.
+#[repr(transparent)]
+pub struct PyNone(PyAny);
+
+pyobject_native_type_core!(
+    PyNone,
+    *ffi::Py_TYPE(ffi::Py_None()),
+    #checkfunction=is_none
+);
+
+impl PyNone {
+    /// Returns the `None` object.
+    ///
+    /// Unlike [`Python::None`], this does not need to touch the reference count, because `None`
+    /// is a statically allocated singleton.
+    #[inline]
+    pub fn get(_py: Python<'_>) -> &PyNone {
+        unsafe { &*(ffi::Py_None() as *const PyNone) }
+    }
+}
+
+#[inline]
+unsafe fn is_none(op: *mut ffi::PyObject) -> c_int {
+    (op == ffi::Py_None()) as c_int
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{PyDict, PyNone};
+    use crate::{PyTypeInfo, Python};
+
+    #[test]
+    fn test_none() {
+        Python::with_gil(|py| {
+            let none = PyNone::get(py);
+            assert!(none.is_none());
+            assert!(none.is(&py.None()));
+            assert_eq!(none.to_string(), "None");
+            assert!(none.get_type().is(PyNone::type_object(py)));
+        })
+    }
+
+    #[test]
+    fn test_none_downcast() {
+        Python::with_gil(|py| {
+            assert!(py.None().as_ref(py).downcast::<PyNone>().is_ok());
+            assert!(py.Ellipsis().as_ref(py).downcast::<PyNone>().is_err());
+            assert!(PyDict::new(py).downcast::<PyNone>().is_err());
+        })
+    }
+}
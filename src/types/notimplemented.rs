@@ -0,0 +1,56 @@
+use crate::{ffi, PyAny, Python};
+use std::os::raw::c_int;
+
+/// Represents the Python `NotImplemented` object.
+#[repr(transparent)]
+pub struct PyNotImplemented(PyAny);
+
+pyobject_native_type_core!(
+    PyNotImplemented,
+    *ffi::Py_TYPE(ffi::Py_NotImplemented()),
+    #checkfunction=is_not_implemented
+);
+
+impl PyNotImplemented {
+    /// Returns the `NotImplemented` object.
+    #[inline]
+    pub fn get(_py: Python<'_>) -> &PyNotImplemented {
+        unsafe { &*(ffi::Py_NotImplemented() as *const PyNotImplemented) }
+    }
+}
+
+#[inline]
+unsafe fn is_not_implemented(op: *mut ffi::PyObject) -> c_int {
+    (op == ffi::Py_NotImplemented()) as c_int
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{PyDict, PyNotImplemented};
+    use crate::{PyTypeInfo, Python};
+
+    #[test]
+    fn test_notimplemented() {
+        Python::with_gil(|py| {
+            let not_implemented = PyNotImplemented::get(py);
+            assert!(not_implemented.is(&py.NotImplemented()));
+            assert_eq!(not_implemented.to_string(), "NotImplemented");
+            assert!(not_implemented
+                .get_type()
+                .is(PyNotImplemented::type_object(py)));
+        })
+    }
+
+    #[test]
+    fn test_notimplemented_downcast() {
+        Python::with_gil(|py| {
+            assert!(py
+                .NotImplemented()
+                .as_ref(py)
+                .downcast::<PyNotImplemented>()
+                .is_ok());
+            assert!(py.None().as_ref(py).downcast::<PyNotImplemented>().is_err());
+            assert!(PyDict::new(py).downcast::<PyNotImplemented>().is_err());
+        })
+    }
+}
@@ -2,7 +2,13 @@
 //
 // based on Daniel Grunwald's https://github.com/dgrunwald/rust-cpython
 
-use crate::{ffi, PyAny};
+use crate::err::{PyErr, PyResult};
+#[cfg(Py_LIMITED_API)]
+use crate::types::{PyBytes, PyDict};
+#[cfg(Py_LIMITED_API)]
+use crate::IntoPyPointer;
+use crate::{ffi, AsPyPointer, Bound, FromPyObject, PyAny, Python, ToPyObject};
+use std::cmp::Ordering;
 
 /// Represents a Python `int` object.
 ///
@@ -10,7 +16,616 @@ use crate::{ffi, PyAny};
 /// by using [`ToPyObject`](crate::conversion::ToPyObject)
 /// and [`extract`](PyAny::extract)
 /// with the primitive Rust integer types.
+///
+/// For integers which do not fit into a primitive type, `PyLong` offers conversion to and from
+/// byte arrays, along with a few arithmetic helpers, without needing the `num-bigint` feature.
 #[repr(transparent)]
 pub struct PyLong(PyAny);
 
 pyobject_native_type_core!(PyLong, ffi::PyLong_Type, #checkfunction=ffi::PyLong_Check);
+
+/// Byte order used when converting a [`PyLong`] to or from bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    /// The least significant byte comes first.
+    Little,
+    /// The most significant byte comes first.
+    Big,
+}
+
+impl Endianness {
+    /// The byte order of the target platform.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Endianness = Endianness::Little;
+    /// The byte order of the target platform.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Endianness = Endianness::Big;
+
+    #[cfg(Py_LIMITED_API)]
+    fn as_str(self) -> &'static str {
+        match self {
+            Endianness::Little => "little",
+            Endianness::Big => "big",
+        }
+    }
+}
+
+macro_rules! binary_op {
+    ($(#[$meta:meta])* $name:ident, $ffi_fn:path) => {
+        $(#[$meta])*
+        pub fn $name<V>(&self, other: V) -> PyResult<&PyLong>
+        where
+            V: ToPyObject,
+        {
+            let py = self.py();
+            let other = other.to_object(py);
+            let result: &PyAny =
+                unsafe { py.from_owned_ptr_or_err($ffi_fn(self.as_ptr(), other.as_ptr()))? };
+            result.downcast().map_err(PyErr::from)
+        }
+    };
+}
+
+impl PyLong {
+    /// Converts `ob` to an integer using its `__index__` method, like `operator.index(ob)`.
+    ///
+    /// The result is not registered in the GIL pool, so that conversions calling this in a loop
+    /// don't accumulate temporaries.
+    pub(crate) fn index(ob: &PyAny) -> PyResult<Bound<'_, PyLong>> {
+        unsafe { Bound::from_owned_ptr_or_err(ob.py(), ffi::PyNumber_Index(ob.as_ptr())) }
+    }
+
+    /// Creates an integer from its representation as bytes.
+    ///
+    /// If `signed` is true, `bytes` are interpreted as a two's complement number. This is
+    /// equivalent to the Python expression `int.from_bytes(bytes, endianness, signed=signed)`.
+    pub fn from_bytes<'py>(
+        py: Python<'py>,
+        bytes: &[u8],
+        endianness: Endianness,
+        signed: bool,
+    ) -> PyResult<&'py PyLong> {
+        unsafe { py.from_owned_ptr_or_err(Self::from_bytes_ptr(py, bytes, endianness, signed)) }
+    }
+
+    /// Like [`PyLong::from_bytes`], but returns an owned pointer instead of registering the
+    /// result in the GIL pool, for use in conversions.
+    ///
+    /// On failure the error is set and the returned pointer is null.
+    pub(crate) fn from_bytes_ptr(
+        py: Python<'_>,
+        bytes: &[u8],
+        endianness: Endianness,
+        signed: bool,
+    ) -> *mut ffi::PyObject {
+        #[cfg(not(Py_LIMITED_API))]
+        unsafe {
+            let _ = py;
+            ffi::_PyLong_FromByteArray(
+                bytes.as_ptr(),
+                bytes.len(),
+                (endianness == Endianness::Little) as std::os::raw::c_int,
+                signed as std::os::raw::c_int,
+            )
+        }
+        #[cfg(Py_LIMITED_API)]
+        {
+            if bytes.len() <= 8 {
+                // Small values are assembled in Rust, avoiding a call to `int.from_bytes`.
+                let mut buffer = [0; 8];
+                match endianness {
+                    Endianness::Little => buffer[..bytes.len()].copy_from_slice(bytes),
+                    Endianness::Big => {
+                        for (dst, src) in buffer.iter_mut().zip(bytes.iter().rev()) {
+                            *dst = *src;
+                        }
+                    }
+                }
+                let negative = signed && buffer[bytes.len().saturating_sub(1)] & 0x80 != 0;
+                return if negative {
+                    for byte in &mut buffer[bytes.len()..] {
+                        *byte = 0xff;
+                    }
+                    unsafe { ffi::PyLong_FromLongLong(i64::from_le_bytes(buffer)) }
+                } else {
+                    unsafe { ffi::PyLong_FromUnsignedLongLong(u64::from_le_bytes(buffer)) }
+                };
+            }
+            match Self::from_bytes_call(py, bytes, endianness, signed) {
+                Ok(result) => result.into_ptr(),
+                Err(err) => {
+                    err.restore(py);
+                    std::ptr::null_mut()
+                }
+            }
+        }
+    }
+
+    /// Calls `int.from_bytes`, for values which don't fit into 64 bits.
+    #[cfg(Py_LIMITED_API)]
+    fn from_bytes_call<'py>(
+        py: Python<'py>,
+        bytes: &[u8],
+        endianness: Endianness,
+        signed: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let kwargs = PyDict::new_bound(py);
+        kwargs.set_item(crate::intern!(py, "signed"), signed)?;
+        let bytes: Bound<'_, PyBytes> = unsafe {
+            Bound::from_owned_ptr_or_err(
+                py,
+                ffi::PyBytes_FromStringAndSize(
+                    bytes.as_ptr().cast(),
+                    bytes.len() as ffi::Py_ssize_t,
+                ),
+            )?
+        };
+        Bound::from(py.get_type::<PyLong>()).call_method(
+            crate::intern!(py, "from_bytes"),
+            (bytes, endianness.as_str()),
+            Some(&kwargs),
+        )
+    }
+
+    /// Converts the integer into the shortest byte representation which holds it.
+    ///
+    /// If `signed` is true, the result is in two's complement and includes a sign bit; otherwise
+    /// negative integers fail with `OverflowError`. Zero is represented by a single byte.
+    pub fn to_bytes(&self, endianness: Endianness, signed: bool) -> PyResult<Vec<u8>> {
+        let negative = self.sign() == Ordering::Less;
+        let len = if signed {
+            // The magnitude of a negative number `n` in two's complement is the magnitude of `!n`.
+            let magnitude = if negative { self.invert()? } else { self };
+            magnitude.bit_length()? / 8 + 1
+        } else if negative {
+            return Err(crate::exceptions::PyOverflowError::new_err(
+                "can't convert negative int to unsigned",
+            ));
+        } else {
+            self.bit_length()?.saturating_sub(1) / 8 + 1
+        };
+        let mut buffer = vec![0; len];
+        self.to_bytes_into(&mut buffer, endianness, signed)?;
+        Ok(buffer)
+    }
+
+    /// Writes the integer into `buffer`, using exactly `buffer.len()` bytes.
+    ///
+    /// Fails with `OverflowError` if the integer does not fit. This is equivalent to the Python
+    /// expression `self.to_bytes(len(buffer), endianness, signed=signed)`.
+    pub fn to_bytes_into(
+        &self,
+        buffer: &mut [u8],
+        endianness: Endianness,
+        signed: bool,
+    ) -> PyResult<()> {
+        let py = self.py();
+        #[cfg(not(Py_LIMITED_API))]
+        unsafe {
+            crate::err::error_on_minusone(
+                py,
+                ffi::_PyLong_AsByteArray(
+                    self.as_ptr() as *mut ffi::PyLongObject,
+                    buffer.as_mut_ptr(),
+                    buffer.len(),
+                    (endianness == Endianness::Little) as std::os::raw::c_int,
+                    signed as std::os::raw::c_int,
+                ),
+            )
+        }
+        #[cfg(Py_LIMITED_API)]
+        {
+            if buffer.len() >= 8 {
+                // Values which fit into an `i64` are converted without calling `int.to_bytes`.
+                let mut overflow = 0;
+                let value =
+                    unsafe { ffi::PyLong_AsLongLongAndOverflow(self.as_ptr(), &mut overflow) };
+                if value == -1 {
+                    if let Some(err) = PyErr::take(py) {
+                        return Err(err);
+                    }
+                }
+                if overflow == 0 && (signed || value >= 0) {
+                    let fill = if value < 0 { 0xff } else { 0 };
+                    buffer[..8].copy_from_slice(&value.to_le_bytes());
+                    for byte in &mut buffer[8..] {
+                        *byte = fill;
+                    }
+                    if endianness == Endianness::Big {
+                        buffer.reverse();
+                    }
+                    return Ok(());
+                }
+            }
+            let kwargs = PyDict::new_bound(py);
+            kwargs.set_item(crate::intern!(py, "signed"), signed)?;
+            let bytes = Bound::from(self).call_method(
+                crate::intern!(py, "to_bytes"),
+                (buffer.len(), endianness.as_str()),
+                Some(&kwargs),
+            )?;
+            buffer.copy_from_slice(bytes.downcast::<PyBytes>()?.as_bytes());
+            Ok(())
+        }
+    }
+
+    /// Returns the number of bits needed to represent the absolute value of the integer.
+    ///
+    /// This is equivalent to the Python expression `self.bit_length()`.
+    pub fn bit_length(&self) -> PyResult<usize> {
+        #[cfg(not(Py_LIMITED_API))]
+        {
+            let bits = unsafe { ffi::_PyLong_NumBits(self.as_ptr()) };
+            if bits == usize::MAX {
+                if let Some(err) = PyErr::take(self.py()) {
+                    return Err(err);
+                }
+            }
+            Ok(bits)
+        }
+        #[cfg(Py_LIMITED_API)]
+        {
+            self.call_method0(crate::intern!(self.py(), "bit_length"))?
+                .extract()
+        }
+    }
+
+    /// Returns how the integer compares to zero.
+    pub fn sign(&self) -> Ordering {
+        #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+        {
+            unsafe { ffi::_PyLong_Sign(self.as_ptr()) }.cmp(&0)
+        }
+        #[cfg(any(Py_LIMITED_API, PyPy))]
+        {
+            let mut overflow = 0;
+            let value = unsafe { ffi::PyLong_AsLongLongAndOverflow(self.as_ptr(), &mut overflow) };
+            // This cannot fail for an `int`, and `overflow` holds the sign if it does not fit.
+            if overflow != 0 {
+                overflow.cmp(&0)
+            } else {
+                value.cmp(&0)
+            }
+        }
+    }
+
+    /// Checks if the integer is zero.
+    pub fn is_zero(&self) -> bool {
+        self.sign() == Ordering::Equal
+    }
+
+    /// Converts the integer into `T`, returning `None` if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pyo3::prelude::*;
+    /// use pyo3::types::PyLong;
+    ///
+    /// Python::with_gil(|py| {
+    ///     let big: &PyLong = py.eval("2 ** 100", None, None).unwrap().downcast().unwrap();
+    ///     assert_eq!(big.checked_to::<i128>(), Some(1 << 100));
+    ///     assert_eq!(big.checked_to::<u64>(), None);
+    /// });
+    /// ```
+    pub fn checked_to<T>(&self) -> Option<T>
+    where
+        T: for<'a> FromPyObject<'a>,
+    {
+        self.extract().ok()
+    }
+
+    binary_op!(
+        /// Computes `self + other`.
+        add,
+        ffi::PyNumber_Add
+    );
+    binary_op!(
+        /// Computes `self - other`.
+        sub,
+        ffi::PyNumber_Subtract
+    );
+    binary_op!(
+        /// Computes `self * other`.
+        mul,
+        ffi::PyNumber_Multiply
+    );
+    binary_op!(
+        /// Computes `self // other`, rounding towards negative infinity.
+        ///
+        /// Fails with `ZeroDivisionError` if `other` is zero.
+        floor_div,
+        ffi::PyNumber_FloorDivide
+    );
+    binary_op!(
+        /// Computes `self % other`, which has the same sign as `other`.
+        ///
+        /// Fails with `ZeroDivisionError` if `other` is zero.
+        rem,
+        ffi::PyNumber_Remainder
+    );
+    binary_op!(
+        /// Computes `self & other`.
+        bitand,
+        ffi::PyNumber_And
+    );
+    binary_op!(
+        /// Computes `self | other`.
+        bitor,
+        ffi::PyNumber_Or
+    );
+    binary_op!(
+        /// Computes `self ^ other`.
+        bitxor,
+        ffi::PyNumber_Xor
+    );
+
+    /// Computes `self ** exp`.
+    pub fn pow(&self, exp: u32) -> PyResult<&PyLong> {
+        let py = self.py();
+        let exp = exp.to_object(py);
+        let result: &PyAny = unsafe {
+            py.from_owned_ptr_or_err(ffi::PyNumber_Power(
+                self.as_ptr(),
+                exp.as_ptr(),
+                ffi::Py_None(),
+            ))?
+        };
+        result.downcast().map_err(PyErr::from)
+    }
+
+    /// Computes `self << bits`.
+    pub fn shl(&self, bits: usize) -> PyResult<&PyLong> {
+        self.shift(bits, ffi::PyNumber_Lshift)
+    }
+
+    /// Computes `self >> bits`, rounding towards negative infinity.
+    pub fn shr(&self, bits: usize) -> PyResult<&PyLong> {
+        self.shift(bits, ffi::PyNumber_Rshift)
+    }
+
+    /// Computes `-self`.
+    pub fn neg(&self) -> PyResult<&PyLong> {
+        self.unary(ffi::PyNumber_Negative)
+    }
+
+    /// Computes `abs(self)`.
+    pub fn abs(&self) -> PyResult<&PyLong> {
+        self.unary(ffi::PyNumber_Absolute)
+    }
+
+    /// Computes `~self`, which equals `-self - 1`.
+    pub fn invert(&self) -> PyResult<&PyLong> {
+        self.unary(ffi::PyNumber_Invert)
+    }
+
+    fn shift(
+        &self,
+        bits: usize,
+        op: unsafe extern "C" fn(*mut ffi::PyObject, *mut ffi::PyObject) -> *mut ffi::PyObject,
+    ) -> PyResult<&PyLong> {
+        let py = self.py();
+        let bits = bits.to_object(py);
+        let result: &PyAny = unsafe { py.from_owned_ptr_or_err(op(self.as_ptr(), bits.as_ptr()))? };
+        result.downcast().map_err(PyErr::from)
+    }
+
+    fn unary(
+        &self,
+        op: unsafe extern "C" fn(*mut ffi::PyObject) -> *mut ffi::PyObject,
+    ) -> PyResult<&PyLong> {
+        let py = self.py();
+        let result: &PyAny = unsafe { py.from_owned_ptr_or_err(op(self.as_ptr()))? };
+        result.downcast().map_err(PyErr::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Endianness, PyLong};
+    use crate::Python;
+    use std::cmp::Ordering;
+
+    fn eval<'py>(py: Python<'py>, code: &str) -> &'py PyLong {
+        py.eval(code, None, None).unwrap().downcast().unwrap()
+    }
+
+    #[test]
+    fn test_sign_and_is_zero() {
+        Python::with_gil(|py| {
+            assert_eq!(eval(py, "0").sign(), Ordering::Equal);
+            assert!(eval(py, "0").is_zero());
+            assert_eq!(eval(py, "5").sign(), Ordering::Greater);
+            assert_eq!(eval(py, "-5").sign(), Ordering::Less);
+            assert_eq!(eval(py, "2 ** 200").sign(), Ordering::Greater);
+            assert_eq!(eval(py, "-(2 ** 200)").sign(), Ordering::Less);
+            assert!(!eval(py, "-(2 ** 200)").is_zero());
+        });
+    }
+
+    #[test]
+    fn test_bit_length() {
+        Python::with_gil(|py| {
+            assert_eq!(eval(py, "0").bit_length().unwrap(), 0);
+            assert_eq!(eval(py, "255").bit_length().unwrap(), 8);
+            assert_eq!(eval(py, "-256").bit_length().unwrap(), 9);
+            assert_eq!(eval(py, "2 ** 200").bit_length().unwrap(), 201);
+        });
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        Python::with_gil(|py| {
+            for &(bytes, endianness, signed, expected) in &[
+                (&[0x01, 0x02][..], Endianness::Little, false, "0x0201"),
+                (&[0x01, 0x02][..], Endianness::Big, false, "0x0102"),
+                (&[0xff][..], Endianness::Little, true, "-1"),
+                (&[0xff][..], Endianness::Little, false, "255"),
+                (&[][..], Endianness::Big, true, "0"),
+                (&[0xff; 8][..], Endianness::Big, false, "2 ** 64 - 1"),
+                (
+                    &[0x80; 9][..],
+                    Endianness::Big,
+                    true,
+                    "-0x7f7f7f7f7f7f7f7f80",
+                ),
+                (
+                    &[0x80; 9][..],
+                    Endianness::Big,
+                    false,
+                    "0x808080808080808080",
+                ),
+            ] {
+                let value = PyLong::from_bytes(py, bytes, endianness, signed).unwrap();
+                assert!(
+                    value.eq(eval(py, expected)).unwrap(),
+                    "{:?} != {}",
+                    value,
+                    expected
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        Python::with_gil(|py| {
+            let value = eval(py, "0x0102");
+            assert_eq!(
+                value.to_bytes(Endianness::Little, false).unwrap(),
+                [0x02, 0x01]
+            );
+            assert_eq!(
+                value.to_bytes(Endianness::Big, false).unwrap(),
+                [0x01, 0x02]
+            );
+            assert_eq!(eval(py, "0").to_bytes(Endianness::Big, true).unwrap(), [0]);
+            assert_eq!(eval(py, "0").to_bytes(Endianness::Big, false).unwrap(), [0]);
+            assert_eq!(
+                eval(py, "-1").to_bytes(Endianness::Big, true).unwrap(),
+                [0xff]
+            );
+            assert_eq!(
+                eval(py, "128").to_bytes(Endianness::Big, true).unwrap(),
+                [0, 0x80]
+            );
+            assert_eq!(
+                eval(py, "-128").to_bytes(Endianness::Big, true).unwrap(),
+                [0x80]
+            );
+            assert_eq!(
+                eval(py, "-129").to_bytes(Endianness::Big, true).unwrap(),
+                [0xff, 0x7f]
+            );
+
+            let err = eval(py, "-1").to_bytes(Endianness::Big, false).unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyOverflowError>(py));
+        });
+    }
+
+    #[test]
+    fn test_to_bytes_roundtrip() {
+        Python::with_gil(|py| {
+            for &code in &[
+                "2 ** 100 + 7",
+                "-(3 ** 80)",
+                "-(2 ** 63)",
+                "2 ** 64 - 1",
+                "42",
+            ] {
+                let value = eval(py, code);
+                for &endianness in &[Endianness::Little, Endianness::Big] {
+                    let bytes = value.to_bytes(endianness, true).unwrap();
+                    let back = PyLong::from_bytes(py, &bytes, endianness, true).unwrap();
+                    assert!(back.eq(value).unwrap(), "{}", code);
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn test_to_bytes_into_overflow() {
+        Python::with_gil(|py| {
+            let mut buffer = [0; 8];
+            let err = eval(py, "2 ** 64")
+                .to_bytes_into(&mut buffer, Endianness::Little, false)
+                .unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyOverflowError>(py));
+
+            let mut buffer = [0; 10];
+            eval(py, "-2")
+                .to_bytes_into(&mut buffer, Endianness::Big, true)
+                .unwrap();
+            assert_eq!(
+                buffer,
+                [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe]
+            );
+        });
+    }
+
+    #[test]
+    fn test_checked_to() {
+        Python::with_gil(|py| {
+            let big = eval(py, "2 ** 100");
+            assert_eq!(big.checked_to::<i128>(), Some(1 << 100));
+            assert_eq!(big.checked_to::<i64>(), None);
+            assert_eq!(eval(py, "-1").checked_to::<u128>(), None);
+            assert_eq!(eval(py, "-1").checked_to::<i8>(), Some(-1));
+        });
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        Python::with_gil(|py| {
+            let a = eval(py, "2 ** 100");
+            assert!(a.add(1).unwrap().eq(eval(py, "2 ** 100 + 1")).unwrap());
+            assert!(a.sub(a).unwrap().is_zero());
+            assert!(a.mul(a).unwrap().eq(eval(py, "2 ** 200")).unwrap());
+            assert_eq!(a.floor_div(-3).unwrap().sign(), Ordering::Less);
+            assert_eq!(eval(py, "-7").rem(3).unwrap().extract::<i32>().unwrap(), 2);
+            assert_eq!(
+                eval(py, "6").bitand(3).unwrap().extract::<i32>().unwrap(),
+                2
+            );
+            assert_eq!(eval(py, "6").bitor(3).unwrap().extract::<i32>().unwrap(), 7);
+            assert_eq!(
+                eval(py, "6").bitxor(3).unwrap().extract::<i32>().unwrap(),
+                5
+            );
+            assert!(eval(py, "2").pow(100).unwrap().eq(a).unwrap());
+            assert!(eval(py, "1").shl(100).unwrap().eq(a).unwrap());
+            assert_eq!(a.shr(99).unwrap().extract::<i32>().unwrap(), 2);
+            assert_eq!(a.neg().unwrap().sign(), Ordering::Less);
+            assert!(a.neg().unwrap().abs().unwrap().eq(a).unwrap());
+            assert_eq!(
+                eval(py, "5").invert().unwrap().extract::<i32>().unwrap(),
+                -6
+            );
+
+            let err = a.floor_div(0).unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyZeroDivisionError>(py));
+        });
+    }
+
+    #[test]
+    fn test_subclass_returning_non_int() {
+        Python::with_gil(|py| {
+            let value = eval(
+                py,
+                "type('Weird', (int,), {'__neg__': lambda self: 'neg', '__invert__': lambda self: None, '__lshift__': lambda self, n: 1.5, '__pow__': lambda self, *args: []})(-5)",
+            );
+            let assert_type_error = |result: crate::PyResult<&PyLong>| {
+                let err = result.unwrap_err();
+                assert!(err.is_instance_of::<crate::exceptions::PyTypeError>(py));
+            };
+            assert_type_error(value.neg());
+            assert_type_error(value.invert());
+            assert_type_error(value.shl(1));
+            assert_type_error(value.pow(2));
+            // `to_bytes` inverts negative values to find their length.
+            let err = value.to_bytes(Endianness::Little, true).unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyTypeError>(py));
+            assert_eq!(value.abs().unwrap().extract::<i32>().unwrap(), 5);
+        });
+    }
+}
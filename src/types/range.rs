@@ -0,0 +1,225 @@
+use crate::err::PyResult;
+use crate::exceptions::{PyOverflowError, PyValueError};
+use crate::ffi;
+use crate::{AsPyPointer, FromPyObject, PyAny, PyErr, Python};
+use std::ops::{Range, RangeInclusive};
+
+/// Represents a Python `range`.
+///
+/// Ranges can be extracted into [`std::ops::Range<i64>`] and [`std::ops::RangeInclusive<i64>`]
+/// when their step is `1`.
+#[repr(transparent)]
+pub struct PyRange(PyAny);
+
+pyobject_native_type_core!(PyRange, ffi::PyRange_Type, #checkfunction=ffi::PyRange_Check);
+
+impl PyRange {
+    /// Constructs a new range from `start` to `stop` with the given `step`.
+    ///
+    /// This is equivalent to the Python expression `range(start, stop, step)`, and fails if
+    /// `step` is zero.
+    pub fn new(py: Python<'_>, start: isize, stop: isize, step: isize) -> PyResult<&PyRange> {
+        let range = py.get_type::<PyRange>().call1((start, stop, step))?;
+        Ok(unsafe { range.downcast_unchecked() })
+    }
+
+    /// Returns the first value of the range.
+    pub fn start(&self) -> PyResult<isize> {
+        self.getattr(intern!(self.py(), "start"))?.extract()
+    }
+
+    /// Returns the value at which the range stops, exclusive.
+    pub fn stop(&self) -> PyResult<isize> {
+        self.getattr(intern!(self.py(), "stop"))?.extract()
+    }
+
+    /// Returns the difference between successive values of the range.
+    pub fn step(&self) -> PyResult<isize> {
+        self.getattr(intern!(self.py(), "step"))?.extract()
+    }
+
+    /// Returns the number of values in the range.
+    ///
+    /// This is equivalent to the Python expression `len(self)`, and fails with `OverflowError`
+    /// if the length does not fit into a `Py_ssize_t`.
+    pub fn len(&self) -> PyResult<usize> {
+        let v = unsafe { ffi::PyObject_Size(self.as_ptr()) };
+        if v == -1 {
+            Err(PyErr::fetch(self.py()))
+        } else {
+            Ok(v as usize)
+        }
+    }
+
+    /// Checks if the range is empty.
+    pub fn is_empty(&self) -> PyResult<bool> {
+        self.len().map(|len| len == 0)
+    }
+
+    /// Returns an iterator over the values of the range.
+    ///
+    /// The values are computed in Rust, without creating a Python `int` for each of them.
+    /// Fails if the bounds of the range do not fit into an `isize`.
+    pub fn iter(&self) -> PyResult<PyRangeIterator> {
+        Ok(PyRangeIterator {
+            next: self.start()?,
+            step: self.step()?,
+            remaining: self.len()?,
+        })
+    }
+
+    /// Returns `start` and `stop` as `i64`, failing unless `step` is `1`.
+    fn unit_bounds(&self) -> PyResult<(i64, i64)> {
+        let py = self.py();
+        let step: i64 = self.getattr(intern!(py, "step"))?.extract()?;
+        if step != 1 {
+            return Err(PyValueError::new_err(format!(
+                "cannot convert range with step {} to a Rust range",
+                step
+            )));
+        }
+        let start = self.getattr(intern!(py, "start"))?.extract()?;
+        let stop = self.getattr(intern!(py, "stop"))?.extract()?;
+        Ok((start, stop))
+    }
+}
+
+/// Iterator over the values of a [`PyRange`].
+///
+/// Created by [`PyRange::iter`].
+#[derive(Clone, Debug)]
+pub struct PyRangeIterator {
+    next: isize,
+    step: isize,
+    remaining: usize,
+}
+
+impl Iterator for PyRangeIterator {
+    type Item = isize;
+
+    #[inline]
+    fn next(&mut self) -> Option<isize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let value = self.next;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            // Every value of the range lies between `start` and `stop`, so this cannot overflow.
+            self.next += self.step;
+        }
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for PyRangeIterator {
+    #[inline]
+    fn next_back(&mut self) -> Option<isize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        // The intermediate product may wrap, but the final value lies within the range.
+        Some(
+            self.next
+                .wrapping_add((self.remaining as isize).wrapping_mul(self.step)),
+        )
+    }
+}
+
+impl ExactSizeIterator for PyRangeIterator {}
+
+impl std::iter::FusedIterator for PyRangeIterator {}
+
+impl<'source> FromPyObject<'source> for Range<i64> {
+    fn extract(obj: &'source PyAny) -> PyResult<Self> {
+        let (start, stop) = obj.downcast::<PyRange>()?.unit_bounds()?;
+        Ok(start..stop)
+    }
+}
+
+impl<'source> FromPyObject<'source> for RangeInclusive<i64> {
+    fn extract(obj: &'source PyAny) -> PyResult<Self> {
+        let (start, stop) = obj.downcast::<PyRange>()?.unit_bounds()?;
+        let end = stop.checked_sub(1).ok_or_else(|| {
+            PyOverflowError::new_err("range stop is too small for an inclusive Rust range")
+        })?;
+        Ok(start..=end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PyList;
+    use crate::Python;
+
+    #[test]
+    fn test_range_attributes() {
+        Python::with_gil(|py| {
+            let range = PyRange::new(py, 1, 10, 3).unwrap();
+            assert_eq!(range.start().unwrap(), 1);
+            assert_eq!(range.stop().unwrap(), 10);
+            assert_eq!(range.step().unwrap(), 3);
+            assert_eq!(range.len().unwrap(), 3);
+            assert!(!range.is_empty().unwrap());
+            assert!(PyRange::new(py, 5, 0, 1).unwrap().is_empty().unwrap());
+            assert!(PyRange::new(py, 0, 1, 0).is_err());
+        })
+    }
+
+    #[test]
+    fn test_range_downcast() {
+        Python::with_gil(|py| {
+            let range = py.eval("range(4)", None, None).unwrap();
+            assert!(range.downcast::<PyRange>().is_ok());
+            assert!(PyList::empty(py).downcast::<PyRange>().is_err());
+        })
+    }
+
+    #[test]
+    fn test_range_iter() {
+        Python::with_gil(|py| {
+            let range = PyRange::new(py, 10, -3, -4).unwrap();
+            let values: Vec<isize> = range.iter().unwrap().collect();
+            assert_eq!(values, vec![10, 6, 2, -2]);
+            let reversed: Vec<isize> = range.iter().unwrap().rev().collect();
+            assert_eq!(reversed, vec![-2, 2, 6, 10]);
+            assert_eq!(range.iter().unwrap().len(), 4);
+
+            let mut iter = PyRange::new(py, 0, 5, 1).unwrap().iter().unwrap();
+            assert_eq!(iter.next(), Some(0));
+            assert_eq!(iter.next_back(), Some(4));
+            assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3]);
+
+            let extreme = PyRange::new(py, isize::MAX - 2, isize::MAX, 1).unwrap();
+            assert_eq!(
+                extreme.iter().unwrap().collect::<Vec<_>>(),
+                vec![isize::MAX - 2, isize::MAX - 1]
+            );
+
+            let huge = py.eval("range(2 ** 70, 2 ** 70 + 1)", None, None).unwrap();
+            assert!(huge.downcast::<PyRange>().unwrap().iter().is_err());
+        })
+    }
+
+    #[test]
+    fn test_range_extract() {
+        Python::with_gil(|py| {
+            let range = py.eval("range(2, 5)", None, None).unwrap();
+            assert_eq!(range.extract::<Range<i64>>().unwrap(), 2..5);
+            assert_eq!(range.extract::<RangeInclusive<i64>>().unwrap(), 2..=4);
+
+            let stepped = py.eval("range(2, 5, 2)", None, None).unwrap();
+            let err = stepped.extract::<Range<i64>>().unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+
+            assert!(PyList::empty(py).extract::<Range<i64>>().is_err());
+        })
+    }
+}
@@ -1,11 +1,15 @@
 // Copyright (c) 2017-present PyO3 Project and Contributors
 
+use crate::exceptions::PyRuntimeError;
 #[cfg(not(Py_LIMITED_API))]
-use crate::exceptions::PyUnicodeDecodeError;
+use crate::exceptions::{PyUnicodeDecodeError, PyValueError};
 use crate::types::PyBytes;
-use crate::{ffi, AsPyPointer, PyAny, PyResult, Python};
+use crate::{ffi, AsPyPointer, Bound, PyAny, PyErr, PyResult, Python};
 use std::borrow::Cow;
+use std::fmt;
 use std::os::raw::c_char;
+#[cfg(not(Py_LIMITED_API))]
+use std::os::raw::{c_int, c_void};
 use std::str;
 
 /// Represents raw data backing a Python `str`.
@@ -141,6 +145,15 @@ impl PyString {
         unsafe { py.from_owned_ptr(ffi::PyUnicode_FromStringAndSize(ptr, len)) }
     }
 
+    /// Creates a new Python string object, returned as a [`Bound`].
+    ///
+    /// Panics if out of memory.
+    pub fn new_bound<'p>(py: Python<'p>, s: &str) -> Bound<'p, PyString> {
+        let ptr = s.as_ptr() as *const c_char;
+        let len = s.len() as ffi::Py_ssize_t;
+        unsafe { Bound::from_owned_ptr(py, ffi::PyUnicode_FromStringAndSize(ptr, len)) }
+    }
+
     /// Intern the given string
     ///
     /// This will return a reference to the same Python string object if called repeatedly with the same string.
@@ -175,6 +188,74 @@ impl PyString {
         }
     }
 
+    /// Creates a Python string from Latin-1 (ISO 8859-1) encoded text.
+    ///
+    /// Every byte is a valid Latin-1 character, so unlike [`PyString::new`] this does not need to
+    /// validate its input.
+    ///
+    /// Panics if out of memory.
+    pub fn from_latin1<'p>(py: Python<'p>, s: &[u8]) -> &'p PyString {
+        let ptr = s.as_ptr() as *const c_char;
+        let len = s.len() as ffi::Py_ssize_t;
+        unsafe { py.from_owned_ptr(ffi::PyUnicode_DecodeLatin1(ptr, len, std::ptr::null())) }
+    }
+
+    /// Creates a Python string from UCS-2 code units.
+    ///
+    /// Each code unit becomes one character. Unlike UTF-16, surrogates are not combined into pairs.
+    ///
+    /// Panics if out of memory.
+    #[cfg(not(Py_LIMITED_API))]
+    pub fn from_ucs2<'p>(py: Python<'p>, s: &[u16]) -> &'p PyString {
+        unsafe {
+            py.from_owned_ptr(ffi::PyUnicode_FromKindAndData(
+                ffi::PyUnicode_2BYTE_KIND as c_int,
+                s.as_ptr() as *const c_void,
+                s.len() as ffi::Py_ssize_t,
+            ))
+        }
+    }
+
+    /// Creates a Python string from UCS-4 code points.
+    ///
+    /// Returns a `ValueError` if a code point is greater than `0x10FFFF`. Lone surrogates are
+    /// allowed, as in Python strings.
+    #[cfg(not(Py_LIMITED_API))]
+    pub fn from_ucs4<'p>(py: Python<'p>, s: &[u32]) -> PyResult<&'p PyString> {
+        if let Some(c) = s.iter().find(|&&c| c > 0x10FFFF) {
+            return Err(PyValueError::new_err(format!(
+                "code point {:#x} not in range(0x110000)",
+                c
+            )));
+        }
+        unsafe {
+            py.from_owned_ptr_or_err(ffi::PyUnicode_FromKindAndData(
+                ffi::PyUnicode_4BYTE_KIND as c_int,
+                s.as_ptr() as *const c_void,
+                s.len() as ffi::Py_ssize_t,
+            ))
+        }
+    }
+
+    /// Creates a Python string from formatting arguments, as created by [`format_args!`].
+    ///
+    /// The text is written directly into the Python string by a [`PyStringWriter`], without
+    /// formatting it into a Rust `String` first. The [`py_format!`] macro is a shorthand for
+    /// this function.
+    ///
+    /// Returns an error if out of memory or if a formatting trait implementation fails.
+    ///
+    /// [`py_format!`]: crate::py_format
+    pub fn from_fmt<'p>(py: Python<'p>, args: fmt::Arguments<'_>) -> PyResult<&'p PyString> {
+        let mut writer = PyStringWriter::new(py);
+        match fmt::write(&mut writer, args) {
+            Ok(()) => writer.finish(),
+            Err(fmt::Error) => Err(writer.error.take().unwrap_or_else(|| {
+                PyRuntimeError::new_err("a formatting trait implementation returned an error")
+            })),
+        }
+    }
+
     /// Gets the Python string as a byte slice.
     ///
     /// Returns a `UnicodeEncodeError` if the input is not valid unicode
@@ -277,6 +358,288 @@ impl PyString {
     }
 }
 
+/// Builds a Python string incrementally, without an intermediate Rust `String`.
+///
+/// `PyStringWriter` implements [`fmt::Write`], so it can be used with [`write!`]. Text is written
+/// directly into the buffer of the Python string, which uses the narrowest of Python's internal
+/// representations (1, 2 or 4 bytes per character, see [PEP 393]) that fits the text written so
+/// far. [`finish`](PyStringWriter::finish) returns the string.
+///
+/// When compiling for the limited API or PyPy, the text is collected in a Rust `String` and
+/// copied into the Python string by `finish`.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::types::PyStringWriter;
+/// use std::fmt::Write;
+///
+/// # fn main() -> PyResult<()> {
+/// Python::with_gil(|py| {
+///     let mut writer = PyStringWriter::new(py);
+///     writer.write_str("[").unwrap();
+///     for (i, value) in [1.5, 2.0].iter().enumerate() {
+///         if i > 0 {
+///             writer.write_str(", ").unwrap();
+///         }
+///         write!(writer, "{:.1}", value).unwrap();
+///     }
+///     writer.write_str("]").unwrap();
+///
+///     let string = writer.finish()?;
+///     assert_eq!(string.to_str()?, "[1.5, 2.0]");
+///     Ok(())
+/// })
+/// # }
+/// ```
+///
+/// [PEP 393]: https://peps.python.org/pep-0393/
+pub struct PyStringWriter<'py> {
+    py: Python<'py>,
+    #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+    buffer: writer::Buffer,
+    #[cfg(any(Py_LIMITED_API, PyPy))]
+    buffer: String,
+    /// The error which made a write fail.
+    error: Option<PyErr>,
+}
+
+impl<'py> PyStringWriter<'py> {
+    /// Creates an empty writer.
+    pub fn new(py: Python<'py>) -> Self {
+        Self::with_capacity(py, 0)
+    }
+
+    /// Creates an empty writer with room for `capacity` characters.
+    pub fn with_capacity(py: Python<'py>, capacity: usize) -> Self {
+        PyStringWriter {
+            py,
+            #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+            buffer: writer::Buffer::with_capacity(capacity),
+            #[cfg(any(Py_LIMITED_API, PyPy))]
+            buffer: String::with_capacity(capacity),
+            error: None,
+        }
+    }
+
+    /// Returns the number of characters written so far.
+    pub fn len(&self) -> usize {
+        #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+        {
+            self.buffer.len
+        }
+        #[cfg(any(Py_LIMITED_API, PyPy))]
+        {
+            self.buffer.chars().count()
+        }
+    }
+
+    /// Returns `true` if nothing was written so far.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the Python string containing the text written so far.
+    ///
+    /// Returns the error of a failed write, if any.
+    pub fn finish(mut self) -> PyResult<&'py PyString> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+        #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+        {
+            let py = self.py;
+            let string = self.buffer.finish(py)?;
+            Ok(unsafe { py.from_owned_ptr(string) })
+        }
+        #[cfg(any(Py_LIMITED_API, PyPy))]
+        {
+            Ok(PyString::new(self.py, &self.buffer))
+        }
+    }
+}
+
+impl fmt::Write for PyStringWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.error.is_some() {
+            return Err(fmt::Error);
+        }
+        #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+        {
+            if let Err(err) = self.buffer.write_str(self.py, s) {
+                self.error = Some(err);
+                return Err(fmt::Error);
+            }
+        }
+        #[cfg(any(Py_LIMITED_API, PyPy))]
+        {
+            self.buffer.push_str(s);
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for PyStringWriter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PyStringWriter")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+#[cfg(not(any(Py_LIMITED_API, PyPy)))]
+mod writer {
+    use crate::{ffi, PyErr, PyResult, Python};
+    use std::os::raw::c_void;
+    use std::ptr;
+
+    /// A Python string object which is still being written.
+    ///
+    /// The object is created with `PyUnicode_New`, so it is compact and its characters directly
+    /// follow the object header. Its maximum character decides the header and character size.
+    pub(super) struct Buffer {
+        /// The string object, or null if nothing was allocated yet.
+        string: *mut ffi::PyObject,
+        /// One of 0x7F, 0xFF, 0xFFFF and 0x10FFFF.
+        max_char: u32,
+        pub(super) len: usize,
+        capacity: usize,
+    }
+
+    impl Buffer {
+        pub(super) fn with_capacity(capacity: usize) -> Self {
+            Buffer {
+                string: ptr::null_mut(),
+                max_char: 0x7F,
+                len: 0,
+                capacity,
+            }
+        }
+
+        pub(super) fn write_str(&mut self, py: Python<'_>, s: &str) -> PyResult<()> {
+            // A string has at most as many characters as UTF-8 bytes.
+            self.reserve(py, s.len())?;
+            if self.max_char <= 0xFF && s.is_ascii() {
+                unsafe {
+                    let data = (self.data() as *mut u8).add(self.len);
+                    ptr::copy_nonoverlapping(s.as_ptr(), data, s.len());
+                }
+                self.len += s.len();
+                return Ok(());
+            }
+            for c in s.chars() {
+                let c = c as u32;
+                if c > self.max_char {
+                    self.widen(py, c)?;
+                }
+                unsafe { self.write_char(self.len, c) };
+                self.len += 1;
+            }
+            Ok(())
+        }
+
+        /// Returns an owned reference to the finished string.
+        pub(super) fn finish(&mut self, py: Python<'_>) -> PyResult<*mut ffi::PyObject> {
+            if self.string.is_null() {
+                return new_string(py, 0, self.max_char);
+            }
+            if self.len != self.capacity {
+                self.resize(py, self.len)?;
+            }
+            Ok(std::mem::replace(&mut self.string, ptr::null_mut()))
+        }
+
+        /// Makes room for `additional` more characters.
+        fn reserve(&mut self, py: Python<'_>, additional: usize) -> PyResult<()> {
+            if self.string.is_null() {
+                let capacity = self.capacity.max(additional).max(16);
+                self.string = new_string(py, capacity, self.max_char)?;
+                self.capacity = capacity;
+            } else if self.capacity - self.len < additional {
+                let capacity = (self.len + additional).max(self.capacity * 2);
+                self.resize(py, capacity)?;
+            }
+            Ok(())
+        }
+
+        fn resize(&mut self, py: Python<'_>, capacity: usize) -> PyResult<()> {
+            // A fresh string is not shared, so `PyUnicode_Resize` resizes it in place.
+            if unsafe { ffi::PyUnicode_Resize(&mut self.string, capacity as ffi::Py_ssize_t) } == -1
+            {
+                // The string was released by `PyUnicode_Resize`.
+                self.string = ptr::null_mut();
+                return Err(PyErr::fetch(py));
+            }
+            self.capacity = capacity;
+            Ok(())
+        }
+
+        /// Replaces the string by one which can hold `c`, copying the characters written so far.
+        fn widen(&mut self, py: Python<'_>, c: u32) -> PyResult<()> {
+            let max_char = match c {
+                0..=0xFF => 0xFF,
+                0x100..=0xFFFF => 0xFFFF,
+                _ => 0x10FFFF,
+            };
+            let mut wider = Buffer {
+                string: new_string(py, self.capacity, max_char)?,
+                max_char,
+                len: self.len,
+                capacity: self.capacity,
+            };
+            for i in 0..self.len {
+                unsafe { wider.write_char(i, self.read_char(i)) };
+            }
+            std::mem::swap(self, &mut wider);
+            Ok(())
+        }
+
+        /// Returns the pointer to the characters of the string.
+        unsafe fn data(&self) -> *mut c_void {
+            if self.max_char == 0x7F {
+                (self.string as *mut ffi::PyASCIIObject).offset(1) as *mut c_void
+            } else {
+                (self.string as *mut ffi::PyCompactUnicodeObject).offset(1) as *mut c_void
+            }
+        }
+
+        unsafe fn write_char(&mut self, index: usize, c: u32) {
+            let data = self.data();
+            match self.max_char {
+                0x7F | 0xFF => *(data as *mut u8).add(index) = c as u8,
+                0xFFFF => *(data as *mut u16).add(index) = c as u16,
+                _ => *(data as *mut u32).add(index) = c,
+            }
+        }
+
+        unsafe fn read_char(&self, index: usize) -> u32 {
+            let data = self.data();
+            match self.max_char {
+                0x7F | 0xFF => u32::from(*(data as *const u8).add(index)),
+                0xFFFF => u32::from(*(data as *const u16).add(index)),
+                _ => *(data as *const u32).add(index),
+            }
+        }
+    }
+
+    impl Drop for Buffer {
+        fn drop(&mut self) {
+            // The buffer is only used while the `PyStringWriter` holds the GIL.
+            unsafe { ffi::Py_XDECREF(self.string) };
+        }
+    }
+
+    fn new_string(py: Python<'_>, len: usize, max_char: u32) -> PyResult<*mut ffi::PyObject> {
+        let string = unsafe { ffi::PyUnicode_New(len as ffi::Py_ssize_t, max_char) };
+        if string.is_null() {
+            Err(PyErr::fetch(py))
+        } else {
+            Ok(string)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -481,4 +844,88 @@ mod tests {
             assert_ne!(py_string1.as_ptr(), py_string3.as_ptr());
         });
     }
+
+    #[test]
+    fn test_string_writer() {
+        use std::fmt::Write;
+
+        Python::with_gil(|py| {
+            let long = "x".repeat(100);
+            let cases: &[&[&str]] = &[
+                &[],
+                &[""],
+                &["ascii", " only"],
+                &["ascii, then ", "latïn-1"],
+                &["latïn-1, then ", "哈哈, then ", "🐈"],
+                &["🐈", " first"],
+                &[&long, "é", &long, "🐈", &long],
+            ];
+            for parts in cases {
+                let mut writer = PyStringWriter::with_capacity(py, 4);
+                for part in parts.iter() {
+                    writer.write_str(part).unwrap();
+                }
+                let expected = parts.concat();
+                assert_eq!(writer.len(), expected.chars().count());
+                let string = writer.finish().unwrap();
+                assert_eq!(string.to_str().unwrap(), expected);
+
+                // The string must use the same representation as one created by Python.
+                let reference = PyString::new(py, &expected);
+                assert!(string.eq(reference).unwrap());
+                assert_eq!(string.hash().unwrap(), reference.hash().unwrap());
+                #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+                unsafe {
+                    assert_eq!(
+                        string.data().unwrap().value_width_bytes(),
+                        reference.data().unwrap().value_width_bytes()
+                    );
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn test_py_format() {
+        struct Failing;
+
+        impl std::fmt::Display for Failing {
+            fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Err(std::fmt::Error)
+            }
+        }
+
+        Python::with_gil(|py| {
+            let string = crate::py_format!(py, "{}-{:>4}-{:?}", 1, "ü", 'c').unwrap();
+            assert_eq!(string.to_str().unwrap(), "1-   ü-'c'");
+
+            let err = crate::py_format!(py, "{}", Failing).unwrap_err();
+            assert!(err.is_instance_of::<PyRuntimeError>(py));
+        });
+    }
+
+    #[test]
+    fn test_from_narrow_data() {
+        Python::with_gil(|py| {
+            let string = PyString::from_latin1(py, b"caf\xe9");
+            assert_eq!(string.to_str().unwrap(), "café");
+
+            #[cfg(not(Py_LIMITED_API))]
+            {
+                let string = PyString::from_ucs2(py, &[0x54c8, 0x54c8]);
+                assert_eq!(string.to_str().unwrap(), "哈哈");
+                // Surrogates are not combined.
+                let string = PyString::from_ucs2(py, &[0xd83d, 0xdc08]);
+                assert_eq!(string.len().unwrap(), 2);
+                // Narrow text gets the narrowest representation.
+                let string = PyString::from_ucs2(py, &[0x61, 0x62]);
+                assert_eq!(unsafe { string.data() }.unwrap(), PyStringData::Ucs1(b"ab"));
+
+                let string = PyString::from_ucs4(py, &[0x1f408, 0x61]).unwrap();
+                assert_eq!(string.to_str().unwrap(), "🐈a");
+                let err = PyString::from_ucs4(py, &[0x110000]).unwrap_err();
+                assert!(err.is_instance_of::<crate::exceptions::PyValueError>(py));
+            }
+        });
+    }
 }
@@ -2,10 +2,15 @@
 
 use crate::err::{error_on_minusone, PyResult};
 use crate::ffi;
+#[cfg(all(not(Py_LIMITED_API), not(PyPy)))]
+use crate::types::PyFrame;
 use crate::types::PyString;
 use crate::{AsPyPointer, PyAny};
 
 /// Represents a Python traceback.
+///
+/// A traceback is a linked list of entries, one per frame the exception passed through, starting
+/// with the outermost frame. Iterating over a traceback yields its entries in that order.
 #[repr(transparent)]
 pub struct PyTraceback(PyAny);
 
@@ -63,6 +68,77 @@ impl PyTraceback {
             .to_owned();
         Ok(formatted)
     }
+
+    /// Returns the frame of this traceback entry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use pyo3::{Python, PyResult};
+    /// # let result: PyResult<()> =
+    /// Python::with_gil(|py| {
+    ///     let err = py
+    ///         .run("def fail():\n    raise Exception('banana')\nfail()", None, None)
+    ///         .expect_err("raise will create a Python error");
+    ///
+    ///     let traceback = err.traceback(py).expect("raised exception will have a traceback");
+    ///     let mut names = Vec::new();
+    ///     for entry in traceback {
+    ///         names.push(entry.frame()?.code().name()?.to_owned());
+    ///     }
+    ///     assert_eq!(names, ["<module>", "fail"]);
+    ///     Ok(())
+    /// })
+    /// # ;
+    /// # result.expect("example failed");
+    /// ```
+    #[cfg(all(not(Py_LIMITED_API), not(PyPy)))]
+    pub fn frame(&self) -> PyResult<&PyFrame> {
+        let py = self.py();
+        Ok(self.getattr(intern!(py, "tb_frame"))?.downcast()?)
+    }
+
+    /// Returns the line at which the exception passed through the frame of this entry.
+    ///
+    /// This differs from the current line of the frame if the frame continued to run, for example
+    /// after catching the exception.
+    pub fn line_number(&self) -> Option<usize> {
+        let py = self.py();
+        self.getattr(intern!(py, "tb_lineno")).ok()?.extract().ok()
+    }
+
+    /// Returns an iterator over the entries of the traceback, starting with this one.
+    pub fn iter(&self) -> PyTracebackIterator<'_> {
+        PyTracebackIterator { next: Some(self) }
+    }
+}
+
+impl<'a> IntoIterator for &'a PyTraceback {
+    type Item = &'a PyTraceback;
+    type IntoIter = PyTracebackIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// PyO3 implementation of an iterator over the entries of a Python traceback.
+pub struct PyTracebackIterator<'py> {
+    next: Option<&'py PyTraceback>,
+}
+
+impl<'py> Iterator for PyTracebackIterator<'py> {
+    type Item = &'py PyTraceback;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        let py = current.py();
+        self.next = current
+            .getattr(intern!(py, "tb_next"))
+            .ok()
+            .and_then(|next| next.downcast().ok());
+        Some(current)
+    }
 }
 
 #[cfg(test)]
@@ -82,4 +158,30 @@ mod tests {
             );
         })
     }
+
+    #[test]
+    fn traceback_entries() {
+        Python::with_gil(|py| {
+            let err = py
+                .run(
+                    "def fail():\n    raise Exception('banana')\n\nfail()",
+                    None,
+                    None,
+                )
+                .expect_err("raising should have given us an error");
+            let traceback = err.traceback(py).unwrap();
+
+            let lines: Vec<_> = traceback.iter().map(|entry| entry.line_number()).collect();
+            assert_eq!(lines, [Some(4), Some(2)]);
+
+            #[cfg(all(not(Py_LIMITED_API), not(PyPy)))]
+            {
+                let names: Vec<_> = traceback
+                    .iter()
+                    .map(|entry| entry.frame().unwrap().code().qualname().unwrap())
+                    .collect();
+                assert_eq!(names, ["<module>", "fail"]);
+            }
+        })
+    }
 }
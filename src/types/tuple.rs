@@ -9,8 +9,8 @@ use crate::internal_tricks::get_ssize_index;
 use crate::types::PyList;
 use crate::types::PySequence;
 use crate::{
-    exceptions, AsPyPointer, FromPyObject, IntoPy, IntoPyPointer, Py, PyAny, PyErr, PyObject,
-    PyResult, Python, ToPyObject,
+    exceptions, AsPyPointer, Bound, FromPyObject, IntoPy, IntoPyPointer, Py, PyAny, PyErr,
+    PyObject, PyResult, Python, ToPyObject,
 };
 
 #[inline]
@@ -103,6 +103,27 @@ impl PyTuple {
         unsafe { py.from_owned_ptr(ffi::PyTuple_New(0)) }
     }
 
+    /// Constructs a new tuple with the given elements, returned as a [`Bound`].
+    ///
+    /// See [`PyTuple::new`].
+    #[track_caller]
+    pub fn new_bound<T, U>(
+        py: Python<'_>,
+        elements: impl IntoIterator<Item = T, IntoIter = U>,
+    ) -> Bound<'_, PyTuple>
+    where
+        T: ToPyObject,
+        U: ExactSizeIterator<Item = T>,
+    {
+        let mut elements = elements.into_iter().map(|e| e.to_object(py));
+        new_from_iter(py, &mut elements).into_bound(py)
+    }
+
+    /// Constructs an empty tuple, returned as a [`Bound`].
+    pub fn empty_bound(py: Python<'_>) -> Bound<'_, PyTuple> {
+        unsafe { Bound::from_owned_ptr(py, ffi::PyTuple_New(0)) }
+    }
+
     /// Gets the length of the tuple.
     pub fn len(&self) -> usize {
         unsafe {
@@ -273,6 +294,98 @@ impl<'a> IntoIterator for &'a PyTuple {
     }
 }
 
+impl<'py> Bound<'py, PyTuple> {
+    /// Gets the tuple item at the specified index.
+    ///
+    /// See [`PyTuple::get_item`].
+    pub fn get_item(&self, index: usize) -> PyResult<Bound<'py, PyAny>> {
+        unsafe {
+            let item = ffi::PyTuple_GetItem(self.as_ptr(), index as Py_ssize_t);
+            ffi::Py_XINCREF(item);
+            Bound::from_owned_ptr_or_err(self.py(), item)
+        }
+    }
+
+    /// Gets the tuple item at the specified index. Undefined behavior on bad index. Use with caution.
+    ///
+    /// # Safety
+    ///
+    /// Caller must verify that the index is within the bounds of the tuple.
+    #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+    pub unsafe fn get_item_unchecked(&self, index: usize) -> Bound<'py, PyAny> {
+        let item = ffi::PyTuple_GET_ITEM(self.as_ptr(), index as Py_ssize_t);
+        Bound::from_borrowed_ptr(self.py(), item)
+    }
+
+    /// Returns an iterator over the tuple items.
+    pub fn iter(&self) -> BoundTupleIterator<'py> {
+        BoundTupleIterator {
+            length: self.len(),
+            tuple: self.clone(),
+            index: 0,
+        }
+    }
+}
+
+/// Used by `Bound<PyTuple>::iter()`.
+pub struct BoundTupleIterator<'py> {
+    tuple: Bound<'py, PyTuple>,
+    index: usize,
+    length: usize,
+}
+
+impl<'py> Iterator for BoundTupleIterator<'py> {
+    type Item = Bound<'py, PyAny>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Bound<'py, PyAny>> {
+        if self.index < self.length {
+            #[cfg(any(Py_LIMITED_API, PyPy))]
+            let item = self.tuple.get_item(self.index).expect("tuple.get failed");
+            #[cfg(not(any(Py_LIMITED_API, PyPy)))]
+            let item = unsafe { self.tuple.get_item_unchecked(self.index) };
+            self.index += 1;
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for BoundTupleIterator<'_> {
+    fn len(&self) -> usize {
+        self.length.saturating_sub(self.index)
+    }
+}
+
+impl<'py> IntoIterator for Bound<'py, PyTuple> {
+    type Item = Bound<'py, PyAny>;
+    type IntoIter = BoundTupleIterator<'py>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BoundTupleIterator {
+            length: self.len(),
+            tuple: self,
+            index: 0,
+        }
+    }
+}
+
+impl<'py> IntoIterator for &Bound<'py, PyTuple> {
+    type Item = Bound<'py, PyAny>;
+    type IntoIter = BoundTupleIterator<'py>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 #[cold]
 fn wrong_tuple_length(t: &PyTuple, expected_length: usize) -> PyErr {
     let msg = format!(
@@ -529,6 +642,25 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_bound() {
+        Python::with_gil(|py| {
+            let tuple = PyTuple::new_bound(py, [1, 2, 3]);
+            assert_eq!(tuple.len(), 3);
+            assert_eq!(tuple.get_item(2).unwrap().extract::<i32>().unwrap(), 3);
+            assert!(tuple.get_item(3).is_err());
+
+            let items: Vec<i32> = (&tuple)
+                .into_iter()
+                .map(|item| item.extract().unwrap())
+                .collect();
+            assert_eq!(items, [1, 2, 3]);
+            assert_eq!(tuple.iter().len(), 3);
+            assert_eq!(tuple.into_iter().count(), 3);
+            assert!(PyTuple::empty_bound(py).is_empty());
+        });
+    }
+
     #[test]
     #[cfg(not(Py_LIMITED_API))]
     fn test_as_slice() {
@@ -0,0 +1,647 @@
+//! Statically-typed views over Python containers.
+
+use crate::exceptions::PyTypeError;
+#[cfg(feature = "experimental-inspect")]
+use crate::inspect::types::TypeInfo;
+use crate::types::iter::PyDictIterator;
+use crate::types::{PyAny, PyDict, PyIterator, PyList, PySequence, PyTuple};
+use crate::{
+    AsPyPointer, FromPyObject, IntoPy, PyErr, PyNativeType, PyObject, PyResult, Python, ToPyObject,
+};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+macro_rules! typed_container_base {
+    ($name:ident<$($generics:ident),+>, $inner:ty) => {
+        impl<'a, $($generics),+> From<&'a $inner> for &'a $name<$($generics),+> {
+            #[inline]
+            fn from(inner: &'a $inner) -> Self {
+                // Safety: `$name` is `repr(transparent)` over `$inner`.
+                unsafe { &*(inner as *const $inner as *const $name<$($generics),+>) }
+            }
+        }
+
+        impl<$($generics),+> Deref for $name<$($generics),+> {
+            type Target = $inner;
+
+            #[inline]
+            fn deref(&self) -> &$inner {
+                &self.0
+            }
+        }
+
+        impl<$($generics),+> AsRef<PyAny> for $name<$($generics),+> {
+            #[inline]
+            fn as_ref(&self) -> &PyAny {
+                &self.0
+            }
+        }
+
+        impl<$($generics),+> ToPyObject for $name<$($generics),+> {
+            #[inline]
+            fn to_object(&self, py: Python<'_>) -> PyObject {
+                self.0.to_object(py)
+            }
+        }
+
+        impl<$($generics),+> fmt::Debug for $name<$($generics),+> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl<$($generics),+> fmt::Display for $name<$($generics),+> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+macro_rules! typed_sequence {
+    ($name:ident, $inner:ty, $kind:literal, $type_info:expr) => {
+        typed_container_base!($name<T>, $inner);
+
+        impl<T> $name<T> {
+            /// Gets the item at `index`, extracted as `T`.
+            ///
+            /// Fails if `index` is out of range or if the item cannot be extracted.
+            pub fn get<'py>(&'py self, index: usize) -> PyResult<T>
+            where
+                T: FromPyObject<'py>,
+            {
+                extract_item(self.0.get_item(index)?, $kind, index)
+            }
+
+            /// Returns an iterator over the items, each extracted as `T`.
+            ///
+            /// If the iterator cannot be created, the error is produced as its first item.
+            pub fn iter<'py>(&'py self) -> PyTypedSequenceIterator<'py, T>
+            where
+                T: FromPyObject<'py>,
+            {
+                PyTypedSequenceIterator {
+                    inner: PyAny::iter(&self.0).map_err(Some),
+                    kind: $kind,
+                    index: 0,
+                    _marker: PhantomData,
+                }
+            }
+
+            /// Extracts all items into a `Vec<T>`.
+            pub fn to_vec<'py>(&'py self) -> PyResult<Vec<T>>
+            where
+                T: FromPyObject<'py>,
+            {
+                self.iter().collect()
+            }
+        }
+
+        impl<'py, T> IntoIterator for &'py $name<T>
+        where
+            T: FromPyObject<'py>,
+        {
+            type Item = PyResult<T>;
+            type IntoIter = PyTypedSequenceIterator<'py, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'py, T> FromPyObject<'py> for &'py $name<T>
+        where
+            T: FromPyObject<'py>,
+        {
+            fn extract(obj: &'py PyAny) -> PyResult<Self> {
+                let inner: &$inner = obj.downcast()?;
+                Ok(inner.into())
+            }
+
+            #[cfg(feature = "experimental-inspect")]
+            fn type_input() -> TypeInfo {
+                $type_info(T::type_input())
+            }
+        }
+
+        impl<T> IntoPy<PyObject> for &'_ $name<T>
+        where
+            T: IntoPy<PyObject>,
+        {
+            #[inline]
+            fn into_py(self, py: Python<'_>) -> PyObject {
+                (&self.0).into_py(py)
+            }
+
+            #[cfg(feature = "experimental-inspect")]
+            fn type_output() -> TypeInfo {
+                $type_info(T::type_output())
+            }
+        }
+    };
+}
+
+/// A Python `list` whose items are expected to be of type `T`.
+///
+/// This is a zero-cost view over a [`PyList`]: items are only checked when they are accessed,
+/// and extraction errors name the index of the offending item. The untyped `PyList` API remains
+/// available through `Deref`.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::types::PyListOf;
+///
+/// #[pyfunction]
+/// fn total(values: &PyListOf<i64>) -> PyResult<i64> {
+///     values.iter().sum()
+/// }
+/// #
+/// # Python::with_gil(|py| {
+/// #     let f = wrap_pyfunction!(total, py).unwrap();
+/// #     assert_eq!(f.call1(([1, 2, 3],)).unwrap().extract::<i64>().unwrap(), 6);
+/// #     let bad = py.eval("[1, 'two']", None, None).unwrap();
+/// #     let err = f.call1((bad,)).unwrap_err();
+/// #     assert_eq!(err.value(py).to_string(), "failed to extract list item at index 1");
+/// # });
+/// ```
+#[repr(transparent)]
+pub struct PyListOf<T>(PyList, PhantomData<T>);
+
+typed_sequence!(PyListOf, PyList, "list", TypeInfo::list_of);
+
+impl<T> PyListOf<T> {
+    /// Creates a new empty list.
+    pub fn empty(py: Python<'_>) -> &PyListOf<T> {
+        PyList::empty(py).into()
+    }
+
+    /// Sets the item at `index` to `item`.
+    pub fn set_item(&self, index: usize, item: T) -> PyResult<()>
+    where
+        T: ToPyObject,
+    {
+        self.0.set_item(index, item)
+    }
+
+    /// Appends `item` to the end of the list.
+    pub fn append(&self, item: T) -> PyResult<()>
+    where
+        T: ToPyObject,
+    {
+        self.0.append(item)
+    }
+
+    /// Inserts `item` before `index`.
+    pub fn insert(&self, index: usize, item: T) -> PyResult<()>
+    where
+        T: ToPyObject,
+    {
+        self.0.insert(index, item)
+    }
+}
+
+/// A Python `tuple` whose items are expected to be of type `T`.
+///
+/// This is a zero-cost view over a [`PyTuple`]; see [`PyListOf`] for details.
+#[repr(transparent)]
+pub struct PyTupleOf<T>(PyTuple, PhantomData<T>);
+
+typed_sequence!(PyTupleOf, PyTuple, "tuple", unsized_tuple_of);
+
+#[cfg(feature = "experimental-inspect")]
+fn unsized_tuple_of(t: TypeInfo) -> TypeInfo {
+    TypeInfo::UnsizedTypedTuple(Box::new(t))
+}
+
+/// A Python sequence whose items are expected to be of type `T`.
+///
+/// This is a zero-cost view over a [`PySequence`]; see [`PyListOf`] for details.
+#[repr(transparent)]
+pub struct PySequenceOf<T>(PySequence, PhantomData<T>);
+
+typed_sequence!(PySequenceOf, PySequence, "sequence", TypeInfo::sequence_of);
+
+impl<T> PySequenceOf<T> {
+    /// Sets the item at `index` to `item`.
+    pub fn set_item(&self, index: usize, item: T) -> PyResult<()>
+    where
+        T: ToPyObject,
+    {
+        self.0.set_item(index, item)
+    }
+}
+
+/// A Python `dict` whose keys are expected to be of type `K` and values of type `V`.
+///
+/// This is a zero-cost view over a [`PyDict`]: entries are only checked when they are accessed,
+/// and extraction errors name the offending key. The untyped `PyDict` API remains available
+/// through `Deref`.
+///
+/// # Examples
+///
+/// ```rust
+/// use pyo3::prelude::*;
+/// use pyo3::types::PyDictOf;
+///
+/// #[pyfunction]
+/// fn bump(counts: &PyDictOf<String, u32>, name: String) -> PyResult<()> {
+///     let count = counts.get(name.clone())?.unwrap_or(0);
+///     counts.set_item(name, count + 1)
+/// }
+/// #
+/// # Python::with_gil(|py| {
+/// #     let f = wrap_pyfunction!(bump, py).unwrap();
+/// #     let counts = pyo3::types::PyDict::new(py);
+/// #     f.call1((counts, "a")).unwrap();
+/// #     f.call1((counts, "a")).unwrap();
+/// #     assert_eq!(counts.get_item("a").unwrap().extract::<u32>().unwrap(), 2);
+/// # });
+/// ```
+#[repr(transparent)]
+pub struct PyDictOf<K, V>(PyDict, PhantomData<(K, V)>);
+
+typed_container_base!(PyDictOf<K, V>, PyDict);
+
+impl<K, V> PyDictOf<K, V> {
+    /// Creates a new empty dictionary.
+    pub fn new(py: Python<'_>) -> &PyDictOf<K, V> {
+        PyDict::new(py).into()
+    }
+
+    /// Gets the value for `key`, extracted as `V`.
+    ///
+    /// Returns `Ok(None)` if `key` is not present.
+    pub fn get<'py>(&'py self, key: K) -> PyResult<Option<V>>
+    where
+        K: ToPyObject,
+        V: FromPyObject<'py>,
+    {
+        let py = self.py();
+        let key = key.to_object(py);
+        let value = unsafe {
+            py.from_owned_ptr_or_opt::<PyAny>(crate::ffi::_Py_XNewRef(
+                crate::ffi::PyDict_GetItemWithError(self.as_ptr(), key.as_ptr()),
+            ))
+        };
+        match value {
+            Some(value) => extract_value(value, key.as_ref(py)).map(Some),
+            None => match PyErr::take(py) {
+                Some(err) => Err(err),
+                None => Ok(None),
+            },
+        }
+    }
+
+    /// Sets `key` to `value`.
+    pub fn set_item(&self, key: K, value: V) -> PyResult<()>
+    where
+        K: ToPyObject,
+        V: ToPyObject,
+    {
+        self.0.set_item(key, value)
+    }
+
+    /// Returns an iterator of `(key, value)` pairs, extracted as `K` and `V`.
+    ///
+    /// # Panics
+    ///
+    /// Like [`PyDict::iter`], this panics if the set of keys changes during iteration.
+    pub fn iter<'py>(&'py self) -> PyDictOfIterator<'py, K, V>
+    where
+        K: FromPyObject<'py>,
+        V: FromPyObject<'py>,
+    {
+        PyDictOfIterator {
+            inner: self.0.iter(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'py, K, V> IntoIterator for &'py PyDictOf<K, V>
+where
+    K: FromPyObject<'py>,
+    V: FromPyObject<'py>,
+{
+    type Item = PyResult<(K, V)>;
+    type IntoIter = PyDictOfIterator<'py, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'py, K, V> FromPyObject<'py> for &'py PyDictOf<K, V>
+where
+    K: FromPyObject<'py>,
+    V: FromPyObject<'py>,
+{
+    fn extract(obj: &'py PyAny) -> PyResult<Self> {
+        let dict: &PyDict = obj.downcast()?;
+        Ok(dict.into())
+    }
+
+    #[cfg(feature = "experimental-inspect")]
+    fn type_input() -> TypeInfo {
+        TypeInfo::dict_of(K::type_input(), V::type_input())
+    }
+}
+
+impl<K, V> IntoPy<PyObject> for &'_ PyDictOf<K, V>
+where
+    K: IntoPy<PyObject>,
+    V: IntoPy<PyObject>,
+{
+    #[inline]
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        (&self.0).into_py(py)
+    }
+
+    #[cfg(feature = "experimental-inspect")]
+    fn type_output() -> TypeInfo {
+        TypeInfo::dict_of(K::type_output(), V::type_output())
+    }
+}
+
+/// Iterator over a [`PyListOf`], [`PyTupleOf`] or [`PySequenceOf`].
+pub struct PyTypedSequenceIterator<'py, T> {
+    /// The error is taken after it has been produced.
+    inner: Result<&'py PyIterator, Option<PyErr>>,
+    kind: &'static str,
+    index: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'py, T> Iterator for PyTypedSequenceIterator<'py, T>
+where
+    T: FromPyObject<'py>,
+{
+    type Item = PyResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match &mut self.inner {
+            Ok(iter) => match iter.next()? {
+                Ok(item) => item,
+                Err(err) => return Some(Err(err)),
+            },
+            Err(err) => return err.take().map(Err),
+        };
+        let index = self.index;
+        self.index += 1;
+        Some(extract_item(item, self.kind, index))
+    }
+}
+
+/// Iterator over a [`PyDictOf`].
+pub struct PyDictOfIterator<'py, K, V> {
+    inner: PyDictIterator<'py>,
+    _marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'py, K, V> Iterator for PyDictOfIterator<'py, K, V>
+where
+    K: FromPyObject<'py>,
+    V: FromPyObject<'py>,
+{
+    type Item = PyResult<(K, V)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.inner.next()?;
+        Some(extract_key(key).and_then(|k| Ok((k, extract_value(value, key)?))))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'py, K, V> ExactSizeIterator for PyDictOfIterator<'py, K, V>
+where
+    K: FromPyObject<'py>,
+    V: FromPyObject<'py>,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+fn extract_item<'py, T>(item: &'py PyAny, kind: &str, index: usize) -> PyResult<T>
+where
+    T: FromPyObject<'py>,
+{
+    item.extract().map_err(|inner_err| {
+        extraction_error(
+            item.py(),
+            inner_err,
+            format!("failed to extract {} item at index {}", kind, index),
+        )
+    })
+}
+
+fn extract_key<'py, K>(key: &'py PyAny) -> PyResult<K>
+where
+    K: FromPyObject<'py>,
+{
+    key.extract().map_err(|inner_err| {
+        extraction_error(
+            key.py(),
+            inner_err,
+            format!("failed to extract dict key {}", describe_key(key)),
+        )
+    })
+}
+
+fn extract_value<'py, V>(value: &'py PyAny, key: &PyAny) -> PyResult<V>
+where
+    V: FromPyObject<'py>,
+{
+    value.extract().map_err(|inner_err| {
+        extraction_error(
+            value.py(),
+            inner_err,
+            format!("failed to extract dict value for key {}", describe_key(key)),
+        )
+    })
+}
+
+fn describe_key(key: &PyAny) -> String {
+    match key.repr() {
+        Ok(repr) => repr.to_string_lossy().into_owned(),
+        Err(_) => "<unprintable key>".to_owned(),
+    }
+}
+
+fn extraction_error(py: Python<'_>, inner_err: PyErr, message: String) -> PyErr {
+    let new_err = PyTypeError::new_err(message);
+    new_err.set_cause(py, Some(inner_err));
+    new_err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::IntoPyDict;
+
+    #[test]
+    fn test_list_of() {
+        Python::with_gil(|py| {
+            let list: &PyListOf<i32> = py.eval("[1, 2, 3]", None, None).unwrap().extract().unwrap();
+            assert_eq!(list.len(), 3);
+            assert_eq!(list.get(1).unwrap(), 2);
+            assert!(list.get(3).is_err());
+            assert_eq!(list.to_vec().unwrap(), vec![1, 2, 3]);
+
+            list.append(4).unwrap();
+            list.insert(0, 0).unwrap();
+            list.set_item(1, 10).unwrap();
+            assert_eq!(list.to_vec().unwrap(), vec![0, 10, 2, 3, 4]);
+
+            let list: &PyListOf<i32> = py
+                .eval("[1, 'a', 3]", None, None)
+                .unwrap()
+                .extract()
+                .unwrap();
+            let err = list.get(1).unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+            assert_eq!(
+                err.value(py).to_string(),
+                "failed to extract list item at index 1"
+            );
+            assert!(err.cause(py).is_some());
+            let results: Vec<_> = list.iter().collect();
+            assert_eq!(results.len(), 3);
+            assert!(results[0].is_ok());
+            assert!(results[1].is_err());
+            assert!(results[2].is_ok());
+
+            assert!(py
+                .eval("(1, 2)", None, None)
+                .unwrap()
+                .extract::<&PyListOf<i32>>()
+                .is_err());
+        })
+    }
+
+    #[test]
+    fn test_tuple_and_sequence_of() {
+        Python::with_gil(|py| {
+            let tuple: &PyTupleOf<String> = py
+                .eval("('a', 'b')", None, None)
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(tuple.to_vec().unwrap(), vec!["a", "b"]);
+            assert_eq!(tuple.get(0).unwrap(), "a");
+
+            let obj = py.eval("range(3)", None, None).unwrap();
+            let seq: &PySequenceOf<usize> = obj.downcast::<PySequence>().unwrap().into();
+            assert_eq!(
+                seq.iter().collect::<PyResult<Vec<_>>>().unwrap(),
+                vec![0, 1, 2]
+            );
+
+            let seq: &PySequenceOf<usize> =
+                py.eval("[0, None]", None, None).unwrap().extract().unwrap();
+            seq.set_item(1, 1).unwrap();
+            assert_eq!(seq.to_vec().unwrap(), vec![0, 1]);
+
+            let tuple: &PyTupleOf<u8> = py.eval("(1, -1)", None, None).unwrap().extract().unwrap();
+            assert_eq!(
+                tuple.to_vec().unwrap_err().value(py).to_string(),
+                "failed to extract tuple item at index 1"
+            );
+        })
+    }
+
+    #[test]
+    fn test_sequence_of_iter_error() {
+        Python::with_gil(|py| {
+            let obj = py
+                .eval(
+                    "type('S', (list,), {'__iter__': lambda self: 1 / 0})([1])",
+                    None,
+                    None,
+                )
+                .unwrap();
+            let seq: &PySequenceOf<i32> = obj.extract().unwrap();
+            let mut iter = seq.iter();
+            let err = iter.next().unwrap().unwrap_err();
+            assert!(err.is_instance_of::<crate::exceptions::PyZeroDivisionError>(py));
+            assert!(iter.next().is_none());
+            assert!(seq.to_vec().is_err());
+        })
+    }
+
+    #[test]
+    fn test_dict_of() {
+        Python::with_gil(|py| {
+            let dict: &PyDictOf<String, i32> =
+                [("a", 1), ("b", 2)].into_py_dict(py).extract().unwrap();
+            assert_eq!(dict.get("a".to_owned()).unwrap(), Some(1));
+            assert_eq!(dict.get("z".to_owned()).unwrap(), None);
+            dict.set_item("c".to_owned(), 3).unwrap();
+            assert_eq!(dict.len(), 3);
+
+            let mut total = 0;
+            for item in dict {
+                let (_, value) = item.unwrap();
+                total += value;
+            }
+            assert_eq!(total, 6);
+
+            PyDict::set_item(dict, "d", "four").unwrap();
+            let err = dict.get("d".to_owned()).unwrap_err();
+            assert_eq!(
+                err.value(py).to_string(),
+                "failed to extract dict value for key 'd'"
+            );
+
+            dict.del_item("d").unwrap();
+            PyDict::set_item(dict, 5, 5).unwrap();
+            let errors: Vec<_> = dict.iter().filter_map(Result::err).collect();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(
+                errors[0].value(py).to_string(),
+                "failed to extract dict key 5"
+            );
+        })
+    }
+
+    #[test]
+    fn test_empty_constructors() {
+        Python::with_gil(|py| {
+            let list = PyListOf::<&str>::empty(py);
+            list.append("x").unwrap();
+            assert_eq!(list.to_vec().unwrap(), vec!["x"]);
+
+            let dict = PyDictOf::<&str, bool>::new(py);
+            dict.set_item("x", true).unwrap();
+            assert_eq!(dict.get("x").unwrap(), Some(true));
+        })
+    }
+
+    #[cfg(feature = "experimental-inspect")]
+    #[test]
+    fn test_type_info() {
+        assert_eq!(
+            <&PyListOf<i32> as FromPyObject<'_>>::type_input().to_string(),
+            "List[int]"
+        );
+        assert_eq!(
+            <&PyListOf<i32> as IntoPy<PyObject>>::type_output().to_string(),
+            "List[int]"
+        );
+        assert_eq!(
+            <&PySequenceOf<String> as FromPyObject<'_>>::type_input().to_string(),
+            "Sequence[str]"
+        );
+        assert_eq!(
+            <&PyDictOf<String, i32> as FromPyObject<'_>>::type_input().to_string(),
+            "Dict[str, int]"
+        );
+    }
+}
@@ -0,0 +1,48 @@
+#![cfg(all(Py_3_8, not(any(PyPy, Py_LIMITED_API))))]
+
+use pyo3::exceptions::PyPermissionError;
+use pyo3::prelude::*;
+use pyo3::sandbox::{add_audit_hook, audit};
+use pyo3::types::PyTuple;
+use std::sync::{Arc, Mutex};
+
+// Kept in its own test binary, as audit hooks cannot be removed and see the events of all tests
+// in the process.
+#[test]
+fn test_audit_hook() {
+    Python::with_gil(|py| {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = events.clone();
+        add_audit_hook(py, move |event, args: &PyTuple| {
+            if !event.starts_with("pyo3_test.") {
+                return Ok(());
+            }
+            recorded
+                .lock()
+                .unwrap()
+                .push((event.to_owned(), args.to_string()));
+            if event == "pyo3_test.deny" {
+                return Err(PyPermissionError::new_err("denied"));
+            }
+            Ok(())
+        })
+        .unwrap();
+
+        audit(py, "pyo3_test.allow", (1, "a")).unwrap();
+        let err = audit(py, "pyo3_test.deny", ()).unwrap_err();
+        assert!(err.is_instance_of::<PyPermissionError>(py));
+        let err = py
+            .run("import sys; sys.audit('pyo3_test.deny', 2)", None, None)
+            .unwrap_err();
+        assert!(err.is_instance_of::<PyPermissionError>(py));
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                ("pyo3_test.allow".to_owned(), "(1, 'a')".to_owned()),
+                ("pyo3_test.deny".to_owned(), "()".to_owned()),
+                ("pyo3_test.deny".to_owned(), "(2,)".to_owned()),
+            ]
+        );
+    });
+}
@@ -138,3 +138,19 @@ fn test_get_buffer_errors() {
         );
     });
 }
+
+#[test]
+fn test_detach_slice_in_allow_threads() {
+    Python::with_gil(|py| {
+        let bytes = py.eval("bytes(range(256)) * 64", None, None).unwrap();
+        let buffer = PyBuffer::<u8>::get(bytes).unwrap();
+        let data = buffer.detach_slice(py).unwrap();
+        let sum = py.allow_threads(move || data.iter().map(|&b| u64::from(b)).sum::<u64>());
+        assert_eq!(sum, 64 * (0..256).sum::<u64>());
+
+        // Writable buffers may change while the GIL is released.
+        let bytearray = py.eval("bytearray(16)", None, None).unwrap();
+        let buffer = PyBuffer::<u8>::get(bytearray).unwrap();
+        assert!(buffer.detach_slice(py).is_none());
+    });
+}
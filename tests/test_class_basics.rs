@@ -1,7 +1,7 @@
 #![cfg(feature = "macros")]
 
 use pyo3::prelude::*;
-use pyo3::types::PyType;
+use pyo3::types::{IntoPyDict, PyType};
 use pyo3::{py_run, PyClass};
 
 mod common;
@@ -435,6 +435,106 @@ fn inherited_dict() {
     });
 }
 
+#[test]
+#[cfg(not(any(PyPy, all(Py_LIMITED_API, not(Py_3_10)))))]
+fn pycell_dict() {
+    Python::with_gil(|py| {
+        let inst = PyCell::new(
+            py,
+            DunderDictSupport {
+                _pad: *b"DEADBEEFDEADBEEFDEADBEEFDEADBEEF",
+            },
+        )
+        .unwrap();
+        let dict = inst.dict().unwrap();
+        assert!(dict.is_empty());
+        dict.set_item("a", 1).unwrap();
+        py_run!(py, inst, "assert inst.a == 1; inst.b = 2");
+        assert_eq!(dict.get_item("b").unwrap().extract::<i32>().unwrap(), 2);
+        assert!(inst.dict().unwrap().is(dict));
+    });
+}
+
+#[pyclass(subclass)]
+struct SlotsSupport {
+    #[pyo3(get)]
+    value: usize,
+}
+
+#[pymethods]
+impl SlotsSupport {
+    #[new]
+    fn new(value: usize) -> Self {
+        SlotsSupport { value }
+    }
+}
+
+#[test]
+fn python_subclass_with_slots() {
+    Python::with_gil(|py| {
+        let cls = py.get_type::<SlotsSupport>();
+        let inst = py
+            .eval(
+                "type('WithSlots', (cls,), {'__slots__': ('extra',)})(5)",
+                None,
+                Some([("cls", cls)].into_py_dict(py)),
+            )
+            .unwrap();
+        py_run!(
+            py,
+            inst,
+            r#"
+        inst.extra = [1, 2]
+        assert inst.extra == [1, 2]
+        assert inst.value == 5
+        assert not hasattr(inst, "__dict__")
+        try:
+            inst.other = 1
+        except AttributeError:
+            pass
+        else:
+            assert False, "instances with __slots__ should not accept new attributes"
+    "#
+        );
+
+        let cell: &PyCell<SlotsSupport> = inst.downcast().unwrap();
+        assert_eq!(cell.borrow().value, 5);
+        #[cfg(not(any(PyPy, all(Py_LIMITED_API, not(Py_3_10)))))]
+        assert!(cell
+            .dict()
+            .unwrap_err()
+            .is_instance_of::<pyo3::exceptions::PyAttributeError>(py));
+
+        #[cfg(not(any(PyPy, Py_LIMITED_API)))]
+        {
+            use pyo3::exceptions::PyAttributeError;
+
+            let extra = cell.get_slot("extra").unwrap().unwrap();
+            assert_eq!(extra.extract::<Vec<i32>>().unwrap(), [1, 2]);
+            cell.set_slot("extra", "replaced").unwrap();
+            py_run!(py, inst, "assert inst.extra == 'replaced'");
+
+            cell.del_slot("extra").unwrap();
+            assert!(cell.get_slot("extra").unwrap().is_none());
+            py_run!(py, inst, "assert not hasattr(inst, 'extra')");
+            assert!(cell
+                .del_slot("extra")
+                .unwrap_err()
+                .is_instance_of::<PyAttributeError>(py));
+
+            // `value` is a Rust getter, not a slot
+            for name in ["value", "missing"].iter() {
+                let err = cell.get_slot(*name).unwrap_err();
+                assert!(err.is_instance_of::<PyAttributeError>(py));
+                assert_eq!(
+                    err.value(py).to_string(),
+                    format!("'WithSlots' object has no slot '{}'", name)
+                );
+            }
+        }
+    });
+}
+
 #[pyclass(weakref, dict)]
 struct WeakRefDunderDictSupport {
     // Make sure that weaklist_offset runs with non-zero sized Self
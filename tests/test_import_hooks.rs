@@ -0,0 +1,146 @@
+#![cfg(all(feature = "macros", not(Py_LIMITED_API)))]
+
+use pyo3::import_hooks::{add_meta_path_finder, EmbeddedModules, FoundModule, ModuleFinder};
+use pyo3::prelude::*;
+use pyo3::py_run;
+use pyo3::types::PyDict;
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Counts how often modules are looked up.
+struct CountingFinder {
+    modules: EmbeddedModules,
+    lookups: Arc<AtomicUsize>,
+}
+
+impl ModuleFinder for CountingFinder {
+    fn find_module(&self, fullname: &str) -> Option<FoundModule> {
+        self.lookups.fetch_add(1, Ordering::SeqCst);
+        self.modules.find_module(fullname)
+    }
+
+    fn resource(&self, package: &str, name: &str) -> Option<Cow<'static, [u8]>> {
+        ModuleFinder::resource(&self.modules, package, name)
+    }
+
+    fn resource_names(&self, package: &str) -> Vec<String> {
+        self.modules.resource_names(package)
+    }
+}
+
+#[test]
+fn test_package_imports() {
+    Python::with_gil(|py| {
+        let lookups = Arc::new(AtomicUsize::new(0));
+        let modules = EmbeddedModules::new()
+            .package("hooks_app", "from .models import shapes")
+            .package("hooks_app.models", "")
+            .module(
+                "hooks_app.models.shapes",
+                "from ..geometry import area\nSQUARE = area(3)",
+            )
+            .module(
+                "hooks_app.geometry",
+                "def area(side):\n    return side * side",
+            );
+        add_meta_path_finder(
+            py,
+            CountingFinder {
+                modules,
+                lookups: lookups.clone(),
+            },
+        )
+        .unwrap();
+
+        py_run!(
+            py,
+            *PyDict::new(py),
+            r#"
+import hooks_app
+from hooks_app.models.shapes import SQUARE
+
+assert SQUARE == 9
+assert hooks_app.shapes.SQUARE == 9
+assert hooks_app.__package__ == "hooks_app"
+assert hooks_app.models.__path__ == []
+assert hooks_app.geometry.__spec__.origin == "hooks_app/geometry.py"
+assert not hooks_app.geometry.__spec__.submodule_search_locations
+"#
+        );
+        // Each module is looked up once, by `find_spec`, and not again while it is loaded.
+        assert_eq!(lookups.load(Ordering::SeqCst), 4);
+
+        let err = py.import("hooks_app.missing").unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyModuleNotFoundError>(py));
+    });
+}
+
+#[test]
+fn test_resources() {
+    Python::with_gil(|py| {
+        let modules = EmbeddedModules::new()
+            .package("hooks_resources", "")
+            .resource("hooks_resources", "config.toml", &b"answer = 42"[..])
+            .resource("hooks_resources", "logo.bin", &b"\x00\x01"[..]);
+        add_meta_path_finder(py, modules).unwrap();
+
+        py_run!(
+            py,
+            *PyDict::new(py),
+            r#"
+import importlib.resources
+
+if hasattr(importlib.resources, "files"):
+    files = importlib.resources.files("hooks_resources")
+    assert files.is_dir()
+    assert sorted(f.name for f in files.iterdir()) == ["config.toml", "logo.bin"]
+    assert files.joinpath("config.toml").is_file()
+    assert files.joinpath("config.toml").read_text() == "answer = 42"
+    assert (files / "logo.bin").read_bytes() == b"\x00\x01"
+    with (files / "config.toml").open() as f:
+        assert f.read() == "answer = 42"
+    assert not (files / "missing.txt").is_file()
+    try:
+        (files / "missing.txt").read_bytes()
+    except FileNotFoundError:
+        pass
+    else:
+        raise AssertionError("missing resource was read")
+else:
+    assert importlib.resources.read_text("hooks_resources", "config.toml") == "answer = 42"
+    assert importlib.resources.read_binary("hooks_resources", "logo.bin") == b"\x00\x01"
+    assert not importlib.resources.is_resource("hooks_resources", "missing.txt")
+"#
+        );
+    });
+}
+
+#[test]
+fn test_traceback_source_lines() {
+    Python::with_gil(|py| {
+        let modules = EmbeddedModules::new().module(
+            "hooks_traceback",
+            "def check(value):\n    if value < 0:\n        raise ValueError('negative value')",
+        );
+        add_meta_path_finder(py, modules).unwrap();
+
+        py_run!(
+            py,
+            *PyDict::new(py),
+            r#"
+import inspect
+import traceback
+import hooks_traceback
+
+try:
+    hooks_traceback.check(-1)
+except ValueError:
+    tb = traceback.format_exc()
+assert 'hooks_traceback.py", line 3, in check' in tb, tb
+assert "raise ValueError('negative value')" in tb, tb
+assert inspect.getsource(hooks_traceback.check).startswith("def check(value):")
+"#
+        );
+    });
+}
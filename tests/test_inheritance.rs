@@ -362,3 +362,218 @@ fn module_add_class_inherit_bool_fails() {
         );
     })
 }
+
+#[pyclass(extends_py = "collections.abc.Sized")]
+struct SizedFromPython {
+    len: usize,
+}
+
+#[pymethods]
+impl SizedFromPython {
+    #[new]
+    fn new(len: usize) -> Self {
+        SizedFromPython { len }
+    }
+
+    fn __len__(&self) -> usize {
+        self.len
+    }
+}
+
+// Python 3.14 rejects `abc.ABCMeta` bases, see `extends_python_abc_rejected`.
+#[test]
+#[cfg(not(Py_3_14))]
+fn extends_python_class() {
+    Python::with_gil(|py| {
+        let cls = py.get_type::<SizedFromPython>();
+        py_run!(
+            py,
+            cls,
+            r#"
+            from collections.abc import Sized
+            assert issubclass(cls, Sized)
+            obj = cls(3)
+            assert isinstance(obj, Sized)
+            assert len(obj) == 3
+            "#
+        );
+
+        let obj = Py::new(py, SizedFromPython::new(5)).unwrap();
+        assert_eq!(obj.as_ref(py).len().unwrap(), 5);
+    });
+}
+
+#[test]
+fn extends_python_class_incompatible_layout() {
+    #[pyclass(extends_py = "enum.Enum")]
+    struct ExtendsEnum;
+
+    Python::with_gil(|py| {
+        let m = PyModule::new(py, "test_module").unwrap();
+
+        let err = m.add_class::<ExtendsEnum>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "RuntimeError: An error occurred while initializing class ExtendsEnum"
+        );
+        assert!(err
+            .cause(py)
+            .unwrap()
+            .to_string()
+            .starts_with("TypeError: `enum.Enum` cannot be used as the base of `ExtendsEnum`"));
+    })
+}
+
+#[test]
+#[cfg(not(Py_LIMITED_API))]
+fn extends_python_class_not_a_subclass() {
+    use pyo3::types::PyDict;
+
+    #[pyclass(extends = PyDict, extends_py = "collections.abc.Sized")]
+    struct ExtendsDictAndSized;
+
+    Python::with_gil(|py| {
+        let m = PyModule::new(py, "test_module").unwrap();
+
+        let err = m.add_class::<ExtendsDictAndSized>().unwrap_err();
+        assert_eq!(
+            err.cause(py).unwrap().to_string(),
+            "TypeError: `collections.abc.Sized` cannot be used as the base of \
+             `ExtendsDictAndSized`: it is not a subclass of `dict`"
+        );
+    })
+}
+
+const ABSTRACT_SHAPES: &str = r#"
+import abc
+
+class Shape(abc.ABC):
+    __slots__ = ()
+
+    @abc.abstractmethod
+    def area(self): ...
+
+    def describe(self):
+        return f"shape with area {self.area()}"
+
+class Meta(type):
+    pass
+
+class WithMeta(metaclass=Meta):
+    __slots__ = ()
+"#;
+
+fn import_abstract_shapes(py: Python<'_>) {
+    PyModule::from_code(py, ABSTRACT_SHAPES, "abstract_shapes.py", "abstract_shapes").unwrap();
+}
+
+#[pyclass(extends_py = "abstract_shapes.Shape")]
+struct Square {
+    side: f64,
+}
+
+#[pymethods]
+impl Square {
+    #[new]
+    fn new(side: f64) -> Self {
+        Square { side }
+    }
+
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+}
+
+#[pyclass(extends_py = "abstract_shapes.Shape")]
+struct Shapeless;
+
+#[pymethods]
+impl Shapeless {
+    #[new]
+    fn new() -> Self {
+        Shapeless
+    }
+}
+
+#[test]
+#[cfg(not(Py_3_14))]
+fn extends_python_abc_implemented() {
+    Python::with_gil(|py| {
+        import_abstract_shapes(py);
+        let cls = py.get_type::<Square>();
+        py_run!(
+            py,
+            cls,
+            r#"
+            from abstract_shapes import Shape
+            square = cls(2.0)
+            assert isinstance(square, Shape)
+            assert square.describe() == "shape with area 4.0"
+            assert cls.__abstractmethods__ == frozenset()
+            "#
+        );
+        // Since Python 3.12 the metaclass is derived from the bases, i.e. it is `ABCMeta`.
+        #[cfg(not(Py_3_12))]
+        py_run!(py, cls, "assert type(cls) is type");
+    });
+}
+
+#[test]
+#[cfg(Py_3_14)]
+fn extends_python_abc_rejected() {
+    Python::with_gil(|py| {
+        import_abstract_shapes(py);
+        let m = PyModule::new(py, "test_module").unwrap();
+        let err = m.add_class::<Square>().unwrap_err();
+        assert!(err
+            .cause(py)
+            .unwrap()
+            .to_string()
+            .contains("its metaclass `ABCMeta` is not supported, only `type` is"));
+    });
+}
+
+#[test]
+#[cfg(not(Py_3_14))]
+fn extends_python_abc_not_implemented() {
+    Python::with_gil(|py| {
+        import_abstract_shapes(py);
+        let cls = py.get_type::<Shapeless>();
+        py_expect_exception!(
+            py,
+            cls,
+            "cls()",
+            PyTypeError,
+            "Can't instantiate abstract class Shapeless with abstract method area"
+        );
+
+        let err = Py::new(py, Shapeless).unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+    });
+}
+
+#[test]
+fn extends_python_class_unsupported_metaclass() {
+    #[pyclass(extends_py = "abstract_shapes.WithMeta")]
+    struct ExtendsWithMeta;
+
+    Python::with_gil(|py| {
+        import_abstract_shapes(py);
+        let m = PyModule::new(py, "test_module").unwrap();
+
+        let err = m.add_class::<ExtendsWithMeta>().unwrap_err();
+        let supported = if cfg!(Py_3_14) {
+            "`type` is"
+        } else {
+            "`type` and `abc.ABCMeta` are"
+        };
+        assert_eq!(
+            err.cause(py).unwrap().to_string(),
+            format!(
+                "TypeError: `abstract_shapes.WithMeta` cannot be used as the base of \
+                 `ExtendsWithMeta`: its metaclass `Meta` is not supported, only {}",
+                supported
+            )
+        );
+    })
+}
@@ -0,0 +1,72 @@
+#![cfg(all(Py_3_8, not(any(PyPy, Py_LIMITED_API))))]
+
+use pyo3::interpreter::{InitializationError, InterpreterBuilder};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+// The interpreter can only be initialized once per process, so everything is checked in a single
+// test.
+#[test]
+fn test_interpreter_builder() {
+    let err = InterpreterBuilder::new()
+        .argv(["prog", "nul\0byte"])
+        .initialize()
+        .unwrap_err();
+    assert_eq!(err, InitializationError::InteriorNul("nul\0byte".into()));
+
+    let guard = InterpreterBuilder::new()
+        .isolated(true)
+        .utf8_mode(true)
+        .ignore_environment(true)
+        .site_import(false)
+        .argv(["prog", "--not-a-python-option"])
+        .initialize()
+        .unwrap();
+
+    assert_eq!(
+        InterpreterBuilder::new().initialize().unwrap_err(),
+        InitializationError::AlreadyInitialized
+    );
+
+    Python::with_gil(|py| {
+        let locals = PyDict::new(py);
+        py.run(
+            r#"
+import sys
+assert sys.argv == ["prog", "--not-a-python-option"], sys.argv
+assert sys.flags.isolated == 1
+assert sys.flags.utf8_mode == 1
+assert sys.flags.ignore_environment == 1
+assert sys.flags.no_site == 1
+assert "site" not in sys.modules
+"#,
+            None,
+            Some(locals),
+        )
+        .unwrap();
+    });
+
+    // Python's SIGINT handler was not installed.
+    #[cfg(unix)]
+    unsafe {
+        let mut action: libc::sigaction = std::mem::zeroed();
+        assert_eq!(
+            libc::sigaction(libc::SIGINT, std::ptr::null(), &mut action),
+            0
+        );
+        assert_eq!(action.sa_sigaction, libc::SIG_DFL);
+    }
+
+    // The GIL can be acquired from other threads.
+    std::thread::spawn(|| Python::with_gil(|py| py.version().to_owned()))
+        .join()
+        .unwrap();
+
+    guard.finalize().unwrap();
+
+    assert!(std::panic::catch_unwind(|| Python::with_gil(|_| ())).is_err());
+    assert_eq!(
+        InterpreterBuilder::new().initialize().unwrap_err(),
+        InitializationError::AlreadyInitialized
+    );
+}
@@ -0,0 +1,58 @@
+#![cfg(unix)]
+
+use pyo3::exceptions::PyKeyboardInterrupt;
+use pyo3::prelude::*;
+use std::os::raw::{c_int, c_void};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Kept in its own test binary, as `allow_threads_interruptible` installs a process-wide `SIGINT`
+// handler.
+
+static CALLED_WITH_INFO: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn siginfo_handler(_signum: c_int, info: *mut libc::siginfo_t, _context: *mut c_void) {
+    CALLED_WITH_INFO.store(!info.is_null(), Ordering::SeqCst);
+}
+
+#[test]
+fn test_allow_threads_interruptible() {
+    Python::with_gil(|py| {
+        // Install Python's handler, as `python` does.
+        py.run(
+            "import signal; signal.signal(signal.SIGINT, signal.default_int_handler)",
+            None,
+            None,
+        )
+        .unwrap();
+
+        let result = py.allow_threads_interruptible(|token| token.is_cancelled());
+        assert!(!result.unwrap());
+
+        let result = py.allow_threads_interruptible(|token| {
+            unsafe { libc::raise(libc::SIGINT) };
+            token.is_cancelled()
+        });
+        assert!(result
+            .unwrap_err()
+            .is_instance_of::<PyKeyboardInterrupt>(py));
+
+        // A handler installed with `SA_SIGINFO` is called with all of its arguments.
+        unsafe {
+            let mut action: libc::sigaction = std::mem::zeroed();
+            action.sa_sigaction = siginfo_handler
+                as extern "C" fn(c_int, *mut libc::siginfo_t, *mut c_void)
+                as libc::sighandler_t;
+            action.sa_flags = libc::SA_SIGINFO;
+            assert_eq!(
+                libc::sigaction(libc::SIGINT, &action, std::ptr::null_mut()),
+                0
+            );
+        }
+        let result = py.allow_threads_interruptible(|token| {
+            unsafe { libc::raise(libc::SIGINT) };
+            token.is_cancelled()
+        });
+        assert!(result.unwrap());
+        assert!(CALLED_WITH_INFO.load(Ordering::SeqCst));
+    });
+}
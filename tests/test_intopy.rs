@@ -0,0 +1,142 @@
+#![cfg(feature = "macros")]
+
+use pyo3::prelude::*;
+use pyo3::py_run;
+use pyo3::types::PyDict;
+
+#[macro_use]
+mod common;
+
+#[derive(Debug, Clone, PartialEq, FromPyObject, IntoPy)]
+struct Point {
+    x: i32,
+    #[pyo3(attribute("y_coord"))]
+    y: i32,
+}
+
+#[test]
+fn test_struct_into_namespace() {
+    Python::with_gil(|py| {
+        let point = Point { x: 1, y: 2 }.into_py(py);
+        py_run!(
+            py,
+            point,
+            r#"
+            import types
+            assert isinstance(point, types.SimpleNamespace)
+            assert vars(point) == {"x": 1, "y_coord": 2}
+            "#
+        );
+        assert_eq!(point.extract::<Point>(py).unwrap(), Point { x: 1, y: 2 });
+    });
+}
+
+#[derive(Debug, Clone, PartialEq, FromPyObject, IntoPy)]
+struct Record {
+    #[pyo3(item)]
+    name: String,
+    #[pyo3(item("value"))]
+    val: Option<f64>,
+    #[pyo3(item(0))]
+    first: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, FromPyObject, IntoPy)]
+#[pyo3(from_item_all)]
+struct Config<T> {
+    path: String,
+    #[pyo3(item("inner"))]
+    data: T,
+}
+
+#[test]
+fn test_struct_into_dict() {
+    Python::with_gil(|py| {
+        let record = Record {
+            name: "a".to_owned(),
+            val: None,
+            first: true,
+        };
+        let obj = record.clone().into_py(py);
+        py_assert!(py, obj, "obj == {'name': 'a', 'value': None, 0: True}");
+        assert_eq!(obj.extract::<Record>(py).unwrap(), record);
+
+        let config = Config {
+            path: "p".to_owned(),
+            data: record.clone(),
+        };
+        let obj = config.clone().into_py(py);
+        py_assert!(py, obj, "type(obj) is dict");
+        py_assert!(py, obj, "obj['inner']['name'] == 'a'");
+        assert_eq!(obj.extract::<Config<Record>>(py).unwrap(), config);
+    });
+}
+
+#[derive(Debug, Clone, PartialEq, FromPyObject, IntoPy)]
+struct Pair(String, i64);
+
+#[derive(Debug, Clone, PartialEq, FromPyObject, IntoPy)]
+struct Wrapper(Vec<u8>);
+
+#[derive(Debug, Clone, PartialEq, FromPyObject, IntoPy)]
+#[pyo3(transparent)]
+struct Transparent {
+    inner: String,
+}
+
+#[test]
+fn test_tuple_and_newtype() {
+    Python::with_gil(|py| {
+        let pair = Pair("a".to_owned(), 5).into_py(py);
+        py_assert!(py, pair, "pair == ('a', 5)");
+        assert_eq!(pair.extract::<Pair>(py).unwrap(), Pair("a".to_owned(), 5));
+
+        let wrapper = Wrapper(vec![1, 2]).into_py(py);
+        py_assert!(py, wrapper, "wrapper == [1, 2]");
+
+        let transparent = Transparent {
+            inner: "x".to_owned(),
+        }
+        .into_py(py);
+        py_assert!(py, transparent, "transparent == 'x'");
+        assert_eq!(transparent.extract::<Transparent>(py).unwrap().inner, "x");
+    });
+}
+
+#[derive(Debug, Clone, PartialEq, FromPyObject, IntoPy)]
+enum Shape {
+    Circle(f64),
+    Rect(u32, u32),
+    #[pyo3(transparent)]
+    Named {
+        name: String,
+    },
+    Point {
+        #[pyo3(item)]
+        x: i32,
+        #[pyo3(item)]
+        y: i32,
+    },
+}
+
+#[test]
+fn test_enum_round_trip() {
+    Python::with_gil(|py| {
+        let shapes = [
+            Shape::Rect(1, 2),
+            Shape::Circle(1.5),
+            Shape::Named {
+                name: "n".to_owned(),
+            },
+            Shape::Point { x: 3, y: 4 },
+        ];
+        for shape in shapes {
+            let obj = shape.clone().into_py(py);
+            assert_eq!(obj.extract::<Shape>(py).unwrap(), shape);
+        }
+        let point = Shape::Point { x: 3, y: 4 }.into_py(py);
+        let dict: &PyDict = point.downcast(py).unwrap();
+        assert_eq!(dict.len(), 2);
+        py_assert!(py, point, "point == {'x': 3, 'y': 4}");
+    });
+}
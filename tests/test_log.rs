@@ -0,0 +1,136 @@
+#![cfg(feature = "log")]
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use pyo3::log::{forward_python_logging, PythonLogger};
+use pyo3::prelude::*;
+use std::sync::Mutex;
+
+type CapturedRecord = (Level, String, String, Option<u32>);
+
+struct Capture(Mutex<Vec<CapturedRecord>>);
+
+impl Log for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        self.0.lock().unwrap().push((
+            record.level(),
+            record.target().to_owned(),
+            record.args().to_string(),
+            record.line(),
+        ));
+    }
+
+    fn flush(&self) {}
+}
+
+static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+
+// The Rust logger can only be set once per process, so everything is checked in a single test.
+#[test]
+fn test_logging_bridge() {
+    log::set_logger(&CAPTURE).unwrap();
+    log::set_max_level(LevelFilter::Debug);
+
+    Python::with_gil(|py| {
+        let handler = forward_python_logging(py).unwrap();
+        // The handler class is only created once.
+        let other = forward_python_logging(py).unwrap();
+        assert!(handler.get_type().is(other.get_type()));
+        let root = py
+            .import("logging")
+            .unwrap()
+            .call_method0("getLogger")
+            .unwrap();
+        root.call_method1("removeHandler", (other,)).unwrap();
+        py.run(
+            r#"
+import logging
+logger = logging.getLogger("app.db")
+logger.debug("connected to %s", "db")
+logger.log(5, "dropped by the root logger level")
+try:
+    1 / 0
+except ZeroDivisionError:
+    logger.exception("query failed")
+"#,
+            None,
+            None,
+        )
+        .unwrap();
+
+        // Records emitted by `PythonLogger` are not forwarded back to Rust, but still reach the
+        // other Python handlers.
+        let records: &PyAny = py
+            .eval(
+                "type('Handler', (__import__('logging').Handler,), {'records': [], 'emit': lambda self, r: self.records.append(r)})()",
+                None,
+                None,
+            )
+            .unwrap();
+        root.call_method1("addHandler", (records,)).unwrap();
+        PythonLogger.log(
+            &Record::builder()
+                .level(Level::Warn)
+                .target("my_crate::module")
+                .args(format_args!("from {}", "rust"))
+                .line(Some(7))
+                .build(),
+        );
+        root.call_method1("removeHandler", (records,)).unwrap();
+        root.call_method1("removeHandler", (handler,)).unwrap();
+
+        let records = records.getattr("records").unwrap();
+        assert_eq!(records.len().unwrap(), 1);
+        let record = records.get_item(0).unwrap();
+        assert_eq!(
+            record.getattr("name").unwrap().extract::<&str>().unwrap(),
+            "my_crate.module"
+        );
+        assert_eq!(
+            record
+                .call_method0("getMessage")
+                .unwrap()
+                .extract::<&str>()
+                .unwrap(),
+            "from rust"
+        );
+        assert_eq!(
+            record.getattr("lineno").unwrap().extract::<u32>().unwrap(),
+            7
+        );
+        assert_eq!(
+            record
+                .getattr("levelname")
+                .unwrap()
+                .extract::<&str>()
+                .unwrap(),
+            "WARNING"
+        );
+    });
+
+    let captured = CAPTURE.0.lock().unwrap();
+    assert_eq!(captured.len(), 2, "{:?}", captured);
+    assert_eq!(
+        captured[0],
+        (
+            Level::Debug,
+            "app.db".to_owned(),
+            "connected to db".to_owned(),
+            Some(4)
+        )
+    );
+    let (level, target, message, line) = &captured[1];
+    assert_eq!(
+        (*level, target.as_str(), *line),
+        (Level::Error, "app.db", Some(9))
+    );
+    assert!(
+        message.starts_with("query failed\nTraceback"),
+        "{}",
+        message
+    );
+    assert!(message.contains("ZeroDivisionError"), "{}", message);
+}
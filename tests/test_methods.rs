@@ -111,6 +111,93 @@ fn class_method_with_args() {
     });
 }
 
+#[pyclass(subclass)]
+struct SubclassHooks {}
+
+#[pymethods]
+impl SubclassHooks {
+    #[new]
+    fn new() -> Self {
+        SubclassHooks {}
+    }
+
+    #[pyo3(signature = (**kwargs))]
+    fn __init_subclass__(cls: &PyType, kwargs: Option<&PyDict>) -> PyResult<()> {
+        let tag = match kwargs.and_then(|kwargs| kwargs.get_item("tag")) {
+            Some(tag) => tag,
+            None => cls.py().None().into_ref(cls.py()),
+        };
+        cls.setattr("tag", tag)
+    }
+
+    fn __class_getitem__(cls: &PyType, item: &PyAny) -> PyResult<String> {
+        Ok(format!("{}[{}]", cls.name()?, item.repr()?))
+    }
+}
+
+#[test]
+fn init_subclass_and_class_getitem() {
+    Python::with_gil(|py| {
+        let cls = py.get_type::<SubclassHooks>();
+        py_run!(
+            py,
+            cls,
+            r#"
+            class Untagged(cls):
+                pass
+            assert Untagged.tag is None
+
+            class Tagged(cls, tag="abc"):
+                pass
+            assert Tagged.tag == "abc"
+
+            assert cls[int] == "SubclassHooks[<class 'int'>]"
+            assert Tagged["x"] == "Tagged['x']"
+            "#
+        );
+    });
+}
+
+#[pyclass]
+struct SetNameDescriptor {
+    #[pyo3(get)]
+    owner: Option<PyObject>,
+    #[pyo3(get)]
+    name: Option<String>,
+}
+
+#[pymethods]
+impl SetNameDescriptor {
+    #[new]
+    fn new() -> Self {
+        SetNameDescriptor {
+            owner: None,
+            name: None,
+        }
+    }
+
+    fn __set_name__(&mut self, owner: PyObject, name: String) {
+        self.owner = Some(owner);
+        self.name = Some(name);
+    }
+}
+
+#[test]
+fn set_name() {
+    Python::with_gil(|py| {
+        let descriptor = py.get_type::<SetNameDescriptor>();
+        py_run!(
+            py,
+            descriptor,
+            r#"
+            Owner = type("Owner", (), {"attr": descriptor()})
+            assert Owner.attr.owner is Owner
+            assert Owner.attr.name == "attr"
+            "#
+        );
+    });
+}
+
 #[pyclass]
 struct StaticMethod {}
 
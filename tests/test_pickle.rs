@@ -0,0 +1,403 @@
+#![cfg(feature = "macros")]
+
+use pyo3::prelude::*;
+use pyo3::py_run;
+
+mod common;
+
+/// Pickle looks classes up by `__module__`, so the classes under test must be importable.
+fn register_module(py: Python<'_>) -> &PyModule {
+    let module = PyModule::new(py, "test_pickle").unwrap();
+    module.add_class::<Point>().unwrap();
+    module.add_class::<Tagged>().unwrap();
+    module.add_class::<Renamed>().unwrap();
+    module.add_class::<Color>().unwrap();
+    #[cfg(feature = "serde")]
+    module.add_class::<serde_pickle::Shape>().unwrap();
+    py.import("sys")
+        .unwrap()
+        .getattr("modules")
+        .unwrap()
+        .set_item("test_pickle", module)
+        .unwrap();
+    module
+}
+
+#[pyclass(module = "test_pickle", pickle)]
+#[derive(Clone)]
+struct Point {
+    #[pyo3(get)]
+    x: i64,
+    #[pyo3(get, name = "y_coord")]
+    y: i64,
+    // Not exposed to Python, so not passed to `#[new]`.
+    scratch: Vec<i64>,
+}
+
+#[pymethods]
+impl Point {
+    #[new]
+    fn new(x: i64, y_coord: i64) -> Self {
+        Point {
+            x,
+            y: y_coord,
+            scratch: Vec::new(),
+        }
+    }
+
+    fn push_scratch(&mut self, value: i64) {
+        self.scratch.push(value);
+    }
+
+    fn scratch(&self) -> Vec<i64> {
+        self.scratch.clone()
+    }
+}
+
+#[test]
+fn pickle_from_new_arguments() {
+    Python::with_gil(|py| {
+        let module = register_module(py);
+        py_run!(
+            py,
+            module,
+            r#"
+            import pickle
+            p = module.Point(1, y_coord=2)
+            assert p.__getnewargs_ex__() == ((), {"x": 1, "y_coord": 2})
+            for protocol in range(2, pickle.HIGHEST_PROTOCOL + 1):
+                q = pickle.loads(pickle.dumps(p, protocol))
+                assert type(q) is module.Point
+                assert (q.x, q.y_coord) == (1, 2)
+            "#
+        );
+    });
+}
+
+#[test]
+fn copy_clones_rust_value() {
+    Python::with_gil(|py| {
+        let module = register_module(py);
+        py_run!(
+            py,
+            module,
+            r#"
+            import copy
+            p = module.Point(1, 2)
+            p.push_scratch(3)
+            for q in (copy.copy(p), copy.deepcopy(p)):
+                assert q is not p
+                assert (q.x, q.y_coord, q.scratch()) == (1, 2, [3])
+            q.push_scratch(4)
+            assert p.scratch() == [3]
+            "#
+        );
+    });
+}
+
+/// The `#[new]` parameter is not named after the getter, which is only detected on unpickling.
+#[pyclass(module = "test_pickle", pickle)]
+#[derive(Clone)]
+struct Renamed {
+    #[pyo3(get)]
+    value: i64,
+}
+
+#[pymethods]
+impl Renamed {
+    #[new]
+    fn new(initial: i64) -> Self {
+        Renamed { value: initial }
+    }
+}
+
+#[test]
+fn pickle_new_signature_mismatch() {
+    Python::with_gil(|py| {
+        let module = register_module(py);
+        py_run!(
+            py,
+            module,
+            r#"
+            import pickle
+            data = pickle.dumps(module.Renamed(1))
+            try:
+                pickle.loads(data)
+            except TypeError as e:
+                assert "unexpected keyword argument 'value'" in str(e), str(e)
+            else:
+                assert False, "unpickling should fail"
+            "#
+        );
+    });
+}
+
+#[pyclass(module = "test_pickle", dict, subclass, pickle)]
+#[derive(Clone)]
+struct Tagged {
+    #[pyo3(get)]
+    tag: String,
+}
+
+#[pymethods]
+impl Tagged {
+    #[new]
+    fn new(tag: String) -> Self {
+        Tagged { tag }
+    }
+}
+
+#[test]
+fn pickle_preserves_instance_dict() {
+    Python::with_gil(|py| {
+        let module = register_module(py);
+        py_run!(
+            py,
+            module,
+            r#"
+            import copy, pickle
+            t = module.Tagged("a")
+            t.extra = [1, 2]
+            u = pickle.loads(pickle.dumps(t))
+            assert (u.tag, u.extra) == ("a", [1, 2])
+            shallow = copy.copy(t)
+            assert shallow.extra is t.extra
+            deep = copy.deepcopy(t)
+            assert deep.extra == t.extra and deep.extra is not t.extra
+            "#
+        );
+    });
+}
+
+#[test]
+fn copy_python_subclass() {
+    Python::with_gil(|py| {
+        let module = register_module(py);
+        py_run!(
+            py,
+            module,
+            r#"
+            import copy
+            Sub = type("Sub", (module.Tagged,), {})
+            s = Sub("b")
+            s.extra = 1
+            c = copy.copy(s)
+            assert type(c) is Sub
+            assert (c.tag, c.extra) == ("b", 1)
+            "#
+        );
+    });
+}
+
+#[test]
+fn copy_python_subclass_with_slots() {
+    Python::with_gil(|py| {
+        let module = register_module(py);
+        py_run!(
+            py,
+            module,
+            r#"
+            import copy, pickle
+            class SlotSub(module.Tagged):
+                __slots__ = ("a", "b")
+            SlotSub.__module__ = "test_pickle"
+            module.SlotSub = SlotSub
+            s = SlotSub("c")
+            s.a = [1]
+            s.extra = 2
+            for c in (copy.copy(s), copy.deepcopy(s), pickle.loads(pickle.dumps(s))):
+                assert type(c) is SlotSub
+                assert (c.tag, c.a, c.extra) == ("c", [1], 2)
+                assert not hasattr(c, "b")
+            assert copy.copy(s).a is s.a
+            assert copy.deepcopy(s).a is not s.a
+            "#
+        );
+    });
+}
+
+#[test]
+fn copy_python_subclass_with_custom_state() {
+    Python::with_gil(|py| {
+        let module = register_module(py);
+        py_run!(
+            py,
+            module,
+            r#"
+            import copy
+            class Stateful(module.Tagged):
+                def __getstate__(self):
+                    return {"saved": self.value * 2}
+                def __setstate__(self, state):
+                    self.value = state["saved"]
+            s = Stateful("d")
+            s.value = 1
+            for c in (copy.copy(s), copy.deepcopy(s)):
+                assert (c.tag, c.value) == ("d", 2)
+            "#
+        );
+    });
+}
+
+#[pyclass(extends = Tagged)]
+struct TaggedChild {}
+
+#[pymethods]
+impl TaggedChild {
+    #[new]
+    fn new() -> (Self, Tagged) {
+        (TaggedChild {}, Tagged::new("child".to_owned()))
+    }
+}
+
+#[test]
+fn copy_rust_subclass_fails() {
+    Python::with_gil(|py| {
+        let child = PyCell::new(py, TaggedChild::new()).unwrap();
+        let err = py
+            .import("copy")
+            .unwrap()
+            .call_method1("copy", (child,))
+            .unwrap_err();
+        assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+        assert_eq!(
+            err.value(py).to_string(),
+            "cannot create a TaggedChild from a Tagged value, because it is a subclass defined in Rust"
+        );
+    });
+}
+
+#[pyclass(module = "test_pickle", pickle)]
+enum Color {
+    Red,
+    #[pyo3(name = "GREEN")]
+    Green,
+}
+
+#[test]
+fn pickle_enum() {
+    Python::with_gil(|py| {
+        let module = register_module(py);
+        py_run!(
+            py,
+            module,
+            r#"
+            import copy, pickle
+            for variant in (module.Color.Red, module.Color.GREEN):
+                for protocol in range(pickle.HIGHEST_PROTOCOL + 1):
+                    assert pickle.loads(pickle.dumps(variant, protocol)) == variant
+                assert copy.deepcopy(variant) == variant
+            "#
+        );
+    });
+}
+
+#[cfg(feature = "serde")]
+mod serde_pickle {
+    use super::*;
+    use pyo3::types::PyDict;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    enum Kind {
+        Empty,
+        Circle(f64),
+        Rect { width: u32, height: u32 },
+        Poly(Vec<(i32, i32)>, bool),
+    }
+
+    #[pyclass(module = "test_pickle", pickle = serde)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Shape {
+        name: String,
+        kinds: Vec<Kind>,
+        label: Option<String>,
+        data: HashMap<u64, Vec<u8>>,
+        #[serde(with = "serde_bytes_compat")]
+        raw: Vec<u8>,
+        big: u128,
+    }
+
+    mod serde_bytes_compat {
+        use serde::{Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(bytes)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<u8>, D::Error> {
+            struct BytesVisitor;
+
+            impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+                type Value = Vec<u8>;
+
+                fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str("bytes")
+                }
+
+                fn visit_bytes<E>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+                    Ok(v.to_vec())
+                }
+            }
+
+            deserializer.deserialize_bytes(BytesVisitor)
+        }
+    }
+
+    fn shape() -> Shape {
+        Shape {
+            name: "shape".to_owned(),
+            kinds: vec![
+                Kind::Empty,
+                Kind::Circle(1.5),
+                Kind::Rect {
+                    width: 2,
+                    height: 3,
+                },
+                Kind::Poly(vec![(0, 0), (1, -1)], true),
+            ],
+            label: None,
+            data: vec![(1, vec![1, 2]), (u64::MAX, vec![])]
+                .into_iter()
+                .collect(),
+            raw: b"raw".to_vec(),
+            big: u128::MAX,
+        }
+    }
+
+    #[test]
+    fn pickle_with_serde() {
+        Python::with_gil(|py| {
+            register_module(py);
+            let original = Py::new(py, shape()).unwrap();
+            let pickle = py.import("pickle").unwrap();
+            let dumped = pickle.call_method1("dumps", (&original,)).unwrap();
+            let loaded: PyRef<'_, Shape> = pickle
+                .call_method1("loads", (dumped,))
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(*loaded, shape());
+        });
+    }
+
+    #[test]
+    fn pickle_with_serde_invalid_state() {
+        Python::with_gil(|py| {
+            let module = register_module(py);
+            let locals = PyDict::new(py);
+            locals.set_item("module", module).unwrap();
+            let err = py
+                .eval(
+                    "module.Shape.__pyo3_unpickle__({'name': 1})",
+                    None,
+                    Some(locals),
+                )
+                .unwrap_err();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        });
+    }
+}
@@ -1,7 +1,7 @@
 #![cfg(feature = "macros")]
 
 use pyo3::exceptions::{PyAttributeError, PyIndexError, PyValueError};
-use pyo3::types::{PyDict, PyList, PyMapping, PySequence, PySlice, PyType};
+use pyo3::types::{PyDict, PyList, PyMapping, PySequence, PySlice, PyType, RustIterator};
 use pyo3::{prelude::*, py_run, PyCell};
 use std::{isize, iter};
 
@@ -393,6 +393,60 @@ fn iterator() {
     });
 }
 
+#[pyclass]
+struct Range {
+    values: Vec<u32>,
+}
+
+#[pymethods]
+impl Range {
+    fn __iter__(&self) -> RustIterator<std::vec::IntoIter<u32>> {
+        RustIterator(self.values.clone().into_iter())
+    }
+
+    fn evens(&self) -> PyResult<RustIterator<Vec<u32>>> {
+        Ok(RustIterator(
+            self.values.iter().copied().filter(|v| v % 2 == 0).collect(),
+        ))
+    }
+
+    fn checked(&self) -> RustIterator<Vec<PyResult<u32>>> {
+        RustIterator(vec![Ok(1), Err(PyValueError::new_err("bad value")), Ok(3)])
+    }
+}
+
+#[test]
+fn rust_iterator() {
+    Python::with_gil(|py| {
+        let inst = Py::new(
+            py,
+            Range {
+                values: vec![1, 2, 3, 4],
+            },
+        )
+        .unwrap();
+        py_assert!(py, inst, "list(inst) == [1, 2, 3, 4]");
+        py_assert!(py, inst, "list(inst.evens()) == [2, 4]");
+        py_assert!(py, inst, "type(iter(inst)).__name__ == 'RustIterator'");
+        py_assert!(py, inst, "iter(inst).__length_hint__() == 4");
+        py_run!(
+            py,
+            inst,
+            r#"
+it = inst.checked()
+assert next(it) == 1
+try:
+    next(it)
+except ValueError as e:
+    assert str(e) == "bad value"
+else:
+    assert False
+assert list(it) == [3]
+"#
+        );
+    });
+}
+
 #[pyclass]
 struct Callable;
 
@@ -545,3 +545,28 @@ fn test_return_value_borrows_from_arguments() {
         py_assert!(py, function key value, "function(key, value) == { \"key\": 42 }");
     });
 }
+
+#[pyfunction]
+fn bound_arguments<'py>(
+    list: Bound<'py, types::PyList>,
+    value: Bound<'py, Value>,
+) -> PyResult<Bound<'py, types::PyList>> {
+    list.append(value.borrow().0)?;
+    Ok(list)
+}
+
+#[test]
+fn test_bound_arguments() {
+    Python::with_gil(|py| {
+        let function = wrap_pyfunction!(bound_arguments, py).unwrap();
+        let value = Py::new(py, Value(42)).unwrap();
+
+        py_assert!(py, function value, "function([1], value) == [1, 42]");
+        py_expect_exception!(
+            py,
+            function value,
+            "function((1,), value)",
+            PyTypeError
+        );
+    });
+}
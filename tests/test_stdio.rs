@@ -0,0 +1,78 @@
+#![cfg(feature = "redirect-stdio")]
+
+use pyo3::prelude::*;
+use pyo3::stdio::{redirect_stderr, redirect_stdout};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn test_redirect_stdio() {
+    Python::with_gil(|py| {
+        let stdout = Arc::new(Mutex::new(Vec::new()));
+        let stderr = Arc::new(Mutex::new(Vec::new()));
+        let previous_stdout = {
+            let stdout = stdout.clone();
+            redirect_stdout(py, move |line| stdout.lock().unwrap().push(line.to_owned())).unwrap()
+        };
+        let previous_stderr = {
+            let stderr = stderr.clone();
+            redirect_stderr(py, move |line| stderr.lock().unwrap().push(line.to_owned())).unwrap()
+        };
+
+        py.run(
+            r#"
+import sys
+print("hello", "world")
+print("partial", end="")
+print(" line")
+print("unterminated", end="", flush=True)
+print("error", file=sys.stderr)
+assert sys.stdout.name == "<stdout>"
+assert sys.stdout.writable()
+assert type(sys.stdout) is type(sys.stderr)
+sys.stdout.writelines(["written", " lines\n"])
+try:
+    sys.stdout.write(b"bytes")
+except TypeError:
+    pass
+else:
+    assert False
+"#,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let sys = py.import("sys").unwrap();
+        sys.setattr("stdout", previous_stdout).unwrap();
+        sys.setattr("stderr", previous_stderr).unwrap();
+
+        assert_eq!(
+            *stdout.lock().unwrap(),
+            [
+                "hello world",
+                "partial line",
+                "unterminated",
+                "written lines"
+            ]
+        );
+        assert_eq!(*stderr.lock().unwrap(), ["error"]);
+    });
+}
+
+#[test]
+fn test_reentrant_write_fails() {
+    Python::with_gil(|py| {
+        let previous = redirect_stdout(py, |line| {
+            Python::with_gil(|py| {
+                let result = py.run("print('nested')", None, None);
+                assert!(result.is_err(), "{}", line);
+            })
+        })
+        .unwrap();
+        py.run("print('outer')", None, None).unwrap();
+        py.import("sys")
+            .unwrap()
+            .setattr("stdout", previous)
+            .unwrap();
+    });
+}
@@ -0,0 +1,102 @@
+#![cfg(all(feature = "macros", Py_3_9, not(any(PyPy, Py_LIMITED_API))))]
+
+use pyo3::exceptions::{PyRuntimeError, PyTypeError};
+use pyo3::interpreter::SubInterpreter;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::PyList;
+use pyo3::{intern, py_run, AsPyPointer};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[pyclass]
+struct Counter {
+    count: usize,
+}
+
+#[test]
+fn test_subinterpreters_are_isolated() {
+    let (a, b) = Python::with_gil(|py| {
+        (
+            SubInterpreter::new(py).unwrap(),
+            SubInterpreter::new(py).unwrap(),
+        )
+    });
+    assert_ne!(a.id(), b.id());
+
+    a.run("import sys; sys.tenant = 'a'; x = 1").unwrap();
+    b.run("import sys; sys.tenant = 'b'").unwrap();
+
+    fn tenant(interp: &SubInterpreter) -> PyResult<String> {
+        interp.with_gil(|py| py.eval("sys.tenant", None, None)?.extract())
+    }
+    assert_eq!(tenant(&a).unwrap(), "a");
+    assert_eq!(tenant(&b).unwrap(), "b");
+
+    // `__main__` is kept between calls, but not shared.
+    a.run("assert x == 1").unwrap();
+    let err = b.run("x").unwrap_err();
+    Python::with_gil(|py| {
+        assert!(err.is_instance_of::<PyRuntimeError>(py));
+        assert!(err.to_string().contains("NameError"), "{}", err);
+        assert!(py.eval("__import__('sys').tenant", None, None).is_err());
+    });
+}
+
+#[test]
+fn test_per_interpreter_caches() {
+    let sub = Python::with_gil(SubInterpreter::new).unwrap();
+
+    static CACHE: GILOnceCell<Py<PyList>> = GILOnceCell::new();
+    fn pointers(py: Python<'_>) -> (usize, usize) {
+        assert_eq!(intern!(py, "interned").to_str().unwrap(), "interned");
+        let cached = CACHE
+            .per_interpreter(py)
+            .unwrap()
+            .get_or_init(py, || PyList::empty(py).into());
+        (
+            py.get_type::<Counter>().as_ptr() as usize,
+            cached.as_ptr() as usize,
+        )
+    }
+    let main = Python::with_gil(pointers);
+    let first = sub.with_gil(pointers);
+    let second = sub.with_gil(pointers);
+    assert_eq!(first, second);
+    assert_ne!(main.0, first.0);
+    assert_ne!(main.1, first.1);
+
+    sub.with_gil(|py| {
+        let counter = PyCell::new(py, Counter { count: 1 }).unwrap();
+        py_run!(py, counter, "assert type(counter).__name__ == 'Counter'");
+        assert_eq!(counter.borrow().count, 1);
+    });
+}
+
+#[test]
+fn test_cross_interpreter_use_is_detected() {
+    let sub = Python::with_gil(SubInterpreter::new).unwrap();
+    let from_main = Python::with_gil(|py| Py::new(py, Counter { count: 0 }).unwrap());
+
+    let from_main = sub.with_gil(move |py| {
+        let any = from_main.clone_ref(py).into_py(py);
+        let err = any.extract::<PyRef<'_, Counter>>(py).err().unwrap();
+        assert!(err.is_instance_of::<PyTypeError>(py));
+        if cfg!(debug_assertions) {
+            let result = catch_unwind(AssertUnwindSafe(|| from_main.borrow(py).count));
+            assert!(result.is_err());
+        }
+        from_main
+    });
+    Python::with_gil(|py| assert_eq!(from_main.borrow(py).count, 0));
+
+    let from_sub = sub.with_gil(|py| Py::new(py, Counter { count: 1 }).unwrap());
+    let from_sub = Python::with_gil(move |py| {
+        if cfg!(debug_assertions) {
+            let result = catch_unwind(AssertUnwindSafe(|| from_sub.as_ref(py).borrow().count));
+            assert!(result.is_err());
+        }
+        from_sub
+    });
+    // Release the object in the interpreter which owns it.
+    sub.with_gil(move |py| assert_eq!(from_sub.borrow(py).count, 1));
+}
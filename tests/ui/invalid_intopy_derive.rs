@@ -0,0 +1,30 @@
+use pyo3::IntoPy;
+
+#[derive(IntoPy)]
+struct MixedGetters {
+    #[pyo3(item)]
+    a: i32,
+    #[pyo3(attribute)]
+    b: i32,
+}
+
+#[derive(IntoPy)]
+struct MixedDefault {
+    a: i32,
+    #[pyo3(item("b"))]
+    b: i32,
+}
+
+#[derive(IntoPy)]
+struct UnhashableKey {
+    #[pyo3(item(b"a"))]
+    a: i32,
+}
+
+#[derive(IntoPy)]
+enum EmptyVariant {
+    A(i32),
+    B,
+}
+
+fn main() {}
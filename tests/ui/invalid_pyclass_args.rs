@@ -24,4 +24,13 @@ struct InvalidArg {}
 #[pyclass(mapping, sequence)]
 struct CannotBeMappingAndSequence {}
 
+#[pyclass(pickle = json)]
+struct InvalidPickle {}
+
+#[pyclass(extends = pyo3::types::PyDict, pickle = serde)]
+struct SerdePickleWithExtends {}
+
+#[pyclass(extends = pyo3::types::PyDict, pickle)]
+struct PickleWithExtends {}
+
 fn main() {}
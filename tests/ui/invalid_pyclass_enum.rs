@@ -15,4 +15,9 @@ enum NotDrivedClass {
 #[pyclass]
 enum NoEmptyEnum {}
 
+#[pyclass(pickle = serde)]
+enum SerdePickleEnum {
+    X,
+}
+
 fn main() {}
@@ -170,4 +170,29 @@ impl DuplicateMethod {
     fn func_b(&self) {}
 }
 
+struct SubclassHooks {}
+
+#[pymethods]
+impl SubclassHooks {
+    #[staticmethod]
+    fn __init_subclass__() {}
+}
+
+#[pymethods]
+impl SubclassHooks {
+    #[pyo3(name = "__class_getitem__")]
+    fn class_getitem_with_receiver(&self, _item: &PyAny) {}
+}
+
+#[pymethods]
+impl SubclassHooks {
+    #[pyo3(name = "__class_getitem__")]
+    fn class_getitem_without_item(_cls: &pyo3::types::PyType) {}
+}
+
+#[pymethods]
+impl SubclassHooks {
+    fn __set_name__(&self, _owner: &PyAny) {}
+}
+
 fn main() {}